@@ -0,0 +1,588 @@
+//! The project configuration, read from the `rome.json` file in the current directory or the
+//! closest of its ancestors.
+//!
+//! ```json
+//! {
+//!   "format": {
+//!     "indentStyle": "space",
+//!     "indentSize": 4,
+//!     "languages": {
+//!       "json": { "indentSize": 2 }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! The options of a language override the options of the formatter, which override the defaults.
+//! The options passed to the CLI override all of them.
+//!
+//! The `ignore` and `include` patterns select the files of the project that the commands process,
+//! see [TraversalFilter](path::traversal::TraversalFilter).
+use core::file_handlers::Language;
+use core::file_system::{FileKind, FileSystem};
+use path::traversal::check_pattern;
+use rome_formatter::{
+	FormatOptions, IndentStyle, LineEndingStyle, QuoteStyle, Semicolons, TrailingComma,
+};
+use rslint_errors::Diagnostic;
+use rslint_parser::ast::{JsonKey, JsonObject, JsonValue};
+use rslint_parser::{parse_json, AstNode, TextRange};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub(crate) const CONFIG_FILE: &str = "rome.json";
+
+/// The code of the diagnostics of invalid configurations
+const DIAGNOSTIC_CODE: &str = "Configuration";
+
+const ROOT_PROPERTIES: [&str; 3] = ["format", "ignore", "include"];
+
+const FORMAT_PROPERTIES: [&str; 10] = [
+	"enabled",
+	"indentStyle",
+	"indentSize",
+	"lineWidth",
+	"quoteStyle",
+	"semicolons",
+	"trailingComma",
+	"bracketSpacing",
+	"lineEnding",
+	"languages",
+];
+
+/// The properties of `format` that a language can override
+const LANGUAGE_OPTION_PROPERTIES: [&str; 8] = [
+	"indentStyle",
+	"indentSize",
+	"lineWidth",
+	"quoteStyle",
+	"semicolons",
+	"trailingComma",
+	"bracketSpacing",
+	"lineEnding",
+];
+
+const LANGUAGES: [(&str, Language); 5] = [
+	("js", Language::Js),
+	("ts", Language::Ts),
+	("json", Language::Json),
+	("jsonc", Language::Jsonc),
+	("json5", Language::Json5),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Configuration {
+	pub(crate) format: FormatConfiguration,
+	/// Patterns of the files that are skipped by the commands
+	pub(crate) ignore: Vec<String>,
+	/// Patterns of the files processed by the commands, all files if it's empty
+	pub(crate) include: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct FormatConfiguration {
+	/// Whether the files of the project are formatted. Defaults to true
+	pub(crate) enabled: bool,
+	pub(crate) options: PartialFormatOptions,
+	/// The options of a language, which override the [options](FormatConfiguration::options)
+	pub(crate) languages: HashMap<Language, PartialFormatOptions>,
+}
+
+impl Default for FormatConfiguration {
+	fn default() -> Self {
+		Self {
+			enabled: true,
+			options: PartialFormatOptions::default(),
+			languages: HashMap::new(),
+		}
+	}
+}
+
+/// Formatter options that override other options only if they're set
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct PartialFormatOptions {
+	/// The indent style, whose size is set by [indent_size](PartialFormatOptions::indent_size)
+	pub(crate) indent_style: Option<IndentStyle>,
+	/// The size of the indent if the indent style is space
+	pub(crate) indent_size: Option<u8>,
+	pub(crate) line_width: Option<u16>,
+	pub(crate) quote_style: Option<QuoteStyle>,
+	pub(crate) semicolons: Option<Semicolons>,
+	pub(crate) trailing_comma: Option<TrailingComma>,
+	pub(crate) bracket_spacing: Option<bool>,
+	pub(crate) line_ending: Option<LineEndingStyle>,
+}
+
+impl PartialFormatOptions {
+	/// Overrides the `options` with the options that are set
+	pub(crate) fn apply_to(&self, options: &mut FormatOptions) {
+		let indent_size = self
+			.indent_size
+			.or(match options.indent_style {
+				IndentStyle::Space(size) => Some(size),
+				IndentStyle::Tab => None,
+			})
+			.unwrap_or(2);
+
+		options.indent_style = match (&self.indent_style, &options.indent_style) {
+			(Some(IndentStyle::Tab), _) | (None, IndentStyle::Tab) => IndentStyle::Tab,
+			(Some(IndentStyle::Space(_)), _) | (None, IndentStyle::Space(_)) => {
+				IndentStyle::Space(indent_size)
+			}
+		};
+
+		if let Some(line_width) = self.line_width {
+			options.line_width = line_width;
+		}
+		if let Some(quote_style) = self.quote_style {
+			options.quote_style = quote_style;
+		}
+		if let Some(semicolons) = self.semicolons {
+			options.semicolons = semicolons;
+		}
+		if let Some(trailing_comma) = self.trailing_comma {
+			options.trailing_comma = trailing_comma;
+		}
+		if let Some(bracket_spacing) = self.bracket_spacing {
+			options.bracket_spacing = bracket_spacing;
+		}
+		if let Some(line_ending) = self.line_ending {
+			options.line_ending = line_ending;
+		}
+	}
+}
+
+/// The formatter options of the files, resolved from the configuration and the options passed
+/// to the CLI
+#[derive(Debug, Default)]
+pub(crate) struct FormatSettings {
+	configuration: FormatConfiguration,
+	/// The options passed to the CLI
+	overrides: PartialFormatOptions,
+}
+
+impl FormatSettings {
+	pub(crate) fn new(configuration: FormatConfiguration, overrides: PartialFormatOptions) -> Self {
+		Self {
+			configuration,
+			overrides,
+		}
+	}
+
+	/// Returns `false` if the configuration disables the formatter
+	pub(crate) fn enabled(&self) -> bool {
+		self.configuration.enabled
+	}
+
+	/// Returns the options to format the files written in `language`
+	pub(crate) fn options(&self, language: Language) -> FormatOptions {
+		let mut options = FormatOptions::default();
+
+		self.configuration.options.apply_to(&mut options);
+		if let Some(language_options) = self.configuration.languages.get(&language) {
+			language_options.apply_to(&mut options);
+		}
+		self.overrides.apply_to(&mut options);
+
+		options
+	}
+}
+
+/// Returns the path of the configuration file in `directory` or the closest of its ancestors
+pub(crate) fn find_configuration(
+	directory: &Path,
+	file_system: &dyn FileSystem,
+) -> Option<PathBuf> {
+	directory
+		.ancestors()
+		.map(|directory| directory.join(CONFIG_FILE))
+		.find(|path| file_system.kind(path) == Some(FileKind::File))
+}
+
+/// Parses the configuration `source` of the file `file_id`.
+///
+/// Returns the diagnostics of the syntax errors, the unknown properties and the invalid values
+/// if there are any.
+pub(crate) fn parse_configuration(
+	source: &str,
+	file_id: usize,
+) -> Result<Configuration, Vec<Diagnostic>> {
+	let parse = parse_json(source, file_id);
+	if !parse.errors().is_empty() {
+		return Err(parse.errors().to_vec());
+	}
+
+	let mut parser = ConfigurationParser {
+		file_id,
+		diagnostics: vec![],
+	};
+	let mut configuration = Configuration::default();
+
+	if let Some(root) = parse.tree().value() {
+		parser.parse_root(&root, &mut configuration);
+	}
+
+	if parser.diagnostics.is_empty() {
+		Ok(configuration)
+	} else {
+		Err(parser.diagnostics)
+	}
+}
+
+/// Reads the values of a parsed configuration and collects the diagnostics of the invalid ones
+struct ConfigurationParser {
+	file_id: usize,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl ConfigurationParser {
+	fn parse_root(&mut self, root: &JsonValue, configuration: &mut Configuration) {
+		for (name, range, value) in self.members(root, CONFIG_FILE) {
+			match name.as_str() {
+				"format" => self.parse_format(&value, &mut configuration.format),
+				"ignore" => configuration.ignore = self.patterns(&value, &name),
+				"include" => configuration.include = self.patterns(&value, &name),
+				_ => self.unknown_property(&name, range, &ROOT_PROPERTIES),
+			}
+		}
+	}
+
+	fn parse_format(&mut self, format: &JsonValue, configuration: &mut FormatConfiguration) {
+		for (name, range, value) in self.members(format, "format") {
+			match name.as_str() {
+				"enabled" => {
+					if let Some(enabled) = self.boolean(&value, &name) {
+						configuration.enabled = enabled;
+					}
+				}
+				"languages" => self.parse_languages(&value, &mut configuration.languages),
+				_ => {
+					if !self.parse_option(&name, &value, &mut configuration.options) {
+						self.unknown_property(&name, range, &FORMAT_PROPERTIES);
+					}
+				}
+			}
+		}
+	}
+
+	fn parse_languages(
+		&mut self,
+		languages: &JsonValue,
+		configuration: &mut HashMap<Language, PartialFormatOptions>,
+	) {
+		let names: Vec<_> = LANGUAGES.iter().map(|(name, _)| *name).collect();
+
+		for (name, range, value) in self.members(languages, "languages") {
+			let language = match LANGUAGES.iter().find(|(language, _)| *language == name) {
+				Some((_, language)) => *language,
+				None => {
+					self.unknown_property(&name, range, &names);
+					continue;
+				}
+			};
+
+			let options = configuration.entry(language).or_default();
+			for (option, range, value) in self.members(&value, &name) {
+				if !self.parse_option(&option, &value, options) {
+					self.unknown_property(&option, range, &LANGUAGE_OPTION_PROPERTIES);
+				}
+			}
+		}
+	}
+
+	/// Reads the formatter option `name` into the `options`. Returns `false` if `name` isn't
+	/// an option.
+	fn parse_option(
+		&mut self,
+		name: &str,
+		value: &JsonValue,
+		options: &mut PartialFormatOptions,
+	) -> bool {
+		match name {
+			"indentStyle" => options.indent_style = self.parse(value, name, "tab|space"),
+			"indentSize" => {
+				options.indent_size = self
+					.integer(value, name, 1, u8::MAX.into())
+					.map(|size| size as u8)
+			}
+			"lineWidth" => {
+				options.line_width = self
+					.integer(value, name, 1, u16::MAX.into())
+					.map(|width| width as u16)
+			}
+			"quoteStyle" => options.quote_style = self.parse(value, name, "double|single"),
+			"semicolons" => options.semicolons = self.parse(value, name, "always|as-needed"),
+			"trailingComma" => options.trailing_comma = self.parse(value, name, "none|es5|all"),
+			"bracketSpacing" => options.bracket_spacing = self.boolean(value, name),
+			"lineEnding" => options.line_ending = self.parse(value, name, "lf|crlf|cr|auto"),
+			_ => return false,
+		}
+
+		true
+	}
+
+	/// Returns the name, the range of the key and the value of the members of the object `value`
+	fn members(&mut self, value: &JsonValue, name: &str) -> Vec<(String, TextRange, JsonValue)> {
+		let object = match value {
+			JsonValue::JsonObject(object) => object,
+			_ => {
+				self.invalid_value(value, name, "an object");
+				return vec![];
+			}
+		};
+
+		object_members(object)
+	}
+
+	/// Returns the valid patterns of the array `value`
+	fn patterns(&mut self, value: &JsonValue, name: &str) -> Vec<String> {
+		let array = match value {
+			JsonValue::JsonArray(array) => array,
+			_ => {
+				self.invalid_value(value, name, "an array of patterns");
+				return vec![];
+			}
+		};
+
+		let mut patterns = vec![];
+		for element in array.elements() {
+			match string(&element).map(|pattern| (check_pattern(&pattern), pattern)) {
+				Some((Ok(()), pattern)) => patterns.push(pattern),
+				Some((Err(error), _)) => self.diagnostics.push(
+					Diagnostic::error(
+						self.file_id,
+						DIAGNOSTIC_CODE,
+						format!("invalid pattern in `{}`", name),
+					)
+					.primary(element.syntax().text_range(), error.to_string()),
+				),
+				None => self.invalid_value(&element, name, "a pattern"),
+			}
+		}
+		patterns
+	}
+
+	fn boolean(&mut self, value: &JsonValue, name: &str) -> Option<bool> {
+		match value {
+			JsonValue::JsonBoolean(boolean) => Some(boolean.true_token().is_some()),
+			_ => {
+				self.invalid_value(value, name, "a boolean");
+				None
+			}
+		}
+	}
+
+	/// Returns the integer `value` if it's between `min` and `max`
+	fn integer(&mut self, value: &JsonValue, name: &str, min: u64, max: u64) -> Option<u64> {
+		let integer = match value {
+			JsonValue::JsonNumber(number) if number.minus_token().is_none() => number
+				.value_token()
+				.and_then(|token| token.text().parse::<u64>().ok())
+				.filter(|integer| (min..=max).contains(integer)),
+			_ => None,
+		};
+
+		if integer.is_none() {
+			self.invalid_value(
+				value,
+				name,
+				&format!("an integer between {} and {}", min, max),
+			);
+		}
+		integer
+	}
+
+	/// Parses the string `value` with [FromStr], `expected` lists the valid values
+	fn parse<T: FromStr>(&mut self, value: &JsonValue, name: &str, expected: &str) -> Option<T> {
+		let result = string(value).and_then(|string| T::from_str(&string).ok());
+
+		if result.is_none() {
+			let expected = expected
+				.split('|')
+				.map(|value| format!("\"{}\"", value))
+				.collect::<Vec<_>>()
+				.join(", ");
+			self.invalid_value(value, name, &format!("one of {}", expected));
+		}
+		result
+	}
+
+	fn invalid_value(&mut self, value: &JsonValue, name: &str, expected: &str) {
+		self.diagnostics.push(
+			Diagnostic::error(
+				self.file_id,
+				DIAGNOSTIC_CODE,
+				format!("invalid value for `{}`", name),
+			)
+			.primary(
+				value.syntax().text_range(),
+				format!("expected {}", expected),
+			),
+		);
+	}
+
+	fn unknown_property(&mut self, name: &str, range: TextRange, known: &[&str]) {
+		let known = known
+			.iter()
+			.map(|name| format!("`{}`", name))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		self.diagnostics.push(
+			Diagnostic::error(
+				self.file_id,
+				DIAGNOSTIC_CODE,
+				format!("unknown property `{}`", name),
+			)
+			.primary(range, "unknown property")
+			.footer_help(format!("the known properties are {}", known)),
+		);
+	}
+}
+
+fn object_members(object: &JsonObject) -> Vec<(String, TextRange, JsonValue)> {
+	object
+		.members()
+		.filter_map(|member| {
+			let key = member.key()?;
+			let name = match &key {
+				JsonKey::JsonString(string) => string.value()?,
+				JsonKey::JsonIdentifier(identifier) => identifier.name_token()?.text().to_string(),
+			};
+			Some((name, key.syntax().text_range(), member.value()?))
+		})
+		.collect()
+}
+
+fn string(value: &JsonValue) -> Option<String> {
+	match value {
+		JsonValue::JsonString(string) => string.value(),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{parse_configuration, FormatSettings, PartialFormatOptions};
+	use core::file_handlers::Language;
+	use rome_formatter::{IndentStyle, QuoteStyle, TrailingComma};
+
+	#[test]
+	fn resolves_the_options_of_languages_and_overrides() {
+		let configuration = parse_configuration(
+			r#"{
+	"format": {
+		"indentStyle": "space",
+		"indentSize": 4,
+		"quoteStyle": "single",
+		"languages": {
+			"json": { "indentSize": 2, "trailingComma": "none" },
+			"ts": { "indentStyle": "tab" }
+		}
+	}
+}"#,
+			0,
+		)
+		.unwrap();
+		let overrides = PartialFormatOptions {
+			quote_style: Some(QuoteStyle::Double),
+			..PartialFormatOptions::default()
+		};
+		let settings = FormatSettings::new(configuration.format, overrides);
+
+		let js = settings.options(Language::Js);
+		assert_eq!(js.indent_style, IndentStyle::Space(4));
+		assert_eq!(js.quote_style, QuoteStyle::Double);
+
+		let json = settings.options(Language::Json);
+		assert_eq!(json.indent_style, IndentStyle::Space(2));
+		assert_eq!(json.trailing_comma, TrailingComma::None);
+
+		assert_eq!(
+			settings.options(Language::Ts).indent_style,
+			IndentStyle::Tab
+		);
+	}
+
+	#[test]
+	fn reports_unknown_properties_and_invalid_values() {
+		let source = r#"{
+	"format": {
+		"indentSize": "4",
+		"quoteStyle": "backtick",
+		"lineWidth": 100000,
+		"tabs": true,
+		"languages": { "css": {}, "js": { "lineWidth": 0, "indentSize": 0, "enabled": false } }
+	},
+	"lint": {}
+}"#;
+		let diagnostics = parse_configuration(source, 0).unwrap_err();
+		let labels: Vec<_> = diagnostics
+			.iter()
+			.map(|diagnostic| {
+				let primary = diagnostic.primary.as_ref().unwrap();
+				(
+					diagnostic.title.as_str(),
+					&source[primary.span.range.clone()],
+				)
+			})
+			.collect();
+
+		assert_eq!(
+			labels,
+			vec![
+				("invalid value for `indentSize`", "\"4\""),
+				("invalid value for `quoteStyle`", "\"backtick\""),
+				("invalid value for `lineWidth`", "100000"),
+				("unknown property `tabs`", "\"tabs\""),
+				("unknown property `css`", "\"css\""),
+				("invalid value for `lineWidth`", "0"),
+				("invalid value for `indentSize`", "0"),
+				("unknown property `enabled`", "\"enabled\""),
+				("unknown property `lint`", "\"lint\""),
+			]
+		);
+	}
+
+	#[test]
+	fn reads_the_ignore_and_include_patterns() {
+		let configuration = parse_configuration(
+			r#"{ "ignore": ["dist/", "*.min.js"], "include": ["src/"] }"#,
+			0,
+		)
+		.unwrap();
+		assert_eq!(configuration.ignore, vec!["dist/", "*.min.js"]);
+		assert_eq!(configuration.include, vec!["src/"]);
+
+		let configuration = parse_configuration(
+			r#"{ "ignore": ["src\\generated", "\u002a.min.js"], "\u0069nclude": ["src/"] }"#,
+			0,
+		)
+		.unwrap();
+		assert_eq!(configuration.ignore, vec!["src\\generated", "*.min.js"]);
+		assert_eq!(configuration.include, vec!["src/"]);
+
+		let diagnostics =
+			parse_configuration(r#"{ "ignore": ["src/{a,b", 1], "include": "src" }"#, 0)
+				.unwrap_err();
+		let titles: Vec<_> = diagnostics
+			.iter()
+			.map(|diagnostic| diagnostic.title.as_str())
+			.collect();
+		assert_eq!(
+			titles,
+			vec![
+				"invalid pattern in `ignore`",
+				"invalid value for `ignore`",
+				"invalid value for `include`"
+			]
+		);
+	}
+
+	#[test]
+	fn reports_syntax_errors() {
+		assert!(parse_configuration("{ \"format\": }", 0).is_err());
+		assert!(parse_configuration("[]", 0).is_err());
+	}
+}
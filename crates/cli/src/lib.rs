@@ -1,8 +1,27 @@
-use clap::{crate_version, App, AppSettings, Arg};
+mod config;
+
+use clap::{crate_version, App, AppSettings, Arg, ArgMatches};
+use config::{
+	find_configuration, parse_configuration, Configuration, FormatSettings, PartialFormatOptions,
+};
 use core::create_app;
+use core::file_handlers::Language;
+use core::pipeline::{default_threads, process_in_parallel};
+use core::App as RomeApp;
+use path::traversal::{collect_files, TraversalFilter};
 use path::RomePath;
-use rome_formatter::{format_file_and_save, FormatOptions, IndentStyle};
-use std::{path::PathBuf, str::FromStr};
+use rome_formatter::{
+	format_file_element, format_text, format_text_element, FormatError, IndentStyle,
+	LineEndingStyle, QuoteStyle, Semicolons, TrailingComma,
+};
+use rslint_errors::file::SimpleFiles;
+use rslint_errors::termcolor::{ColorChoice, StandardStream};
+use rslint_errors::{Diagnostic, Emitter, Severity};
+use std::any::Any;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Main function to run Rome CLI
 pub fn run_cli() {
@@ -10,42 +29,7 @@ pub fn run_cli() {
 		.about("The official Rome CLI")
 		.version(crate_version!())
 		.setting(AppSettings::SubcommandRequiredElseHelp)
-		.subcommand(
-			App::new("format")
-				.about("Format a file")
-				.arg(
-					Arg::new("indent_style")
-						.long("indent-style")
-						.about("The style of indentation")
-						.value_name("tab|space")
-						.default_value("tab")
-						.validator(|value| IndentStyle::from_str(value).map(|_| ())),
-				)
-				.arg(
-					Arg::new("indent_size")
-						.long("indent-size")
-						.about("The size of the indent.")
-						.value_name("NUMBER")
-						.default_value("2")
-						.validator(|value| {
-							value
-								.parse::<u8>()
-								.map_err(|_| "Invalid indent-size value. Try using a number")
-						}),
-				)
-				.arg(
-					Arg::new("input")
-						.about("File to format")
-						.required(true)
-						.validator(|value| {
-							let path = PathBuf::from(&value);
-							if !path.exists() {
-								return Err(format!("The file \"{}\" doesn't exist.", value));
-							}
-							Ok(())
-						}),
-				),
-		)
+		.subcommand(format_command())
 		.try_get_matches();
 	let subcommand_matches = match &matches {
 		Ok(r) => r.subcommand(),
@@ -56,22 +40,9 @@ pub fn run_cli() {
 
 	match subcommand_matches {
 		Some(("format", matches)) => {
-			let size = matches.value_of("indent_size");
-			let style = matches.value_of("indent_style");
-			let input = matches.value_of("input").unwrap();
-			let options: IndentStyle = style
-				.map(|s| match s {
-					"tab" => IndentStyle::Tab,
-					"space" => {
-						let size = size.unwrap_or("2");
-						IndentStyle::Space(size.parse::<u8>().unwrap_or(2))
-					}
-					_ => IndentStyle::default(),
-				})
-				.unwrap_or_default();
-
-			let mut file = RomePath::new(input).deduce_handler(&app);
-			format_file_and_save(&mut file, FormatOptions::new(options));
+			if !run_format(matches, &app) {
+				std::process::exit(1);
+			}
 		}
 		// Thanks to the settings AppSettings::SubcommandRequiredElseHelp we should not be there
 		_ => clap::Error::with_description(
@@ -81,3 +52,707 @@ pub fn run_cli() {
 		.exit(),
 	}
 }
+
+/// Runs the `format` subcommand with the files and the configuration of the file system of the
+/// `app`. Returns `false` if any file has an error or, when checking, would change.
+fn run_format(matches: &ArgMatches, app: &RomeApp) -> bool {
+	let (root, configuration) = load_configuration(app);
+	let filter = TraversalFilter::new(&root, &configuration.ignore, &configuration.include)
+		.unwrap_or_else(|error| exit_with_error(error.to_string()));
+	let settings = FormatSettings::new(configuration.format, cli_options(matches));
+
+	if matches.is_present("stdin") {
+		// clap ensures that `--stdin` is used together with `--stdin-filepath`
+		let file_path = matches.value_of("stdin_filepath").unwrap();
+		format_stdin(file_path, &settings, app, matches.is_present("print_ir"));
+		return true;
+	}
+
+	let inputs: Vec<_> = matches.values_of("input").unwrap().collect();
+	let files = collect_files(&inputs, &filter, app.file_system())
+		.unwrap_or_else(|error| exit_with_error(error.to_string()));
+
+	if matches.is_present("print_ir") {
+		print_ir(&files, &settings, app);
+		return true;
+	}
+
+	let mode = if matches.is_present("check") {
+		FormatMode::Check {
+			diff: matches.is_present("diff"),
+		}
+	} else {
+		FormatMode::Write
+	};
+
+	let threads = matches
+		.value_of("threads")
+		.and_then(|value| value.parse().ok())
+		.unwrap_or_else(default_threads);
+	let summary = format_files(&files, &settings, app, mode, threads);
+
+	match mode {
+		FormatMode::Write => println!(
+			"Formatted {} ({} changed), skipped {}",
+			describe_files(summary.formatted),
+			summary.changed,
+			describe_files(summary.skipped)
+		),
+		FormatMode::Check { .. } => println!(
+			"Checked {} ({} would change), skipped {}",
+			describe_files(summary.formatted),
+			summary.changed,
+			describe_files(summary.skipped)
+		),
+	}
+
+	let unformatted = mode != FormatMode::Write && summary.changed > 0;
+	!summary.has_errors && !unformatted
+}
+
+/// The `format` subcommand and its arguments
+fn format_command() -> App<'static> {
+	App::new("format")
+		.about("Format files, directories and the files matching glob patterns")
+		.after_help("The options are read from the rome.json file in the current directory or the closest of its ancestors. The options passed to the command override them.")
+		.arg(
+			Arg::new("indent_style")
+				.long("indent-style")
+				.about("The style of indentation")
+				.value_name("tab|space")
+				.default_value("tab")
+				.validator(|value| IndentStyle::from_str(value).map(|_| ())),
+		)
+		.arg(
+			Arg::new("indent_size")
+				.long("indent-size")
+				.about("The size of the indent.")
+				.value_name("NUMBER")
+				.default_value("2")
+				.validator(|value| match value.parse::<u8>() {
+					Ok(size) if size > 0 => Ok(()),
+					_ => Err("Invalid indent-size value. Try using a number greater than 0"),
+				}),
+		)
+		.arg(
+			Arg::new("quote_style")
+				.long("quote-style")
+				.about("The quotes of string literals")
+				.value_name("double|single")
+				.default_value("double")
+				.validator(|value| QuoteStyle::from_str(value).map(|_| ())),
+		)
+		.arg(
+			Arg::new("semicolons")
+				.long("semicolons")
+				.about("Whether to terminate every statement with a semicolon or only where it's needed")
+				.value_name("always|as-needed")
+				.default_value("always")
+				.validator(|value| Semicolons::from_str(value).map(|_| ())),
+		)
+		.arg(
+			Arg::new("trailing_comma")
+				.long("trailing-comma")
+				.about("The lists that get a trailing comma when they're broken across multiple lines")
+				.value_name("none|es5|all")
+				.default_value("es5")
+				.validator(|value| TrailingComma::from_str(value).map(|_| ())),
+		)
+		.arg(
+			Arg::new("line_width")
+				.long("line-width")
+				.about("The width of the lines that the formatter tries not to exceed")
+				.value_name("NUMBER")
+				.default_value("80")
+				.validator(|value| match value.parse::<u16>() {
+					Ok(width) if width > 0 => Ok(()),
+					_ => Err("Invalid line-width value. Try using a number greater than 0"),
+				}),
+		)
+		.arg(
+			Arg::new("bracket_spacing")
+				.long("bracket-spacing")
+				.about("Whether to print spaces between the braces and the members of an object: { a: 1 }")
+				.value_name("true|false")
+				.default_value("false")
+				.validator(|value| bool::from_str(value).map(|_| ())),
+		)
+		.arg(
+			Arg::new("line_ending")
+				.long("line-ending")
+				.about("The line endings of the formatted code, auto uses the most common line ending of the file")
+				.value_name("lf|crlf|cr|auto")
+				.default_value("lf")
+				.validator(|value| LineEndingStyle::from_str(value).map(|_| ())),
+		)
+		.arg(
+			Arg::new("check")
+				.long("check")
+				.about("Check that the files are formatted without writing them. Lists the files that would change and exits with an error if there are any"),
+		)
+		.arg(
+			Arg::new("diff")
+				.long("diff")
+				.requires("check")
+				.about("Print a unified diff of the changes for every file that would change"),
+		)
+		.arg(
+			Arg::new("print_ir")
+				.long("print-ir")
+				.about("Print the intermediate representation of the formatted code instead of formatting the file"),
+		)
+		.arg(
+			Arg::new("threads")
+				.long("threads")
+				.value_name("NUMBER")
+				.about("The number of threads that format files in parallel. Defaults to the number of CPUs")
+				.validator(|value| match value.parse::<usize>() {
+					Ok(threads) if threads > 0 => Ok(()),
+					_ => Err("Invalid threads value. Try using a number greater than 0"),
+				}),
+		)
+		.arg(
+			Arg::new("stdin")
+				.long("stdin")
+				.requires("stdin_filepath")
+				.conflicts_with_all(&["input", "check"])
+				.about("Format the code read from stdin and print the formatted code to stdout"),
+		)
+		.arg(
+			Arg::new("stdin_filepath")
+				.long("stdin-filepath")
+				.value_name("PATH")
+				.about("The path of the file read from stdin, only used to select its language"),
+		)
+		.arg(
+			Arg::new("input")
+				.about("Files and directories to format, or glob patterns like \"src/**/*.js\"")
+				.required_unless_present("stdin")
+				.multiple_values(true),
+		)
+}
+
+/// The number of files that were formatted, changed and skipped by [format_files]
+#[derive(Debug, Default)]
+struct FormatSummary {
+	formatted: usize,
+	/// The files whose formatted code differs from their content, written or not
+	changed: usize,
+	/// The files that can't be formatted because their handler doesn't support formatting
+	skipped: usize,
+	/// Whether any file has an error diagnostic
+	has_errors: bool,
+}
+
+/// What [format_files] does with the files whose content differs from their formatted code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatMode {
+	/// Writes the formatted code to the files
+	Write,
+	/// Prints the names of the files without writing them, or the diff of their changes
+	Check { diff: bool },
+}
+
+/// Formats the `files` on `threads` threads and writes or lists the ones that change, depending
+/// on the `mode`. The files that can't be formatted are skipped, as are all files if the
+/// configuration disables the formatter.
+///
+/// The names, diffs and diagnostics of the files are printed in the order of the `files`.
+fn format_files(
+	files: &[PathBuf],
+	settings: &FormatSettings,
+	app: &RomeApp,
+	mode: FormatMode,
+	threads: usize,
+) -> FormatSummary {
+	let mut summary = FormatSummary::default();
+
+	process_in_parallel(
+		files,
+		threads,
+		|file| format_file(file, settings, app, mode),
+		|file, report| {
+			let (source, diagnostics) = match report {
+				FileReport::Skipped => {
+					summary.skipped += 1;
+					return;
+				}
+				FileReport::Failed {
+					source,
+					diagnostics,
+				} => (source, diagnostics),
+				FileReport::Formatted {
+					source,
+					changed,
+					output,
+					diagnostics,
+				} => {
+					summary.formatted += 1;
+					if changed {
+						summary.changed += 1;
+					}
+					if let Some(output) = output {
+						print!("{}", output);
+					}
+					(source, diagnostics)
+				}
+			};
+
+			emit_diagnostics(&file.to_string_lossy(), source, &diagnostics);
+
+			summary.has_errors |= diagnostics
+				.iter()
+				.any(|diagnostic| diagnostic.severity >= Severity::Error);
+		},
+	);
+
+	summary
+}
+
+/// What [format_file] did with a file
+enum FileReport {
+	/// The file can't be formatted
+	Skipped,
+	/// The file couldn't be formatted because of an error
+	Failed {
+		/// The content of the file, which the diagnostics point into
+		source: String,
+		diagnostics: Vec<Diagnostic>,
+	},
+	Formatted {
+		/// The content of the file before formatting, which the diagnostics point into
+		source: String,
+		/// Whether the formatted code differs from the content of the file
+		changed: bool,
+		/// What is printed for the file: the name or the diff of a file that would change
+		output: Option<String>,
+		diagnostics: Vec<Diagnostic>,
+	},
+}
+
+/// Formats the `file` and writes it, or returns what to print for it, depending on the `mode`.
+/// It runs on the threads of [format_files] and therefore doesn't print anything.
+fn format_file(
+	path: &Path,
+	settings: &FormatSettings,
+	app: &RomeApp,
+	mode: FormatMode,
+) -> FileReport {
+	let name = path.to_string_lossy();
+	let mut file = RomePath::new(path).deduce_handler(app);
+
+	let language = match format_language(&file) {
+		Some(language) if settings.enabled() => language,
+		_ => return FileReport::Skipped,
+	};
+
+	// The diagnostics point into the source, which gets replaced by the formatted code
+	let source = match file.read_to_string() {
+		Ok(source) => source,
+		Err(error) => {
+			return FileReport::Failed {
+				source: String::new(),
+				diagnostics: vec![error.to_diagnostic(0)],
+			}
+		}
+	};
+
+	// A bug of the formatter that panics on this file doesn't stop the formatting of the others
+	let result = panic::catch_unwind(AssertUnwindSafe(|| {
+		format_text(&source, language, settings.options(language), app)
+	}));
+	let result = match result {
+		Ok(Ok(result)) => result,
+		Ok(Err(error)) => {
+			return FileReport::Failed {
+				source,
+				diagnostics: vec![error.to_diagnostic(0)],
+			}
+		}
+		Err(payload) => {
+			return FileReport::Failed {
+				source,
+				diagnostics: vec![panic_diagnostic(payload)],
+			}
+		}
+	};
+
+	let mut diagnostics = result.diagnostics().to_vec();
+	let changed = *result.code() != source;
+	let output = match mode {
+		_ if !changed => None,
+		FormatMode::Write => {
+			if let Err(error) = file.save(result.code()) {
+				diagnostics.push(error.to_diagnostic(0));
+			}
+			None
+		}
+		FormatMode::Check { diff: false } => Some(format!("{}\n", name)),
+		FormatMode::Check { diff: true } => Some(result.unified_diff(&name, &source)),
+	};
+
+	FileReport::Formatted {
+		source,
+		changed,
+		output,
+		diagnostics,
+	}
+}
+
+/// Prints the intermediate representation of the `files` that can be formatted, none if the
+/// configuration disables the formatter. Exits with an error after printing the other files if
+/// any of them can't be formatted.
+fn print_ir(files: &[PathBuf], settings: &FormatSettings, app: &RomeApp) {
+	let mut has_errors = false;
+
+	for path in files {
+		let mut file = RomePath::new(path).deduce_handler(app);
+
+		let options = match format_language(&file) {
+			Some(language) if settings.enabled() => settings.options(language),
+			_ => continue,
+		};
+
+		match format_file_element(&mut file, options, app) {
+			Ok(element) => println!("{}", element),
+			Err(error) => {
+				let source = file.read_to_string().unwrap_or_default();
+				emit_diagnostics(&path.to_string_lossy(), source, &[error.to_diagnostic(0)]);
+				has_errors = true;
+			}
+		}
+	}
+
+	if has_errors {
+		std::process::exit(1);
+	}
+}
+
+/// Formats the code read from stdin, or prints its intermediate representation if `print_ir`
+/// is `true`, and writes the result to stdout. The language of the code is deduced from the
+/// `file_path`, which doesn't need to exist.
+///
+/// The code is printed as it is if the configuration disables the formatter.
+fn format_stdin(file_path: &str, settings: &FormatSettings, app: &RomeApp, print_ir: bool) {
+	let mut source = String::new();
+	if let Err(error) = std::io::stdin().read_to_string(&mut source) {
+		emit_diagnostics(
+			file_path,
+			source,
+			&[FormatError::from(error).to_diagnostic(0)],
+		);
+		std::process::exit(1);
+	}
+
+	let file = RomePath::new(file_path).deduce_handler(app);
+	let language = match format_language(&file) {
+		Some(language) => language,
+		None => {
+			emit_diagnostics(
+				file_path,
+				source,
+				&[FormatError::UnsupportedLanguage.to_diagnostic(0)],
+			);
+			std::process::exit(1);
+		}
+	};
+
+	if !settings.enabled() {
+		print!("{}", source);
+		return;
+	}
+
+	let options = settings.options(language);
+	let diagnostics = if print_ir {
+		match format_text_element(&source, language, options, app) {
+			Ok(element) => {
+				println!("{}", element);
+				vec![]
+			}
+			Err(error) => vec![error.to_diagnostic(0)],
+		}
+	} else {
+		match format_text(&source, language, options, app) {
+			Ok(result) => {
+				print!("{}", result.code());
+				result.diagnostics().to_vec()
+			}
+			Err(error) => vec![error.to_diagnostic(0)],
+		}
+	};
+
+	let has_errors = diagnostics
+		.iter()
+		.any(|diagnostic| diagnostic.severity >= Severity::Error);
+
+	emit_diagnostics(file_path, source, &diagnostics);
+
+	if has_errors {
+		std::process::exit(1);
+	}
+}
+
+/// Returns the language of the `file` if its handler supports formatting
+fn format_language(file: &RomePath) -> Option<Language> {
+	file.get_handler()
+		.filter(|handler| handler.capabilities().format)
+		.map(|handler| handler.language())
+}
+
+/// Returns the diagnostic of a panic of the formatter, with the message of its `payload`
+fn panic_diagnostic(payload: Box<dyn Any + Send>) -> Diagnostic {
+	let message = payload
+		.downcast_ref::<&str>()
+		.map(|message| message.to_string())
+		.or_else(|| payload.downcast_ref::<String>().cloned())
+		.unwrap_or_else(|| String::from("unknown error"));
+
+	Diagnostic::error(
+		0,
+		"FormatterError",
+		format!("the formatter panicked: {}", message),
+	)
+	.footer_note("this is a bug of the formatter, the file was left as it is")
+}
+
+/// Returns the formatter options passed to the command. The options that weren't passed are
+/// `None`, so that they don't override the configuration.
+fn cli_options(matches: &ArgMatches) -> PartialFormatOptions {
+	PartialFormatOptions {
+		indent_style: explicit_value(matches, "indent_style")
+			.and_then(|value| IndentStyle::from_str(value).ok()),
+		indent_size: explicit_value(matches, "indent_size").and_then(|value| value.parse().ok()),
+		line_width: explicit_value(matches, "line_width").and_then(|value| value.parse().ok()),
+		quote_style: explicit_value(matches, "quote_style")
+			.and_then(|value| QuoteStyle::from_str(value).ok()),
+		semicolons: explicit_value(matches, "semicolons")
+			.and_then(|value| Semicolons::from_str(value).ok()),
+		trailing_comma: explicit_value(matches, "trailing_comma")
+			.and_then(|value| TrailingComma::from_str(value).ok()),
+		bracket_spacing: explicit_value(matches, "bracket_spacing")
+			.and_then(|value| bool::from_str(value).ok()),
+		line_ending: explicit_value(matches, "line_ending")
+			.and_then(|value| LineEndingStyle::from_str(value).ok()),
+	}
+}
+
+/// Returns the value of the argument `name` if it was passed, ignoring its default value
+fn explicit_value<'a>(matches: &'a ArgMatches, name: &str) -> Option<&'a str> {
+	if matches.occurrences_of(name) > 0 {
+		matches.value_of(name)
+	} else {
+		None
+	}
+}
+
+/// Reads the configuration of the project from the file system of the `app` and returns it
+/// together with the root directory of the project, the directory of the configuration file.
+/// Returns the default configuration and the current directory if the project doesn't have a
+/// configuration file.
+///
+/// Prints the diagnostics and exits if the configuration is invalid.
+fn load_configuration(app: &RomeApp) -> (PathBuf, Configuration) {
+	let path = match std::env::current_dir()
+		.ok()
+		.and_then(|directory| find_configuration(&directory, app.file_system()))
+	{
+		Some(path) => path,
+		None => return (PathBuf::from("."), Configuration::default()),
+	};
+	let name = path.to_string_lossy();
+	let root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+	let source = match app.file_system().read_to_string(&path) {
+		Ok(source) => source,
+		Err(error) => {
+			emit_diagnostics(
+				&name,
+				String::new(),
+				&[FormatError::from(error).to_diagnostic(0)],
+			);
+			std::process::exit(1);
+		}
+	};
+
+	match parse_configuration(&source, 0) {
+		Ok(configuration) => (root, configuration),
+		Err(diagnostics) => {
+			emit_diagnostics(&name, source, &diagnostics);
+			std::process::exit(1);
+		}
+	}
+}
+
+/// Prints the `message` like the errors of invalid arguments and exits
+fn exit_with_error(message: String) -> ! {
+	// unlike the errors of clap, the description isn't terminated by a line break
+	clap::Error::with_description(format!("{}\n", message), clap::ErrorKind::ValueValidation).exit()
+}
+
+/// Returns `1 file` or `<count> files`
+fn describe_files(count: usize) -> String {
+	if count == 1 {
+		String::from("1 file")
+	} else {
+		format!("{} files", count)
+	}
+}
+
+/// Prints the `diagnostics` of the file `name` with the content `source` to stderr
+fn emit_diagnostics(name: &str, source: String, diagnostics: &[Diagnostic]) {
+	let mut files = SimpleFiles::new();
+	files.add(name.to_string(), source);
+
+	let mut emitter = Emitter::new(&files);
+	let mut stderr = StandardStream::stderr(ColorChoice::Auto);
+
+	for diagnostic in diagnostics {
+		// there's nowhere else to report a failure to write to stderr
+		let _ = emitter.emit_with_writer(diagnostic, &mut stderr);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{cli_options, format_command, format_files, run_format, FormatMode};
+	use crate::config::{parse_configuration, FormatSettings};
+	use core::create_app;
+	use core::file_handlers::Language;
+	use core::file_system::MemoryFileSystem;
+	use std::path::PathBuf;
+
+	fn files(names: &[&str]) -> Vec<PathBuf> {
+		names.iter().map(PathBuf::from).collect()
+	}
+
+	#[test]
+	fn formats_the_files_of_the_file_system_of_the_app() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("a.js", "let  a=1");
+		file_system.insert("b.json", "{\"b\": 1}\n");
+		file_system.insert("c.txt", "c");
+		let mut app = create_app();
+		app.set_file_system(Box::new(file_system.clone()));
+
+		let summary = format_files(
+			&files(&["a.js", "b.json", "c.txt", "missing.js"]),
+			&FormatSettings::default(),
+			&app,
+			FormatMode::Write,
+			2,
+		);
+
+		assert_eq!(
+			(summary.formatted, summary.changed, summary.skipped),
+			(2, 1, 1)
+		);
+		// the missing file is reported without stopping the other files
+		assert!(summary.has_errors);
+		assert_eq!(file_system.get("a.js").unwrap(), b"let a = 1;\n");
+		assert_eq!(file_system.get("b.json").unwrap(), b"{\"b\": 1}\n");
+	}
+
+	#[test]
+	fn checks_the_files_without_writing_them() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("a.js", "let  a=1");
+		let mut app = create_app();
+		app.set_file_system(Box::new(file_system.clone()));
+
+		let summary = format_files(
+			&files(&["a.js"]),
+			&FormatSettings::default(),
+			&app,
+			FormatMode::Check { diff: true },
+			1,
+		);
+
+		assert_eq!((summary.formatted, summary.changed), (1, 1));
+		assert!(!summary.has_errors);
+		assert_eq!(file_system.get("a.js").unwrap(), b"let  a=1");
+	}
+
+	#[test]
+	fn formats_the_directories_of_the_file_system_of_the_app() {
+		let file_system = MemoryFileSystem::new();
+		// the configuration is searched from the current directory
+		file_system.insert(
+			std::env::current_dir().unwrap().join("rome.json"),
+			r#"{ "ignore": ["dist/"], "format": { "indentStyle": "space" } }"#,
+		);
+		file_system.insert("src/a.js", "function a(){return 1}");
+		file_system.insert("src/b/c.json", "{\"c\":1}");
+		file_system.insert("src/dist/d.js", "let  d");
+		file_system.insert("src/e.js", "let  e");
+		file_system.insert("src/.romeignore", "e.js\n");
+		let mut app = create_app();
+		app.set_file_system(Box::new(file_system.clone()));
+
+		let matches = format_command()
+			.try_get_matches_from(["format", "src"])
+			.unwrap();
+		assert!(run_format(&matches, &app));
+
+		assert_eq!(
+			file_system.get("src/a.js").unwrap(),
+			b"function a() {\n  return 1;\n}\n"
+		);
+		assert_eq!(file_system.get("src/b/c.json").unwrap(), b"{\"c\": 1}\n");
+		// the ignored files aren't formatted
+		assert_eq!(file_system.get("src/dist/d.js").unwrap(), b"let  d");
+		assert_eq!(file_system.get("src/e.js").unwrap(), b"let  e");
+
+		file_system.insert("src/f.js", "let  f");
+		let matches = format_command()
+			.try_get_matches_from(["format", "--check", "src"])
+			.unwrap();
+		assert!(!run_format(&matches, &app));
+		assert_eq!(file_system.get("src/f.js").unwrap(), b"let  f");
+	}
+
+	/// Returns the options of the JS files with the `configuration` and the CLI `arguments`
+	fn js_options(configuration: &str, arguments: &[&str]) -> (bool, u16) {
+		let configuration = parse_configuration(configuration, 0).unwrap();
+		let matches = format_command()
+			.try_get_matches_from([&["format"], arguments, &["src"]].concat())
+			.unwrap();
+		let options =
+			FormatSettings::new(configuration.format, cli_options(&matches)).options(Language::Js);
+
+		(options.bracket_spacing, options.line_width)
+	}
+
+	#[test]
+	fn the_cli_options_override_the_configuration() {
+		let configuration = r#"{ "format": { "bracketSpacing": true, "lineWidth": 100 } }"#;
+		assert_eq!(js_options(configuration, &[]), (true, 100));
+		assert_eq!(
+			js_options(
+				configuration,
+				&["--bracket-spacing=false", "--line-width", "120"]
+			),
+			(false, 120)
+		);
+
+		let configuration = r#"{ "format": { "bracketSpacing": false, "lineWidth": 100 } }"#;
+		assert_eq!(js_options(configuration, &[]), (false, 100));
+		assert_eq!(
+			js_options(
+				configuration,
+				&["--bracket-spacing=true", "--line-width=60"]
+			),
+			(true, 60)
+		);
+	}
+
+	#[test]
+	fn rejects_invalid_option_values() {
+		for arguments in [
+			["format", "--bracket-spacing=yes", "src"],
+			["format", "--line-width=0", "src"],
+			["format", "--indent-size=0", "src"],
+		] {
+			assert!(format_command().try_get_matches_from(arguments).is_err());
+		}
+	}
+}
@@ -0,0 +1,210 @@
+//! Formats the CSS of templates tagged with `css`, e.g. the styles of styled components.
+//!
+//! Every rule and declaration is printed on its own line and indented by its nesting level.
+//! The whitespace inside of a declaration or selector is collapsed to a single space.
+use super::{join_lines, EmbeddedFormatter};
+use crate::file_handlers::Mime;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CssFormatter {}
+
+impl EmbeddedFormatter for CssFormatter {
+	fn mime(&self) -> Mime {
+		Mime::Css
+	}
+
+	fn format(&self, code: &str, indent: &str) -> Option<String> {
+		format_css(code).map(|lines| join_lines(lines, indent))
+	}
+}
+
+/// Returns the lines of the formatted `code` together with their nesting level, or `None` if
+/// the braces, parentheses, strings or comments of the code aren't closed
+fn format_css(code: &str) -> Option<Vec<(usize, String)>> {
+	let mut lines = vec![];
+	let mut current = String::new();
+	let mut level = 0usize;
+	let mut parens = 0usize;
+	let mut chars = code.chars().peekable();
+
+	while let Some(char) = chars.next() {
+		match char {
+			// an expression on its own line, e.g. the mixin of `${mixin}\n color: red;`
+			'\n' if parens == 0 && is_word(current.trim()) => {
+				lines.push((level, current.trim().to_string()));
+				current.clear();
+			}
+			char if char.is_whitespace() => {
+				if !current.is_empty() && !current.ends_with(' ') {
+					current.push(' ');
+				}
+			}
+			'"' | '\'' => {
+				current.push(char);
+				loop {
+					let next = chars.next()?;
+					// a line break in a string isn't valid CSS and may not be changed
+					if next == '\n' {
+						return None;
+					}
+					current.push(next);
+					if next == '\\' {
+						current.push(chars.next()?);
+					} else if next == char {
+						break;
+					}
+				}
+			}
+			'\\' => {
+				current.push(char);
+				current.push(chars.next()?);
+			}
+			'/' if chars.peek() == Some(&'*') => {
+				let mut comment = String::from("/");
+				while !comment.ends_with("*/") || comment.len() < 4 {
+					comment.push(chars.next()?);
+				}
+				// the indention of the lines of a comment can't be changed
+				if comment.contains('\n') {
+					return None;
+				}
+				if current.trim().is_empty() {
+					lines.push((level, comment));
+					current.clear();
+				} else {
+					current.push_str(&comment);
+				}
+			}
+			'(' => {
+				parens += 1;
+				current.push(char);
+			}
+			')' => {
+				parens = parens.checked_sub(1)?;
+				current.push(char);
+			}
+			'{' if parens == 0 => {
+				let selector = current.trim();
+				lines.push((
+					level,
+					if selector.is_empty() {
+						String::from("{")
+					} else {
+						format!("{} {{", selector)
+					},
+				));
+				current.clear();
+				level += 1;
+			}
+			';' if parens == 0 => {
+				let declaration = current.trim();
+				match lines.last_mut() {
+					// a semicolon without a declaration, e.g. after a rule: `a {};`
+					Some((_, line)) if declaration.is_empty() => line.push(';'),
+					_ => lines.push((level, format!("{};", format_declaration(declaration)))),
+				}
+				current.clear();
+			}
+			'}' if parens == 0 => {
+				let declaration = current.trim();
+				if !declaration.is_empty() {
+					lines.push((level, format_declaration(declaration)));
+				}
+				current.clear();
+				level = level.checked_sub(1)?;
+				lines.push((level, String::from("}")));
+			}
+			_ => current.push(char),
+		}
+	}
+
+	if level != 0 || parens != 0 {
+		return None;
+	}
+
+	let rest = current.trim();
+	if !rest.is_empty() {
+		lines.push((level, format_declaration(rest)));
+	}
+
+	Some(lines)
+}
+
+fn is_word(text: &str) -> bool {
+	!text.is_empty()
+		&& text
+			.chars()
+			.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+/// Separates the property and the value of a declaration by `: `
+fn format_declaration(declaration: &str) -> String {
+	let mut quote = None;
+	let mut parens = 0usize;
+	let mut chars = declaration.char_indices();
+
+	while let Some((index, char)) = chars.next() {
+		match (quote, char) {
+			(Some(_), '\\') => {
+				chars.next();
+			}
+			(Some(open), char) if char == open => quote = None,
+			(Some(_), _) => {}
+			(None, '"' | '\'') => quote = Some(char),
+			(None, '(') => parens += 1,
+			(None, ')') => parens = parens.saturating_sub(1),
+			(None, ':') if parens == 0 => {
+				let property = declaration[..index].trim_end();
+				let value = declaration[index + 1..].trim_start();
+				return format!("{}: {}", property, value);
+			}
+			_ => {}
+		}
+	}
+
+	declaration.to_string()
+}
+
+#[cfg(test)]
+mod test {
+	use super::CssFormatter;
+	use crate::embedded_formatters::EmbeddedFormatter;
+
+	fn format(code: &str) -> Option<String> {
+		CssFormatter {}.format(code, "\t")
+	}
+
+	#[test]
+	fn formats_declarations_and_rules() {
+		assert_eq!(
+			format("color:red;  margin :  0 auto;\n&:hover{ color : blue } a, b{}").unwrap(),
+			"color: red;\nmargin: 0 auto;\n&:hover {\n\tcolor: blue\n}\na, b {\n}"
+		);
+	}
+
+	#[test]
+	fn keeps_strings_comments_and_parentheses() {
+		assert_eq!(
+			format(
+				"/* a  comment */ content: \"a  {b}\"; background: url(data:image/png;base64,x)"
+			)
+			.unwrap(),
+			"/* a  comment */\ncontent: \"a  {b}\";\nbackground: url(data:image/png;base64,x)"
+		);
+	}
+
+	#[test]
+	fn keeps_words_on_their_own_line() {
+		assert_eq!(
+			format("mixin\n  color: red;\n  a { other }").unwrap(),
+			"mixin\ncolor: red;\na {\n\tother\n}"
+		);
+	}
+
+	#[test]
+	fn returns_none_for_unbalanced_code() {
+		assert_eq!(format("a { color: red;"), None);
+		assert_eq!(format("a } b {"), None);
+		assert_eq!(format("content: \"a"), None);
+	}
+}
@@ -0,0 +1,212 @@
+//! Formats the GraphQL of templates tagged with `gql` or `graphql`.
+//!
+//! Every field of a selection set, and every field or value of a type definition, is printed on
+//! its own line and indented by its nesting level. The arguments of a field stay on its line.
+use super::{join_lines, EmbeddedFormatter};
+use crate::file_handlers::Mime;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GraphqlFormatter {}
+
+impl EmbeddedFormatter for GraphqlFormatter {
+	fn mime(&self) -> Mime {
+		Mime::GraphQl
+	}
+
+	fn format(&self, code: &str, indent: &str) -> Option<String> {
+		format_graphql(code).map(|lines| join_lines(lines, indent))
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+	/// A name, number, variable name or placeholder
+	Word,
+	String,
+	Comment,
+	Punctuator,
+}
+
+/// Splits the `code` into tokens, omitting the whitespace. Returns `None` if a string isn't closed
+/// or is a block string, whose value depends on its indention.
+fn tokenize(code: &str) -> Option<Vec<(TokenKind, &str)>> {
+	let mut tokens = vec![];
+	let mut rest = code;
+
+	while let Some(char) = rest.chars().next() {
+		let length = match char {
+			char if char.is_whitespace() => {
+				rest = &rest[char.len_utf8()..];
+				continue;
+			}
+			'#' => {
+				let length = rest.find('\n').unwrap_or(rest.len());
+				tokens.push((TokenKind::Comment, rest[..length].trim_end()));
+				length
+			}
+			'"' if rest.starts_with("\"\"\"") => return None,
+			'"' => {
+				let mut escaped = false;
+				let end = rest[1..].find(|char| {
+					let end = !escaped && char == '"';
+					escaped = !escaped && char == '\\';
+					end
+				})?;
+				let length = end + 2;
+				if rest[..length].contains('\n') {
+					return None;
+				}
+				tokens.push((TokenKind::String, &rest[..length]));
+				length
+			}
+			'.' if rest.starts_with("...") => {
+				tokens.push((TokenKind::Punctuator, "..."));
+				3
+			}
+			'{' | '}' | '(' | ')' | '[' | ']' | ':' | '=' | '@' | '!' | '|' | '&' | ',' => {
+				tokens.push((TokenKind::Punctuator, &rest[..1]));
+				1
+			}
+			_ => {
+				let length = rest
+					.find(|char: char| char.is_whitespace() || "{}()[]:=@!|&,#\".".contains(char))
+					.unwrap_or(rest.len())
+					// a word always makes progress, e.g. a single `.`
+					.max(char.len_utf8());
+				tokens.push((TokenKind::Word, &rest[..length]));
+				length
+			}
+		};
+
+		rest = &rest[length..];
+	}
+
+	Some(tokens)
+}
+
+/// Returns the lines of the formatted `code` together with their nesting level, or `None` if
+/// the code can't be formatted
+fn format_graphql(code: &str) -> Option<Vec<(usize, String)>> {
+	let tokens = tokenize(code)?;
+	let mut lines = vec![];
+	let mut current = String::new();
+	let mut level = 0usize;
+	let mut parens = 0usize;
+
+	for (index, &(kind, text)) in tokens.iter().enumerate() {
+		let previous = index.checked_sub(1).map(|index| tokens[index]);
+
+		match (kind, text) {
+			(TokenKind::Comment, _) => {
+				flush_line(&mut lines, &mut current, level);
+				lines.push((level, text.to_string()));
+				continue;
+			}
+			(TokenKind::Punctuator, "{") if parens == 0 => {
+				if !current.is_empty() {
+					current.push(' ');
+				}
+				current.push('{');
+				flush_line(&mut lines, &mut current, level);
+				level += 1;
+				continue;
+			}
+			(TokenKind::Punctuator, "}") if parens == 0 => {
+				flush_line(&mut lines, &mut current, level);
+				level = level.checked_sub(1)?;
+				lines.push((level, String::from("}")));
+				continue;
+			}
+			(TokenKind::Punctuator, "(") => parens += 1,
+			(TokenKind::Punctuator, ")") => parens = parens.checked_sub(1)?,
+			_ => {}
+		}
+
+		// Every field of a selection set starts on a new line
+		if level > 0
+			&& parens == 0
+			&& (matches!(kind, TokenKind::Word | TokenKind::String) || text == "...")
+			&& starts_field(&tokens[..index])
+		{
+			flush_line(&mut lines, &mut current, level);
+		}
+
+		if !current.is_empty() && needs_space(previous, text) {
+			current.push(' ');
+		}
+		current.push_str(text);
+	}
+
+	if level != 0 || parens != 0 {
+		return None;
+	}
+
+	flush_line(&mut lines, &mut current, level);
+	Some(lines)
+}
+
+/// Returns `true` if a word following the `previous` tokens starts a new field, e.g. after a
+/// name, the arguments or the type of a field, but not after a `:` or a fragment spread `...`.
+fn starts_field(previous: &[(TokenKind, &str)]) -> bool {
+	match previous {
+		[.., (TokenKind::Punctuator, "..."), (TokenKind::Word, "on")] => false,
+		[.., (TokenKind::Word | TokenKind::String, _)] => true,
+		[.., (TokenKind::Punctuator, last)] => matches!(*last, ")" | "]" | "!" | ","),
+		_ => false,
+	}
+}
+
+/// Returns `true` if `text` is separated from the `previous` token by a space
+fn needs_space(previous: Option<(TokenKind, &str)>, text: &str) -> bool {
+	let previous = match previous {
+		Some((_, previous)) => previous,
+		None => return false,
+	};
+
+	match (previous, text) {
+		(_, ")" | "]" | ":" | "," | "!") => false,
+		("(" | "[" | "@", _) => false,
+		("...", text) => text == "on",
+		(_, "(") => false,
+		_ => true,
+	}
+}
+
+fn flush_line(lines: &mut Vec<(usize, String)>, current: &mut String, level: usize) {
+	if !current.is_empty() {
+		lines.push((level, std::mem::take(current)));
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::GraphqlFormatter;
+	use crate::embedded_formatters::EmbeddedFormatter;
+
+	fn format(code: &str) -> Option<String> {
+		GraphqlFormatter {}.format(code, "  ")
+	}
+
+	#[test]
+	fn formats_queries() {
+		assert_eq!(
+			format("query User($id:ID!){user(id:$id,first : 2) @include(if: $x) {id, name ...Fields ... on Admin{ role }}}").unwrap(),
+			"query User($id: ID!) {\n  user(id: $id, first: 2) @include(if: $x) {\n    id,\n    name\n    ...Fields\n    ... on Admin {\n      role\n    }\n  }\n}"
+		);
+	}
+
+	#[test]
+	fn formats_type_definitions() {
+		assert_eq!(
+			format("# users\ntype User { id: ID! friends(first: Int = 10): [User!]! @deprecated name: String }").unwrap(),
+			"# users\ntype User {\n  id: ID!\n  friends(first: Int = 10): [User!]! @deprecated\n  name: String\n}"
+		);
+	}
+
+	#[test]
+	fn returns_none_for_unbalanced_code_and_block_strings() {
+		assert_eq!(format("{ user { id }"), None);
+		assert_eq!(format("{ user(id: 1 { id } }"), None);
+		assert_eq!(format("\"\"\"description\"\"\" type User { id: ID }"), None);
+	}
+}
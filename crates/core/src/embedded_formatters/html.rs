@@ -0,0 +1,225 @@
+//! Formats the HTML of templates tagged with `html`, e.g. the templates of lit elements.
+//!
+//! Tags separated by whitespace from their neighbours are printed on their own line and
+//! indented by the number of open elements. Text is kept on the line of its tags, with its
+//! whitespace collapsed to a single space.
+use super::{join_lines, EmbeddedFormatter};
+use crate::file_handlers::Mime;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct HtmlFormatter {}
+
+impl EmbeddedFormatter for HtmlFormatter {
+	fn mime(&self) -> Mime {
+		Mime::Html
+	}
+
+	fn format(&self, code: &str, indent: &str) -> Option<String> {
+		format_html(code).map(|lines| join_lines(lines, indent))
+	}
+}
+
+/// Elements that can't have any content and therefore never have a closing tag
+const VOID_ELEMENTS: [&str; 14] = [
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+	"track", "wbr",
+];
+
+/// Elements whose whitespace is significant or whose content isn't HTML
+const RAW_ELEMENTS: [&str; 4] = ["<pre", "<textarea", "<script", "<style"];
+
+#[derive(Debug, PartialEq, Eq)]
+enum Unit {
+	OpenTag(String),
+	CloseTag(String),
+	/// A comment, a doctype or a self closing tag like `<br />`
+	Tag(String),
+	Word(String),
+}
+
+impl Unit {
+	fn text(&self) -> &str {
+		match self {
+			Unit::OpenTag(text) | Unit::CloseTag(text) | Unit::Tag(text) | Unit::Word(text) => text,
+		}
+	}
+
+	fn is_tag(&self) -> bool {
+		!matches!(self, Unit::Word(_))
+	}
+}
+
+/// Splits the `code` into tags and words. Each unit is returned together with whether it is
+/// preceded by whitespace. Returns `None` if a tag or comment isn't closed, or if a comment or
+/// an attribute value spans multiple lines.
+fn tokenize(code: &str) -> Option<Vec<(bool, Unit)>> {
+	let mut units = vec![];
+	let mut rest = code;
+	let mut whitespace = false;
+
+	while let Some(char) = rest.chars().next() {
+		if char.is_whitespace() {
+			whitespace = true;
+			rest = &rest[char.len_utf8()..];
+			continue;
+		}
+
+		let starts_tag = char == '<'
+			&& matches!(
+				rest[1..].chars().next(),
+				Some(next) if next.is_ascii_alphabetic() || next == '/' || next == '!'
+			);
+
+		let length = if rest.starts_with("<!--") {
+			let length = rest.find("-->")? + 3;
+			if rest[..length].contains('\n') {
+				return None;
+			}
+			units.push((whitespace, Unit::Tag(rest[..length].to_string())));
+			length
+		} else if starts_tag {
+			let (length, tag) = read_tag(rest)?;
+			let unit = if tag.starts_with("</") {
+				Unit::CloseTag(tag)
+			} else if tag.starts_with("<!") || tag.ends_with("/>") || is_void_element(&tag) {
+				Unit::Tag(tag)
+			} else {
+				Unit::OpenTag(tag)
+			};
+			units.push((whitespace, unit));
+			length
+		} else {
+			// a `<` that doesn't start a tag is part of the text
+			let length = rest[1..]
+				.find(|char: char| char.is_whitespace() || char == '<')
+				.map_or(rest.len(), |index| index + 1);
+			units.push((whitespace, Unit::Word(rest[..length].to_string())));
+			length
+		};
+
+		whitespace = false;
+		rest = &rest[length..];
+	}
+
+	Some(units)
+}
+
+/// Reads the tag at the start of `code`, collapsing the whitespace between its attributes.
+/// Returns the length of the tag in `code` and the formatted tag.
+fn read_tag(code: &str) -> Option<(usize, String)> {
+	let mut tag = String::new();
+	let mut quote = None;
+
+	for (index, char) in code.char_indices() {
+		match (quote, char) {
+			(Some(_), '\n') => return None,
+			(Some(open), char) if char == open => quote = None,
+			(Some(_), _) => {}
+			(None, '"' | '\'') => quote = Some(char),
+			(None, char) if char.is_whitespace() => {
+				if !tag.ends_with(' ') {
+					tag.push(' ');
+				}
+				continue;
+			}
+			(None, '>') => {
+				if tag.ends_with(' ') {
+					tag.pop();
+				}
+				tag.push('>');
+				return Some((index + 1, tag));
+			}
+			_ => {}
+		}
+		tag.push(char);
+	}
+
+	None
+}
+
+fn is_void_element(tag: &str) -> bool {
+	let name = tag[1..]
+		.split(|char: char| !char.is_ascii_alphanumeric() && char != '-')
+		.next()
+		.unwrap_or_default();
+	VOID_ELEMENTS
+		.iter()
+		.any(|element| element.eq_ignore_ascii_case(name))
+}
+
+/// Returns the lines of the formatted `code` together with their nesting level, or `None` if
+/// the code can't be formatted
+fn format_html(code: &str) -> Option<Vec<(usize, String)>> {
+	let lowercase = code.to_ascii_lowercase();
+	if RAW_ELEMENTS
+		.iter()
+		.any(|element| lowercase.contains(element))
+	{
+		return None;
+	}
+
+	let units = tokenize(code)?;
+	let mut lines: Vec<(usize, String)> = vec![];
+	let mut depth = 0usize;
+	let mut previous_is_tag = false;
+
+	for (whitespace, unit) in units {
+		let new_line = lines.is_empty() || (whitespace && (unit.is_tag() || previous_is_tag));
+
+		if new_line {
+			let level = match unit {
+				Unit::CloseTag(_) => depth.saturating_sub(1),
+				_ => depth,
+			};
+			lines.push((level, unit.text().to_string()));
+		} else if let Some((_, line)) = lines.last_mut() {
+			if whitespace {
+				line.push(' ');
+			}
+			line.push_str(unit.text());
+		}
+
+		match unit {
+			Unit::OpenTag(_) => depth = depth.saturating_add(1),
+			Unit::CloseTag(_) => depth = depth.saturating_sub(1),
+			_ => {}
+		}
+		previous_is_tag = unit.is_tag();
+	}
+
+	Some(lines)
+}
+
+#[cfg(test)]
+mod test {
+	use super::HtmlFormatter;
+	use crate::embedded_formatters::EmbeddedFormatter;
+
+	fn format(code: &str) -> Option<String> {
+		HtmlFormatter {}.format(code, "\t")
+	}
+
+	#[test]
+	fn indents_elements() {
+		assert_eq!(
+			format("<ul class=\"list\"   id=a>  <li>one  two</li><li><b>three</b></li> <br> <!-- end --> </ul>")
+				.unwrap(),
+			"<ul class=\"list\" id=a>\n\t<li>one two</li><li><b>three</b></li>\n\t<br>\n\t<!-- end -->\n</ul>"
+		);
+	}
+
+	#[test]
+	fn keeps_text_and_attribute_values() {
+		assert_eq!(
+			format("<p title='a  b' >a < b\n  and <input value=\"x\"/> c</p>").unwrap(),
+			"<p title='a  b'>a < b and\n\t<input value=\"x\"/>\n\tc</p>"
+		);
+	}
+
+	#[test]
+	fn returns_none_for_raw_elements_and_unclosed_tags() {
+		assert_eq!(format("<pre>  a  </pre>"), None);
+		assert_eq!(format("<div class=\"a"), None);
+		assert_eq!(format("<p title=\"a\nb\"></p>"), None);
+	}
+}
@@ -0,0 +1,35 @@
+//! Formatters for code of other languages that is embedded in a JavaScript template literal,
+//! e.g. the CSS of ``css`color: red;` ``. The formatter is selected by the tag of the template,
+//! see [crate::App::register_embedded_formatter].
+pub mod css;
+pub mod graphql;
+pub mod html;
+
+use crate::file_handlers::Mime;
+
+/// Main trait to add support for formatting a language embedded in tagged templates.
+///
+/// The formatters are shared by the threads that format files in parallel.
+pub trait EmbeddedFormatter: Send + Sync {
+	/// MIME type of the embedded language
+	fn mime(&self) -> Mime;
+
+	/// Formats the embedded `code`, indenting every nesting level with `indent`. The
+	/// JavaScript formatter indents the returned lines to the level of the template.
+	///
+	/// The expressions of the template, `${value}`, are replaced by placeholders consisting of
+	/// letters, digits and underscores that must be kept in the formatted code.
+	///
+	/// The formatted code must only differ from `code` in its whitespace. Returns `None` if the
+	/// code can't be formatted, e.g. because it is invalid, and the template is kept as it is.
+	fn format(&self, code: &str, indent: &str) -> Option<String>;
+}
+
+/// Joins the `lines`, indenting each line by its nesting level
+fn join_lines(lines: Vec<(usize, String)>, indent: &str) -> String {
+	lines
+		.into_iter()
+		.map(|(level, line)| format!("{}{}", indent.repeat(level), line))
+		.collect::<Vec<_>>()
+		.join("\n")
+}
@@ -0,0 +1,24 @@
+use super::{ExtensionHandler, Mime};
+#[derive(Debug, PartialEq, Eq)]
+pub struct Json5FileHandler {}
+
+impl ExtensionHandler for Json5FileHandler {
+	fn capabilities(&self) -> super::Capabilities {
+		super::Capabilities {
+			format: true,
+			lint: true,
+		}
+	}
+
+	fn language(&self) -> super::Language {
+		super::Language::Json5
+	}
+
+	fn mime(&self) -> super::Mime {
+		Mime::Json5
+	}
+
+	fn may_use_tabs(&self) -> bool {
+		true
+	}
+}
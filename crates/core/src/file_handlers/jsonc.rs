@@ -0,0 +1,24 @@
+use super::{ExtensionHandler, Mime};
+#[derive(Debug, PartialEq, Eq)]
+pub struct JsoncFileHandler {}
+
+impl ExtensionHandler for JsoncFileHandler {
+	fn capabilities(&self) -> super::Capabilities {
+		super::Capabilities {
+			format: true,
+			lint: true,
+		}
+	}
+
+	fn language(&self) -> super::Language {
+		super::Language::Jsonc
+	}
+
+	fn mime(&self) -> super::Mime {
+		Mime::Json
+	}
+
+	fn may_use_tabs(&self) -> bool {
+		true
+	}
+}
@@ -1,18 +1,30 @@
 pub mod javascript;
 pub mod json;
+pub mod json5;
+pub mod jsonc;
+pub mod typescript;
 pub mod unknown;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
 	Js,
 	Json,
+	/// JSON with comments and trailing commas
+	Jsonc,
+	/// JSON5, a superset of JSONC with unquoted keys, single quoted strings and hex numbers
+	Json5,
 	Ts,
 	Unknown,
 }
 
 pub enum Mime {
 	Javascript,
+	TypeScript,
 	Json,
+	Json5,
 	Css,
+	GraphQl,
+	Html,
 	Text,
 }
 
@@ -20,8 +32,12 @@ impl std::fmt::Display for Mime {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
 			Mime::Css => write!(f, "text/css"),
+			Mime::GraphQl => write!(f, "application/graphql"),
+			Mime::Html => write!(f, "text/html"),
 			Mime::Json => write!(f, "application/json"),
+			Mime::Json5 => write!(f, "application/json5"),
 			Mime::Javascript => write!(f, "application/javascript"),
+			Mime::TypeScript => write!(f, "application/typescript"),
 			Mime::Text => write!(f, "text/plain"),
 		}
 	}
@@ -33,8 +49,10 @@ pub struct Capabilities {
 	pub format: bool,
 }
 
-/// Main trait to use to add a new language to Rome
-pub trait ExtensionHandler {
+/// Main trait to use to add a new language to Rome.
+///
+/// The handlers are shared by the threads that process files in parallel.
+pub trait ExtensionHandler: Send + Sync {
 	/// The language of the file. It can be a super language.
 	/// For example, a ".js" file can have [Language::Ts]
 	fn language(&self) -> Language;
@@ -0,0 +1,345 @@
+//! The file systems that files are read from and written to: the file system of the OS, and a
+//! file system in memory for tests and for the unsaved buffers of editors.
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{self, Cursor, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Main trait to read and write files.
+///
+/// The file system is shared by the threads that process files in parallel.
+pub trait FileSystem: Send + Sync {
+	/// Opens the file at `path` for reading
+	fn open(&self, path: &Path) -> io::Result<Box<dyn Read>>;
+
+	/// Replaces the content of the file at `path` with `content`, creating the file if it
+	/// doesn't exist
+	fn save(&self, path: &Path, content: &[u8]) -> io::Result<()>;
+
+	/// Returns the kind of the file or directory at `path`, following symbolic links, or `None`
+	/// if it doesn't exist
+	fn kind(&self, path: &Path) -> Option<FileKind>;
+
+	/// Lists the entries of the directory at `path`, in no particular order, with their kind.
+	/// The paths of the entries start with `path`. Symbolic links aren't followed.
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<(PathBuf, FileKind)>>;
+
+	/// Reads the content of the file at `path`, which must be valid UTF-8
+	fn read_to_string(&self, path: &Path) -> io::Result<String> {
+		let mut content = String::new();
+		self.open(path)?.read_to_string(&mut content)?;
+		Ok(content)
+	}
+}
+
+/// What a path of a [FileSystem] refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+	File,
+	Directory,
+	/// A symbolic link or a special file, like a socket
+	Other,
+}
+
+impl From<fs::FileType> for FileKind {
+	fn from(file_type: fs::FileType) -> Self {
+		if file_type.is_file() {
+			FileKind::File
+		} else if file_type.is_dir() {
+			FileKind::Directory
+		} else {
+			FileKind::Other
+		}
+	}
+}
+
+/// The file system of the operating system
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct OsFileSystem {}
+
+impl FileSystem for OsFileSystem {
+	fn open(&self, path: &Path) -> io::Result<Box<dyn Read>> {
+		Ok(Box::new(File::open(path)?))
+	}
+
+	/// The content is written to a temporary file next to the file, which then replaces the
+	/// file, so that the file is never left half written. The file keeps its permissions.
+	fn save(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+		// Replace the file that a symbolic link points to rather than the link
+		let path = match fs::canonicalize(path) {
+			Ok(target) => target,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => path.to_path_buf(),
+			Err(error) => return Err(error),
+		};
+		let path = path.as_path();
+		let temporary = temporary_path(path);
+
+		let result = write_file(&temporary, content)
+			.and_then(|_| match fs::metadata(path) {
+				Ok(metadata) => fs::set_permissions(&temporary, metadata.permissions()),
+				Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+				Err(error) => Err(error),
+			})
+			.and_then(|_| fs::rename(&temporary, path));
+
+		if result.is_err() {
+			// the temporary file may not exist, there's nothing else to clean up
+			let _ = fs::remove_file(&temporary);
+		}
+		result
+	}
+
+	fn kind(&self, path: &Path) -> Option<FileKind> {
+		fs::metadata(path)
+			.ok()
+			.map(|metadata| metadata.file_type().into())
+	}
+
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<(PathBuf, FileKind)>> {
+		fs::read_dir(path)?
+			.map(|entry| {
+				let entry = entry?;
+				Ok((entry.path(), entry.file_type()?.into()))
+			})
+			.collect()
+	}
+}
+
+/// Returns the path of the temporary file used to save the file at `path`, a hidden file in the
+/// same directory that is unique to the process
+fn temporary_path(path: &Path) -> PathBuf {
+	let name = path.file_name().unwrap_or_else(|| OsStr::new("file"));
+	let mut temporary_name = OsStr::new(".").to_os_string();
+	temporary_name.push(name);
+	temporary_name.push(format!(".{}.rome.tmp", std::process::id()));
+
+	path.with_file_name(temporary_name)
+}
+
+fn write_file(path: &Path, content: &[u8]) -> io::Result<()> {
+	let mut file = File::create(path)?;
+	file.write_all(content)?;
+	file.sync_all()
+}
+
+/// A file system that keeps its files in memory.
+///
+/// The clones of a file system share its files, so a test can keep a clone to inspect the files
+/// written through the [App](crate::App) that owns the file system.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryFileSystem {
+	files: Arc<RwLock<HashMap<PathBuf, Vec<u8>>>>,
+}
+
+impl MemoryFileSystem {
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Creates the file at `path` with `content`, or replaces its content if it exists
+	pub fn insert(&self, path: impl AsRef<Path>, content: impl Into<Vec<u8>>) {
+		self.files
+			.write()
+			.unwrap()
+			.insert(normalize(path.as_ref()), content.into());
+	}
+
+	/// Returns the content of the file at `path`, if it exists
+	pub fn get(&self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+		self.files
+			.read()
+			.unwrap()
+			.get(&normalize(path.as_ref()))
+			.cloned()
+	}
+}
+
+impl FileSystem for MemoryFileSystem {
+	fn open(&self, path: &Path) -> io::Result<Box<dyn Read>> {
+		match self.get(path) {
+			Some(content) => Ok(Box::new(Cursor::new(content))),
+			None => Err(io::Error::new(
+				io::ErrorKind::NotFound,
+				format!("the file {} doesn't exist", path.display()),
+			)),
+		}
+	}
+
+	fn save(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+		self.insert(path, content);
+		Ok(())
+	}
+
+	/// The directories are the ancestors of the files, there are no empty directories
+	fn kind(&self, path: &Path) -> Option<FileKind> {
+		let path = normalize(path);
+		let files = self.files.read().unwrap();
+
+		if files.contains_key(&path) {
+			Some(FileKind::File)
+		} else if files.keys().any(|file| file.starts_with(&path)) {
+			Some(FileKind::Directory)
+		} else {
+			None
+		}
+	}
+
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<(PathBuf, FileKind)>> {
+		if self.kind(path) != Some(FileKind::Directory) {
+			return Err(io::Error::new(
+				io::ErrorKind::NotFound,
+				format!("the directory {} doesn't exist", path.display()),
+			));
+		}
+
+		let directory = normalize(path);
+		let mut entries = BTreeMap::new();
+		for file in self.files.read().unwrap().keys() {
+			let mut components = match file.strip_prefix(&directory) {
+				Ok(relative) => relative.components(),
+				Err(_) => continue,
+			};
+			if let Some(name) = components.next() {
+				let kind = match components.next() {
+					Some(_) => FileKind::Directory,
+					None => FileKind::File,
+				};
+				entries.insert(path.join(name), kind);
+			}
+		}
+
+		Ok(entries.into_iter().collect())
+	}
+}
+
+/// Removes the `.` components of `path`, so that `./a.js` and `a.js` are the same file
+fn normalize(path: &Path) -> PathBuf {
+	path.components()
+		.filter(|component| *component != Component::CurDir)
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::{FileKind, FileSystem, MemoryFileSystem, OsFileSystem};
+	use std::fs;
+	use std::io::ErrorKind;
+	use std::path::{Path, PathBuf};
+
+	#[test]
+	fn saves_files_and_keeps_their_permissions() {
+		let directory = std::env::temp_dir().join("rome_file_system_save");
+		let _ = fs::remove_dir_all(&directory);
+		fs::create_dir_all(&directory).unwrap();
+		let path = directory.join("file.js");
+		fs::write(&path, "let a  = 1").unwrap();
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			fs::set_permissions(&path, fs::Permissions::from_mode(0o750)).unwrap();
+		}
+
+		OsFileSystem {}.save(&path, b"let a = 1;\n").unwrap();
+
+		assert_eq!(
+			OsFileSystem {}.read_to_string(&path).unwrap(),
+			"let a = 1;\n"
+		);
+		// the temporary file was renamed
+		assert_eq!(
+			OsFileSystem {}.read_dir(&directory).unwrap(),
+			vec![(path.clone(), FileKind::File)]
+		);
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			let mode = fs::metadata(&path).unwrap().permissions().mode();
+			assert_eq!(mode & 0o777, 0o750);
+		}
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn saves_the_target_of_symbolic_links() {
+		let directory = std::env::temp_dir().join("rome_file_system_save_link");
+		let _ = fs::remove_dir_all(&directory);
+		fs::create_dir_all(directory.join("target")).unwrap();
+		let target = directory.join("target/file.js");
+		let link = directory.join("link.js");
+		fs::write(&target, "let a  = 1").unwrap();
+		std::os::unix::fs::symlink(&target, &link).unwrap();
+
+		OsFileSystem {}.save(&link, b"let a = 1;\n").unwrap();
+
+		assert!(fs::symlink_metadata(&link)
+			.unwrap()
+			.file_type()
+			.is_symlink());
+		assert_eq!(fs::read_to_string(&target).unwrap(), "let a = 1;\n");
+		// the temporary file was created next to the target and renamed
+		assert_eq!(
+			OsFileSystem {}.read_dir(&directory.join("target")).unwrap(),
+			vec![(target, FileKind::File)]
+		);
+	}
+
+	#[test]
+	fn keeps_files_in_memory() {
+		let file_system = MemoryFileSystem::new();
+		let clone = file_system.clone();
+
+		file_system.insert("src/a.js", "let a");
+		clone.save(Path::new("./src/b.js"), b"let b").unwrap();
+
+		assert_eq!(
+			clone.read_to_string(Path::new("./src/a.js")).unwrap(),
+			"let a"
+		);
+		assert_eq!(file_system.get("src/b.js").unwrap(), b"let b");
+		assert_eq!(
+			file_system
+				.read_to_string(Path::new("c.js"))
+				.unwrap_err()
+				.kind(),
+			ErrorKind::NotFound
+		);
+	}
+
+	#[test]
+	fn lists_the_directories_in_memory() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("a.js", "");
+		file_system.insert("src/b.js", "");
+		file_system.insert("src/c/d.js", "");
+
+		assert_eq!(
+			file_system.kind(Path::new("./src")),
+			Some(FileKind::Directory)
+		);
+		assert_eq!(
+			file_system.kind(Path::new("src/b.js")),
+			Some(FileKind::File)
+		);
+		assert_eq!(file_system.kind(Path::new("sr")), None);
+
+		assert_eq!(
+			file_system.read_dir(Path::new(".")).unwrap(),
+			vec![
+				(PathBuf::from("./a.js"), FileKind::File),
+				(PathBuf::from("./src"), FileKind::Directory)
+			]
+		);
+		assert_eq!(
+			file_system.read_dir(Path::new("src")).unwrap(),
+			vec![
+				(PathBuf::from("src/b.js"), FileKind::File),
+				(PathBuf::from("src/c"), FileKind::Directory)
+			]
+		);
+		assert!(file_system.read_dir(Path::new("a.js")).is_err());
+	}
+}
@@ -1,30 +1,73 @@
-use crate::file_handlers::{javascript::JsFileHandler, unknown::UnknownFileHandler};
-use file_handlers::{json::JsonFileHandler, ExtensionHandler};
+use crate::embedded_formatters::{
+	css::CssFormatter, graphql::GraphqlFormatter, html::HtmlFormatter, EmbeddedFormatter,
+};
+use crate::file_handlers::{
+	javascript::JsFileHandler, typescript::TsFileHandler, unknown::UnknownFileHandler,
+};
+use file_handlers::{
+	json::JsonFileHandler, json5::Json5FileHandler, jsonc::JsoncFileHandler, ExtensionHandler,
+};
+use file_system::{FileSystem, OsFileSystem};
 use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
 
+pub mod embedded_formatters;
 pub mod file_handlers;
+pub mod file_system;
+pub mod pipeline;
 
 // these strings will live for the whole App, so it makes sense to have them as static
 pub type Handlers = HashMap<&'static str, Box<dyn ExtensionHandler>>;
 
+/// Formatters of embedded languages, keyed by the tag of the template literal
+pub type EmbeddedFormatters = HashMap<&'static str, Box<dyn EmbeddedFormatter>>;
+
 pub struct App {
 	handlers: Handlers,
 	unknown_handler: Box<dyn ExtensionHandler>,
+	embedded_formatters: EmbeddedFormatters,
+	/// The file system that the files are read from and written to
+	file_system: Box<dyn FileSystem>,
 }
 
 impl Default for App {
 	fn default() -> Self {
 		let mut map: Handlers = HashMap::new();
 		map.insert("js", Box::new(JsFileHandler {}));
-		map.insert("ts", Box::new(JsFileHandler {}));
+		map.insert("ts", Box::new(TsFileHandler {}));
 		map.insert("json", Box::new(JsonFileHandler {}));
+		map.insert("jsonc", Box::new(JsoncFileHandler {}));
+		map.insert("json5", Box::new(Json5FileHandler {}));
+
+		let mut embedded_formatters: EmbeddedFormatters = HashMap::new();
+		embedded_formatters.insert("css", Box::new(CssFormatter {}));
+		embedded_formatters.insert("gql", Box::new(GraphqlFormatter {}));
+		embedded_formatters.insert("graphql", Box::new(GraphqlFormatter {}));
+		embedded_formatters.insert("html", Box::new(HtmlFormatter {}));
+
 		Self {
 			handlers: map,
 			unknown_handler: Box::new(UnknownFileHandler {}),
+			embedded_formatters,
+			file_system: Box::new(OsFileSystem {}),
 		}
 	}
 }
 
+impl Debug for App {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let mut extensions: Vec<_> = self.handlers.keys().collect();
+		extensions.sort_unstable();
+		let mut tags: Vec<_> = self.embedded_formatters.keys().collect();
+		tags.sort_unstable();
+
+		f.debug_struct("App")
+			.field("handlers", &extensions)
+			.field("embedded_formatters", &tags)
+			.finish()
+	}
+}
+
 impl App {
 	pub fn new() -> Self {
 		Default::default()
@@ -38,6 +81,35 @@ impl App {
 		};
 		handler.map(|handler| handler.as_ref())
 	}
+
+	/// Registers the `formatter` for the templates tagged with `tag`, e.g. ``sql`SELECT 1` ``,
+	/// replacing the formatter previously registered for the tag
+	pub fn register_embedded_formatter(
+		&mut self,
+		tag: &'static str,
+		formatter: Box<dyn EmbeddedFormatter>,
+	) {
+		self.embedded_formatters.insert(tag, formatter);
+	}
+
+	/// Returns the formatter for the templates tagged with `tag`, if there's one
+	pub fn get_embedded_formatter(&self, tag: &str) -> Option<&dyn EmbeddedFormatter> {
+		self.embedded_formatters
+			.get(tag)
+			.map(|formatter| formatter.as_ref())
+	}
+
+	/// Replaces the file system of the OS with `file_system`, e.g. a
+	/// [MemoryFileSystem](file_system::MemoryFileSystem) in tests
+	pub fn set_file_system(&mut self, file_system: Box<dyn FileSystem>) {
+		self.file_system = file_system;
+	}
+
+	/// Returns the file system that the commands list, read and write the files with: the file
+	/// system of the OS unless it was replaced with [App::set_file_system]
+	pub fn file_system(&self) -> &dyn FileSystem {
+		self.file_system.as_ref()
+	}
 }
 
 pub fn create_app() -> App {
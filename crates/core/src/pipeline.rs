@@ -0,0 +1,125 @@
+//! Processes many files on a pool of threads, e.g. to format all the files of a project.
+//!
+//! The files are processed in any order, but their results are reported in the order of the
+//! files so that the output of a command is the same on every run.
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::channel;
+use yastl::Pool;
+
+/// Returns the number of threads used if the user doesn't choose one: the number of CPUs
+pub fn default_threads() -> usize {
+	num_cpus::get()
+}
+
+/// Calls `process` for each of the `items` on a pool of `threads` threads and calls `report`,
+/// on the current thread, with every item and its result.
+///
+/// The items are reported in their order, each one as soon as its result and the results of the
+/// items before it are available. The items are processed on the current thread if `threads` is
+/// `1` or less.
+///
+/// If `process` panics, the panic is resumed on the current thread once the items before the
+/// panicking item were reported, so that no item is silently left unreported.
+pub fn process_in_parallel<I, R, P, F>(items: &[I], threads: usize, process: P, mut report: F)
+where
+	I: Sync,
+	R: Send,
+	P: Fn(&I) -> R + Sync,
+	F: FnMut(&I, R),
+{
+	if threads <= 1 {
+		for item in items {
+			report(item, process(item));
+		}
+		return;
+	}
+
+	let pool = Pool::new(threads);
+	let process = &process;
+	let (sender, receiver) = channel();
+
+	pool.scoped(|scope| {
+		for (index, item) in items.iter().enumerate() {
+			let sender = sender.clone();
+			scope.execute(move || {
+				let result = panic::catch_unwind(AssertUnwindSafe(|| process(item)));
+				// the receiver is only dropped after all results were received, or when a panic
+				// is resumed
+				let _ = sender.send((index, result));
+			});
+		}
+		// the loop below ends when the jobs dropped their senders
+		drop(sender);
+
+		let mut pending = HashMap::new();
+		let mut next = 0;
+
+		for (index, result) in receiver {
+			pending.insert(index, result);
+
+			while let Some(result) = pending.remove(&next) {
+				match result {
+					Ok(result) => report(&items[next], result),
+					Err(payload) => panic::resume_unwind(payload),
+				}
+				next += 1;
+			}
+		}
+	});
+}
+
+#[cfg(test)]
+mod test {
+	use super::process_in_parallel;
+	use std::panic::{self, AssertUnwindSafe};
+	use std::time::Duration;
+
+	#[test]
+	fn reports_the_results_in_the_order_of_the_items() {
+		let items: Vec<u64> = (0..20).collect();
+
+		for threads in [1, 4] {
+			let mut reported = vec![];
+			process_in_parallel(
+				&items,
+				threads,
+				|item| {
+					// the first items take the longest to process
+					std::thread::sleep(Duration::from_millis(20 - item));
+					item * 2
+				},
+				|item, result| reported.push((*item, result)),
+			);
+
+			let expected: Vec<_> = items.iter().map(|item| (*item, item * 2)).collect();
+			assert_eq!(reported, expected);
+		}
+	}
+
+	#[test]
+	fn resumes_the_panics_of_the_jobs() {
+		let items: Vec<u64> = (0..20).collect();
+
+		for threads in [1, 4] {
+			let mut reported = vec![];
+			let result = panic::catch_unwind(AssertUnwindSafe(|| {
+				process_in_parallel(
+					&items,
+					threads,
+					|item| {
+						if *item == 5 {
+							panic!("can't process {}", item);
+						}
+						*item
+					},
+					|item, _| reported.push(*item),
+				)
+			}));
+
+			let payload = result.unwrap_err();
+			assert_eq!(payload.downcast_ref::<String>().unwrap(), "can't process 5");
+			assert_eq!(reported, vec![0, 1, 2, 3, 4]);
+		}
+	}
+}
@@ -0,0 +1,59 @@
+use core::file_handlers::Language;
+use core::App;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rome_formatter::{
+	format_element, format_elements, format_text_element, group_elements, hard_line_break,
+	soft_indent, token, FormatElement, FormatOptions,
+};
+
+/// `[1, [1, [1, …]], 2]`, every array breaks because it contains a deeper array
+fn nested_arrays(depth: usize) -> String {
+	(0..depth).fold(String::from("1"), |inner, _| format!("[1, {}, 2]", inner))
+}
+
+/// Statements with calls, arrays, objects and functions, like a large generated file
+fn statements(count: usize) -> String {
+	(0..count)
+		.map(|index| {
+			format!(
+				"const value{0} = call{0}(argument{0}, [1, 2, {{ a: {0}, b: 'b' }}], function () {{ return a{0} + b{0} * c{0}; }});\n",
+				index
+			)
+		})
+		.collect()
+}
+
+/// Groups that each contain the next group and a hard line break at the innermost level,
+/// without any token that would make the groups exceed the line width first
+fn nested_groups(depth: usize) -> FormatElement {
+	(0..depth).fold(
+		format_elements![token("a"), hard_line_break()],
+		|inner, _| group_elements(soft_indent(inner)),
+	)
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+	let sources = [
+		("nested arrays", nested_arrays(500), Language::Js),
+		("statements", statements(2000), Language::Js),
+	];
+
+	let app = App::new();
+
+	for (name, source, language) in sources.iter() {
+		let element =
+			format_text_element(source, *language, FormatOptions::default(), &app).unwrap();
+
+		c.bench_function(&format!("print {}", name), |b| {
+			b.iter(|| black_box(format_element(&element, FormatOptions::default())))
+		});
+	}
+
+	let element = nested_groups(2000);
+	c.bench_function("print nested groups", |b| {
+		b.iter(|| black_box(format_element(&element, FormatOptions::default())))
+	});
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
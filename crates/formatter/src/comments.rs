@@ -0,0 +1,370 @@
+//! Attaches the comments of a CST to the tokens they belong to.
+//!
+//! Comments are trivia tokens in the CST and the parser places them wherever it has been when
+//! consuming them, which says little about what code a comment refers to. The formatter instead
+//! attaches every comment either to the preceding non-trivia token (a trailing comment) or to the
+//! following non-trivia token (a leading comment), based on the whitespace around the comment:
+//!
+//! ```javascript
+//! // leading comment of `let`
+//! let a = 10; // trailing comment of `;`
+//! call(a /* trailing comment of `a` */, /* leading comment of `b` */ b);
+//! ```
+use rslint_parser::ast::{ClassElement, Stmt};
+use rslint_parser::util::{CommentKind, SyntaxTokenExt};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, SyntaxToken, TextRange};
+
+/// A comment in the source text together with the line breaks that surround it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct SourceComment {
+	token: SyntaxToken,
+	kind: CommentKind,
+	/// Whether there's a line break between the previous non-trivia token and the comment
+	lines_before: bool,
+	/// Whether there's a line break between the comment and the next non-trivia token
+	lines_after: bool,
+}
+
+impl SourceComment {
+	pub fn token(&self) -> &SyntaxToken {
+		&self.token
+	}
+
+	pub fn kind(&self) -> CommentKind {
+		self.kind
+	}
+
+	/// Returns `true` if the comment is on its own line, i.e. it doesn't share the line with the
+	/// preceding token.
+	pub fn has_line_break_before(&self) -> bool {
+		self.lines_before
+	}
+
+	/// Returns `true` if the comment is followed by a line break, that includes `//` comments
+	/// that always end at the end of the line.
+	pub fn has_line_break_after(&self) -> bool {
+		self.lines_after || self.kind == CommentKind::Inline
+	}
+}
+
+/// Returns the comments preceding `token` that should be printed before the token.
+pub(crate) fn leading_comments(token: &SyntaxToken) -> Vec<SourceComment> {
+	let mut trivia = Vec::new();
+	let mut current = token.prev_token();
+
+	while let Some(previous) = current {
+		if !previous.kind().is_trivia() {
+			current = Some(previous);
+			break;
+		}
+		current = previous.prev_token();
+		trivia.push(previous);
+	}
+
+	trivia.reverse();
+
+	attach_comments(current.as_ref(), &trivia, Some(token))
+		.into_iter()
+		.filter_map(|(comment, position)| match position {
+			CommentPosition::Leading => Some(comment),
+			CommentPosition::Trailing => None,
+		})
+		.collect()
+}
+
+/// Returns the comments following `token` that should be printed after the token.
+pub(crate) fn trailing_comments(token: &SyntaxToken) -> Vec<SourceComment> {
+	let mut trivia = Vec::new();
+	let mut current = token.next_token();
+
+	while let Some(next) = current {
+		if !next.kind().is_trivia() {
+			current = Some(next);
+			break;
+		}
+		current = next.next_token();
+		trivia.push(next);
+	}
+
+	attach_comments(Some(token), &trivia, current.as_ref())
+		.into_iter()
+		.filter_map(|(comment, position)| match position {
+			CommentPosition::Trailing => Some(comment),
+			CommentPosition::Leading => None,
+		})
+		.collect()
+}
+
+/// Returns the first token of `node` that isn't trivia
+pub(crate) fn first_non_trivia_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+	let range = node.text_range();
+	let mut current = node.first_token();
+
+	while let Some(token) = current {
+		if !range.contains_range(token.text_range()) {
+			return None;
+		}
+		if !token.kind().is_trivia() {
+			return Some(token);
+		}
+		current = token.next_token();
+	}
+
+	None
+}
+
+/// Returns the last token of `node` that isn't trivia
+pub(crate) fn last_non_trivia_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+	let range = node.text_range();
+	let mut current = node.last_token();
+
+	while let Some(token) = current {
+		if !range.contains_range(token.text_range()) {
+			return None;
+		}
+		if !token.kind().is_trivia() {
+			return Some(token);
+		}
+		current = token.prev_token();
+	}
+
+	None
+}
+
+/// Returns the range from the start of the first to the end of the last non-trivia token of `node`.
+pub(crate) fn non_trivia_range(node: &SyntaxNode) -> Option<TextRange> {
+	let first = first_non_trivia_token(node)?;
+	let last = last_non_trivia_token(node)?;
+
+	Some(TextRange::new(
+		first.text_range().start(),
+		last.text_range().end(),
+	))
+}
+
+/// Returns `true` if `node` has a leading `// rome-ignore format: <reason>` comment and
+/// should be printed as it is in the source.
+pub(crate) fn has_suppression_comment(node: &SyntaxNode) -> bool {
+	match first_non_trivia_token(node) {
+		Some(first) => leading_comments(&first)
+			.iter()
+			.any(|comment| is_suppression_comment(comment.token().text(), "rome-ignore format")),
+		None => false,
+	}
+}
+
+/// Returns `true` if the file starts with a `// rome-ignore-file format: <reason>` comment
+/// and should be left as it is.
+pub(crate) fn has_file_suppression_comment(root: &SyntaxNode) -> bool {
+	std::iter::successors(root.first_token(), |token| token.next_token())
+		.take_while(|token| token.kind().is_trivia())
+		.any(|token| {
+			token.kind() == SyntaxKind::COMMENT
+				&& is_suppression_comment(token.text(), "rome-ignore-file format")
+		})
+}
+
+/// Returns `true` if the text of the `comment` is `directive`, optionally followed by a colon
+/// and the reason for the suppression: `// rome-ignore format: aligned table`.
+fn is_suppression_comment(comment: &str, directive: &str) -> bool {
+	let content = comment
+		.strip_prefix("//")
+		.or_else(|| {
+			comment
+				.strip_prefix("/*")
+				.and_then(|comment| comment.strip_suffix("*/"))
+		})
+		.unwrap_or(comment)
+		.trim();
+
+	match content.strip_prefix(directive) {
+		Some(rest) => {
+			rest.is_empty() || rest.starts_with(':') || rest.starts_with(char::is_whitespace)
+		}
+		None => false,
+	}
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum CommentPosition {
+	Leading,
+	Trailing,
+}
+
+/// Decides for every comment in `trivia` if it belongs to the `previous` or the `next` token.
+///
+/// * A comment on its own line is a leading comment of the next token, unless the next token
+///   closes a block, a list or a parenthesized expression (the comment is the last in the list)
+/// * A comment followed by a line break is a trailing comment of the previous token
+/// * A comment directly followed by a separator or a closing bracket is a trailing comment of the
+///   previous token
+/// * Any other comment is a leading comment of the next token
+fn attach_comments(
+	previous: Option<&SyntaxToken>,
+	trivia: &[SyntaxToken],
+	next: Option<&SyntaxToken>,
+) -> Vec<(SourceComment, CommentPosition)> {
+	let mut result = Vec::new();
+
+	for (index, trivia_token) in trivia.iter().enumerate() {
+		let comment = match trivia_token.comment() {
+			Some(comment) => comment,
+			None => continue,
+		};
+
+		let source_comment = SourceComment {
+			token: comment.token,
+			kind: comment.kind,
+			lines_before: trivia[..index].iter().any(has_line_break),
+			lines_after: trivia[index + 1..].iter().any(has_line_break),
+		};
+
+		let next_kind = next.map(|next| next.kind());
+		let position = match (previous, next_kind) {
+			(None, _) => CommentPosition::Leading,
+			(Some(_), None) => CommentPosition::Trailing,
+			(Some(_), Some(next_kind)) if source_comment.has_line_break_before() => {
+				if is_closing_bracket(next_kind) {
+					CommentPosition::Trailing
+				} else {
+					CommentPosition::Leading
+				}
+			}
+			(Some(_), Some(_)) if source_comment.has_line_break_after() => {
+				CommentPosition::Trailing
+			}
+			(Some(previous), Some(next_kind))
+				if (is_closing_bracket(next_kind)
+					|| matches!(next_kind, SyntaxKind::COMMA | SyntaxKind::SEMICOLON))
+					&& !is_opening_bracket(previous.kind()) =>
+			{
+				CommentPosition::Trailing
+			}
+			_ => CommentPosition::Leading,
+		};
+
+		result.push((source_comment, position));
+	}
+
+	result
+}
+
+/// Returns `true` if `token` is the last token of the innermost statement or class member that
+/// contains it, or of the CST if there's none: nothing but the next statement follows it on
+/// its line.
+pub(crate) fn is_last_token_of_statement(token: &SyntaxToken) -> bool {
+	let statement = token
+		.ancestors()
+		.find(|node| Stmt::can_cast(node.kind()) || ClassElement::can_cast(node.kind()))
+		.or_else(|| token.ancestors().last());
+
+	match statement.and_then(|statement| last_non_trivia_token(&statement)) {
+		Some(last) => last == *token,
+		None => true,
+	}
+}
+
+fn has_line_break(token: &SyntaxToken) -> bool {
+	token.kind() == SyntaxKind::WHITESPACE && token.text().contains(&['\n', '\r'][..])
+}
+
+pub(crate) fn is_opening_bracket(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::L_CURLY | SyntaxKind::L_BRACK | SyntaxKind::L_PAREN
+	)
+}
+
+pub(crate) fn is_closing_bracket(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::R_CURLY | SyntaxKind::R_BRACK | SyntaxKind::R_PAREN
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{is_suppression_comment, leading_comments, trailing_comments};
+	use rslint_parser::{parse_text, SyntaxToken};
+
+	fn find_token(root: &rslint_parser::SyntaxNode, text: &str) -> SyntaxToken {
+		root.descendants_with_tokens()
+			.filter_map(|element| element.into_token())
+			.find(|token| token.text() == text)
+			.unwrap()
+	}
+
+	fn texts(comments: Vec<super::SourceComment>) -> Vec<String> {
+		comments
+			.iter()
+			.map(|comment| comment.token().text().to_string())
+			.collect()
+	}
+
+	#[test]
+	fn own_line_comment_is_leading() {
+		let root = parse_text("a;\n// leading\nb;", 0).syntax();
+
+		assert_eq!(
+			texts(leading_comments(&find_token(&root, "b"))),
+			vec!["// leading"]
+		);
+		assert!(trailing_comments(&find_token(&root, ";")).is_empty());
+	}
+
+	#[test]
+	fn end_of_line_comment_is_trailing() {
+		let root = parse_text("a; // trailing\nb;", 0).syntax();
+
+		assert_eq!(
+			texts(trailing_comments(&find_token(&root, ";"))),
+			vec!["// trailing"]
+		);
+		assert!(leading_comments(&find_token(&root, "b")).is_empty());
+	}
+
+	#[test]
+	fn block_comments_in_lists() {
+		let root = parse_text("call(a /* after a */, /* before b */ b);", 0).syntax();
+
+		assert_eq!(
+			texts(trailing_comments(&find_token(&root, "a"))),
+			vec!["/* after a */"]
+		);
+		assert_eq!(
+			texts(leading_comments(&find_token(&root, "b"))),
+			vec!["/* before b */"]
+		);
+	}
+
+	#[test]
+	fn last_comment_in_block_is_trailing() {
+		let root = parse_text("{\n\ta;\n\t// last\n}", 0).syntax();
+
+		assert_eq!(
+			texts(trailing_comments(&find_token(&root, ";"))),
+			vec!["// last"]
+		);
+		assert!(leading_comments(&find_token(&root, "}")).is_empty());
+	}
+
+	#[test]
+	fn suppression_comments() {
+		let directive = "rome-ignore format";
+
+		assert!(is_suppression_comment("// rome-ignore format", directive));
+		assert!(is_suppression_comment(
+			"// rome-ignore format: aligned table",
+			directive
+		));
+		assert!(is_suppression_comment(
+			"/* rome-ignore format */",
+			directive
+		));
+		assert!(!is_suppression_comment(
+			"// rome-ignore formatting",
+			directive
+		));
+		assert!(!is_suppression_comment("// format", directive));
+	}
+}
@@ -1,11 +1,27 @@
-use crate::{token, FormatElement, Formatter, ToFormatElement};
+use crate::formatter::verbatim_token;
+use crate::{FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::{
-	ArgList, ArrayExpr, ArrayPattern, ArrowExpr, AssignPattern, BlockStmt, CallExpr, CaseClause,
-	CatchClause, ClassBody, ClassDecl, ClassProp, Condition, ConstructorParameters, ContinueStmt,
-	DebuggerStmt, Declarator, DefaultClause, DoWhileStmt, EmptyStmt, ExprStmt, Finalizer, FnDecl,
-	ForInStmt, ForStmt, ForStmtInit, ForStmtTest, ForStmtUpdate, Getter, IdentProp, IfStmt,
-	LabelledStmt, Literal, LiteralProp, Name, NameRef, ObjectExpr, ParameterList, ReturnStmt,
-	Script, SequenceExpr, Setter, SinglePattern, SwitchStmt, TryStmt, VarDecl, WhileStmt, WithStmt,
+	ArgList, ArrayExpr, ArrayPattern, ArrowExpr, AssignExpr, AssignPattern, AwaitExpr, BinExpr,
+	BlockStmt, BracketExpr, BreakStmt, CallExpr, CaseClause, CatchClause, ClassBody, ClassDecl,
+	ClassExpr, ClassProp, ComputedPropertyName, CondExpr, Condition, Constructor,
+	ConstructorParameters, ContinueStmt, DebuggerStmt, Declarator, DefaultClause, DoWhileStmt,
+	DotExpr, EmptyStmt, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportNamed,
+	ExportWildcard, ExprPattern, ExprStmt, Finalizer, FnDecl, FnExpr, ForInStmt, ForOfStmt,
+	ForStmt, ForStmtInit, ForStmtTest, ForStmtUpdate, Getter, GroupingExpr, IdentProp, IfStmt,
+	ImportCall, ImportDecl, ImportMeta, ImportStringSpecifier, InitializedProp, JsonArray,
+	JsonBoolean, JsonIdentifier, JsonMember, JsonNull, JsonNumber, JsonObject, JsonRoot,
+	JsonString, KeyValuePattern, LabelledStmt, Literal, LiteralProp, Method, Module, Name, NameRef,
+	NamedImports, NewExpr, NewTarget, ObjectExpr, ObjectPattern, ParameterList, PrivateName,
+	PrivateProp, PrivatePropAccess, RestPattern, ReturnStmt, Script, SequenceExpr, Setter,
+	SinglePattern, Specifier, SpreadElement, SpreadProp, SuperCall, SwitchStmt, Template,
+	TemplateElement, ThisExpr, ThrowStmt, TryStmt, TsAssertion, TsCallSignatureDecl,
+	TsConstAssertion, TsConstraint, TsConstructSignatureDecl, TsConstructorParam, TsDecorator,
+	TsDefault, TsEnum, TsEnumMember, TsExportAssignment, TsExprWithTypeArgs, TsExtends,
+	TsExternalModuleRef, TsImportEqualsDecl, TsIndexSignature, TsInterfaceDecl, TsMappedTypeParam,
+	TsMappedTypeReadonly, TsMethodSignature, TsModuleBlock, TsModuleDecl, TsNamespaceDecl,
+	TsNamespaceExportDecl, TsNonNull, TsPropertySignature, TsTemplateElement, TsTupleElement,
+	TsType, TsTypeAliasDecl, TsTypeArgs, TsTypeName, TsTypeParam, TsTypeParams, UnaryExpr, VarDecl,
+	WhileStmt, WildcardImport, WithStmt, YieldExpr,
 };
 use rslint_parser::{AstNode, AstToken, SyntaxKind, SyntaxNode, SyntaxToken};
 
@@ -39,7 +55,7 @@ impl ToFormatElement for SyntaxNode {
 			SyntaxKind::SINGLE_PATTERN => SinglePattern::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
-			SyntaxKind::SPREAD_ELEMENT => SinglePattern::cast(self.clone())
+			SyntaxKind::SPREAD_ELEMENT => SpreadElement::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
 			SyntaxKind::VAR_DECL => VarDecl::cast(self.clone())
@@ -159,10 +175,272 @@ impl ToFormatElement for SyntaxNode {
 			SyntaxKind::CLASS_PROP => ClassProp::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
-			_ => todo!(
-				"Implement formatting for the {:?} syntax kind.",
-				self.kind()
-			),
+			SyntaxKind::MODULE => Module::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::IMPORT_DECL => ImportDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::EXPORT_DECL => ExportDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::EXPORT_NAMED => ExportNamed::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::EXPORT_WILDCARD => ExportWildcard::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::EXPORT_DEFAULT_DECL => ExportDefaultDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::EXPORT_DEFAULT_EXPR => ExportDefaultExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::NAMED_IMPORTS => NamedImports::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::WILDCARD_IMPORT => WildcardImport::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::IMPORT_STRING_SPECIFIER => ImportStringSpecifier::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::SPECIFIER => Specifier::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::FOR_OF_STMT => ForOfStmt::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::THROW_STMT => ThrowStmt::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::BREAK_STMT => BreakStmt::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TEMPLATE => Template::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TEMPLATE_ELEMENT => TemplateElement::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::THIS_EXPR => ThisExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::GROUPING_EXPR => GroupingExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::BRACKET_EXPR => BracketExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::DOT_EXPR => DotExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::NEW_EXPR => NewExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::UNARY_EXPR => UnaryExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::BIN_EXPR => BinExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::COND_EXPR => CondExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::ASSIGN_EXPR => AssignExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::FN_EXPR => FnExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::CLASS_EXPR => ClassExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::NEW_TARGET => NewTarget::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::IMPORT_META => ImportMeta::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::IMPORT_CALL => ImportCall::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::YIELD_EXPR => YieldExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::AWAIT_EXPR => AwaitExpr::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::SUPER_CALL => SuperCall::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::PRIVATE_PROP_ACCESS => PrivatePropAccess::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::PRIVATE_NAME => PrivateName::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::PRIVATE_PROP => PrivateProp::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::REST_PATTERN => RestPattern::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::OBJECT_PATTERN => ObjectPattern::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::KEY_VALUE_PATTERN => KeyValuePattern::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::EXPR_PATTERN => ExprPattern::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::SPREAD_PROP => SpreadProp::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::INITIALIZED_PROP => InitializedProp::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::COMPUTED_PROPERTY_NAME => ComputedPropertyName::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::METHOD => Method::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::CONSTRUCTOR => Constructor::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_MAPPED_TYPE_READONLY => TsMappedTypeReadonly::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_MAPPED_TYPE_PARAM => TsMappedTypeParam::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_CONSTRUCTOR_PARAM => TsConstructorParam::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_IMPORT_EQUALS_DECL => TsImportEqualsDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_EXTERNAL_MODULE_REF => TsExternalModuleRef::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_EXPORT_ASSIGNMENT => TsExportAssignment::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_NAMESPACE_EXPORT_DECL => TsNamespaceExportDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_INTERFACE_DECL => TsInterfaceDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_DECORATOR => TsDecorator::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TYPE_ALIAS_DECL => TsTypeAliasDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_ENUM => TsEnum::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_ENUM_MEMBER => TsEnumMember::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TUPLE_ELEMENT => TsTupleElement::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TYPE_NAME => TsTypeName::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TEMPLATE_ELEMENT => TsTemplateElement::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_EXTENDS => TsExtends::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_NON_NULL => TsNonNull::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_ASSERTION => TsAssertion::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_CONST_ASSERTION => TsConstAssertion::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_PROPERTY_SIGNATURE => TsPropertySignature::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_METHOD_SIGNATURE => TsMethodSignature::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_CALL_SIGNATURE_DECL => TsCallSignatureDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_CONSTRUCT_SIGNATURE_DECL => TsConstructSignatureDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_INDEX_SIGNATURE => TsIndexSignature::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TYPE_PARAMS => TsTypeParams::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TYPE_PARAM => TsTypeParam::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_CONSTRAINT => TsConstraint::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_DEFAULT => TsDefault::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_TYPE_ARGS => TsTypeArgs::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_EXPR_WITH_TYPE_ARGS => TsExprWithTypeArgs::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_NAMESPACE_DECL => TsNamespaceDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_MODULE_DECL => TsModuleDecl::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::TS_MODULE_BLOCK => TsModuleBlock::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_ROOT => JsonRoot::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_OBJECT => JsonObject::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_MEMBER => JsonMember::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_ARRAY => JsonArray::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_IDENTIFIER => JsonIdentifier::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_STRING => JsonString::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_NUMBER => JsonNumber::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_BOOLEAN => JsonBoolean::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JSON_NULL => JsonNull::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			kind if TsType::can_cast(kind) => TsType::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			// Kinds without a dedicated formatting are printed as they are in the source
+			_ => None,
 		}
 	}
 }
@@ -170,10 +448,15 @@ impl ToFormatElement for SyntaxNode {
 impl ToFormatElement for SyntaxToken {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		match self.kind() {
-			SyntaxKind::STRING => rslint_parser::ast::String::cast(self.clone())
-				.unwrap()
-				.to_format_element(formatter),
-			_ => Some(token(self.text())),
+			// JSON strings keep their quotes because JSON only allows double quotes
+			SyntaxKind::STRING
+				if self.parent().map(|parent| parent.kind()) != Some(SyntaxKind::JSON_STRING) =>
+			{
+				rslint_parser::ast::String::cast(self.clone())
+					.unwrap()
+					.to_format_element(formatter)
+			}
+			_ => Some(verbatim_token(self.text())),
 		}
 	}
 }
@@ -0,0 +1,505 @@
+//! Computes the minimal [TextEdit] that turns a source text into its formatted version.
+//!
+//! Formatting mostly changes the whitespace and comments between the tokens but keeps the tokens
+//! themselves. The diff therefore aligns the non-trivia tokens of the source and the formatted
+//! code and only compares the text between two aligned tokens. Tokens that the formatter inserted
+//! or removed (e.g. a semicolon) become part of the text between the surrounding aligned tokens.
+use rslint_parser::{tokenize, SyntaxKind, TextRange, TextSize};
+use rslint_text_edit::TextEdit;
+
+/// The maximum number of inserted or deleted tokens for which the diff tries to find the optimal
+/// alignment. The whole text between the first and the last differing token is replaced if the
+/// texts differ by more tokens.
+const MAX_TOKEN_EDITS: usize = 2048;
+
+/// Returns the edit that turns `source` into `formatted`. It only changes the parts of `source`
+/// that differ from `formatted`.
+pub(crate) fn text_edit(source: &str, formatted: &str) -> TextEdit {
+	let source_tokens = non_trivia_tokens(source);
+	let formatted_tokens = non_trivia_tokens(formatted);
+
+	let mut builder = TextEdit::builder();
+	let mut source_offset = 0;
+	let mut formatted_offset = 0;
+
+	let aligned = align(source, &source_tokens, formatted, &formatted_tokens);
+
+	for (source_index, formatted_index) in aligned {
+		let source_token = source_tokens[source_index];
+		let formatted_token = formatted_tokens[formatted_index];
+
+		diff_text(
+			source,
+			source_offset..source_token.start().into(),
+			formatted,
+			formatted_offset..formatted_token.start().into(),
+			&mut builder,
+		);
+
+		source_offset = source_token.end().into();
+		formatted_offset = formatted_token.end().into();
+	}
+
+	diff_text(
+		source,
+		source_offset..source.len(),
+		formatted,
+		formatted_offset..formatted.len(),
+		&mut builder,
+	);
+
+	builder.finish()
+}
+
+/// The number of unchanged lines printed before and after the changed lines of a unified diff
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineChange {
+	Equal,
+	Delete,
+	Insert,
+}
+
+/// Returns the unified diff, as printed by `diff -u`, of the lines that change when `source`
+/// is formatted as `formatted`, or an empty string if the texts are equal. Both files are named
+/// `file_name` in the header.
+pub(crate) fn unified_diff(file_name: &str, source: &str, formatted: &str) -> String {
+	if source == formatted {
+		return String::new();
+	}
+
+	let source_lines: Vec<_> = source.split_inclusive('\n').collect();
+	let formatted_lines: Vec<_> = formatted.split_inclusive('\n').collect();
+
+	// The changed lines together with the line number in the source and in the formatted text
+	let mut changes = vec![];
+	let (mut source_line, mut formatted_line) = (0, 0);
+	let common = longest_common_subsequence(&source_lines, &formatted_lines).unwrap_or_default();
+
+	for (source_end, formatted_end) in common
+		.into_iter()
+		.chain(std::iter::once((source_lines.len(), formatted_lines.len())))
+	{
+		for line in source_line..source_end {
+			changes.push((LineChange::Delete, line, formatted_line));
+		}
+		for line in formatted_line..formatted_end {
+			changes.push((LineChange::Insert, source_end, line));
+		}
+		if source_end < source_lines.len() {
+			changes.push((LineChange::Equal, source_end, formatted_end));
+		}
+
+		source_line = source_end + 1;
+		formatted_line = formatted_end + 1;
+	}
+
+	// Groups the changes that are less than two contexts apart into one hunk
+	let mut hunks: Vec<(usize, usize)> = vec![];
+	for (index, _) in changes
+		.iter()
+		.enumerate()
+		.filter(|(_, (change, _, _))| *change != LineChange::Equal)
+	{
+		let start = index.saturating_sub(CONTEXT_LINES);
+		let end = (index + CONTEXT_LINES + 1).min(changes.len());
+
+		match hunks.last_mut() {
+			Some((_, last_end)) if start <= *last_end => *last_end = end,
+			_ => hunks.push((start, end)),
+		}
+	}
+
+	let mut diff = format!("--- {}\n+++ {}\n", file_name, file_name);
+
+	for (start, end) in hunks {
+		let hunk = &changes[start..end];
+		let source_count = hunk
+			.iter()
+			.filter(|(change, _, _)| *change != LineChange::Insert)
+			.count();
+		let formatted_count = hunk
+			.iter()
+			.filter(|(change, _, _)| *change != LineChange::Delete)
+			.count();
+		let (_, source_start, formatted_start) = hunk[0];
+
+		diff.push_str(&format!(
+			"@@ -{} +{} @@\n",
+			hunk_range(source_start, source_count),
+			hunk_range(formatted_start, formatted_count)
+		));
+
+		for (change, source_line, formatted_line) in hunk {
+			let (prefix, line) = match change {
+				LineChange::Equal => (' ', source_lines[*source_line]),
+				LineChange::Delete => ('-', source_lines[*source_line]),
+				LineChange::Insert => ('+', formatted_lines[*formatted_line]),
+			};
+
+			diff.push(prefix);
+			diff.push_str(line);
+			if !line.ends_with('\n') {
+				diff.push_str("\n\\ No newline at end of file\n");
+			}
+		}
+	}
+
+	diff
+}
+
+/// Returns the `start,count` range of a hunk header with a one-based start line. An empty range
+/// starts at the line before the hunk.
+fn hunk_range(start: usize, count: usize) -> String {
+	if count == 0 {
+		format!("{},0", start)
+	} else {
+		format!("{},{}", start + 1, count)
+	}
+}
+
+/// Returns the ranges of the tokens in `text` that are neither whitespace nor comments
+fn non_trivia_tokens(text: &str) -> Vec<TextRange> {
+	let (tokens, _) = tokenize(text, 0);
+	let mut offset = TextSize::from(0);
+	let mut ranges = Vec::with_capacity(tokens.len());
+
+	for token in tokens {
+		let range = TextRange::at(offset, TextSize::from(token.len as u32));
+		offset = range.end();
+
+		if !token.kind.is_trivia() && token.kind != SyntaxKind::EOF {
+			ranges.push(range);
+		}
+	}
+
+	ranges
+}
+
+/// Adds an indel that replaces `source_range` with the text at `formatted_range` unless they're
+/// equal. Text at the start or end that's equal in both isn't part of the indel.
+fn diff_text(
+	source: &str,
+	source_range: std::ops::Range<usize>,
+	formatted: &str,
+	formatted_range: std::ops::Range<usize>,
+	builder: &mut rslint_text_edit::TextEditBuilder,
+) {
+	let source_text = &source[source_range.clone()];
+	let formatted_text = &formatted[formatted_range];
+
+	if source_text == formatted_text {
+		return;
+	}
+
+	let prefix = common_prefix_len(source_text, formatted_text);
+	let suffix = common_prefix_len(
+		&source_text[prefix..].chars().rev().collect::<String>(),
+		&formatted_text[prefix..].chars().rev().collect::<String>(),
+	);
+
+	let start = source_range.start + prefix;
+	let end = source_range.end - suffix;
+
+	builder.replace(
+		TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32)),
+		formatted_text[prefix..formatted_text.len() - suffix].to_string(),
+	);
+}
+
+/// Returns the length in bytes of the longest common prefix of `left` and `right`
+fn common_prefix_len(left: &str, right: &str) -> usize {
+	left.chars()
+		.zip(right.chars())
+		.take_while(|(left, right)| left == right)
+		.map(|(char, _)| char.len_utf8())
+		.sum()
+}
+
+/// Returns the pairs of indices of the tokens in `source_tokens` and `formatted_tokens` that are
+/// equal and are kept by the formatter.
+fn align(
+	source: &str,
+	source_tokens: &[TextRange],
+	formatted: &str,
+	formatted_tokens: &[TextRange],
+) -> Vec<(usize, usize)> {
+	let source_texts = source_tokens
+		.iter()
+		.map(|range| &source[*range])
+		.collect::<Vec<_>>();
+	let formatted_texts = formatted_tokens
+		.iter()
+		.map(|range| &formatted[*range])
+		.collect::<Vec<_>>();
+
+	let prefix = source_texts
+		.iter()
+		.zip(formatted_texts.iter())
+		.take_while(|(source, formatted)| source == formatted)
+		.count();
+	let suffix = source_texts[prefix..]
+		.iter()
+		.rev()
+		.zip(formatted_texts[prefix..].iter().rev())
+		.take_while(|(source, formatted)| source == formatted)
+		.count();
+
+	let source_middle = &source_texts[prefix..source_texts.len() - suffix];
+	let formatted_middle = &formatted_texts[prefix..formatted_texts.len() - suffix];
+
+	let mut aligned = (0..prefix).map(|index| (index, index)).collect::<Vec<_>>();
+
+	if let Some(middle) = longest_common_subsequence(source_middle, formatted_middle) {
+		aligned.extend(
+			middle
+				.into_iter()
+				.map(|(source, formatted)| (source + prefix, formatted + prefix)),
+		);
+	}
+
+	aligned.extend((0..suffix).map(|index| {
+		(
+			source_texts.len() - suffix + index,
+			formatted_texts.len() - suffix + index,
+		)
+	}));
+
+	aligned
+}
+
+/// Finds the longest common subsequence of `left` and `right` using Myers' diff algorithm and
+/// returns the indices of its elements in `left` and `right`.
+///
+/// Returns `None` if `left` and `right` differ by more than [MAX_TOKEN_EDITS] elements.
+fn longest_common_subsequence<T: PartialEq>(
+	left: &[T],
+	right: &[T],
+) -> Option<Vec<(usize, usize)>> {
+	let n = left.len() as isize;
+	let m = right.len() as isize;
+	let max = ((n + m) as usize).min(MAX_TOKEN_EDITS) as isize;
+	let offset = max + 1;
+
+	// `furthest[k + offset]` is the furthest `x` reached on the diagonal `k = x - y`
+	let mut furthest = vec![0isize; 2 * offset as usize + 1];
+	// `trace[d]` holds the furthest `x` of the diagonals `-d, -d + 2, ..=d` after `d` edits,
+	// the only ones that the step `d` reaches
+	let mut trace: Vec<Vec<isize>> = Vec::new();
+
+	for d in 0..=max {
+		for k in (-d..=d).step_by(2) {
+			let index = (k + offset) as usize;
+			let mut x = if k == -d || (k != d && furthest[index - 1] < furthest[index + 1]) {
+				furthest[index + 1]
+			} else {
+				furthest[index - 1] + 1
+			};
+			let mut y = x - k;
+
+			while x < n && y < m && left[x as usize] == right[y as usize] {
+				x += 1;
+				y += 1;
+			}
+
+			furthest[index] = x;
+
+			if x >= n && y >= m {
+				return Some(backtrack(&trace, k, n, m, left, right));
+			}
+		}
+
+		trace.push(
+			(-d..=d)
+				.step_by(2)
+				.map(|k| furthest[(k + offset) as usize])
+				.collect(),
+		);
+	}
+
+	None
+}
+
+/// Walks the `trace` of the furthest reaching paths backwards, from the path that reached the
+/// end on the diagonal `k` after `trace.len()` edits, and collects the diagonal moves, which are
+/// the elements that are equal in both sequences.
+fn backtrack<T: PartialEq>(
+	trace: &[Vec<isize>],
+	mut k: isize,
+	mut x: isize,
+	mut y: isize,
+	left: &[T],
+	right: &[T],
+) -> Vec<(usize, usize)> {
+	let mut result = Vec::new();
+
+	for d in (0..=trace.len() as isize).rev() {
+		let (previous_k, previous_x) = if d == 0 {
+			(k, 0)
+		} else {
+			// The furthest `x` of the diagonal `k` after `d - 1` edits
+			let previous = &trace[d as usize - 1];
+			let furthest = |k: isize| previous[((k + d - 1) / 2) as usize];
+
+			let previous_k = if k == -d || (k != d && furthest(k - 1) < furthest(k + 1)) {
+				k + 1
+			} else {
+				k - 1
+			};
+			(previous_k, furthest(previous_k))
+		};
+		let previous_y = previous_x - previous_k;
+
+		// The snake: equal elements at the end of the path
+		let (start_x, start_y) = if d == 0 {
+			(0, 0)
+		} else if previous_k == k + 1 {
+			(previous_x, previous_y + 1)
+		} else {
+			(previous_x + 1, previous_y)
+		};
+
+		while x > start_x && y > start_y {
+			x -= 1;
+			y -= 1;
+			debug_assert!(left[x as usize] == right[y as usize]);
+			result.push((x as usize, y as usize));
+		}
+
+		x = previous_x;
+		y = previous_y;
+		k = previous_k;
+	}
+
+	result.reverse();
+	result
+}
+
+#[cfg(test)]
+mod test {
+	use super::{longest_common_subsequence, text_edit, unified_diff};
+
+	fn apply(source: &str, formatted: &str) -> String {
+		let mut result = source.to_string();
+		text_edit(source, formatted).apply(&mut result);
+		result
+	}
+
+	#[test]
+	fn equal_texts_result_in_an_empty_edit() {
+		assert!(text_edit("let a = 1;\n", "let a = 1;\n").is_empty());
+	}
+
+	#[test]
+	fn only_changes_the_whitespace_between_tokens() {
+		let edit = text_edit("let   a=1;\n", "let a = 1;\n");
+		let indels = edit.iter().collect::<Vec<_>>();
+
+		assert_eq!(indels.len(), 3);
+		assert_eq!(indels[0].insert, "");
+		assert_eq!(
+			indels[0].delete,
+			rslint_parser::TextRange::new(4.into(), 6.into())
+		);
+		assert_eq!(indels[1].insert, " ");
+		assert_eq!(indels[2].insert, " ");
+		assert_eq!(apply("let   a=1;\n", "let a = 1;\n"), "let a = 1;\n");
+	}
+
+	#[test]
+	fn inserts_added_tokens() {
+		let edit = text_edit("a()\nb()\n", "a();\nb();\n");
+
+		assert_eq!(edit.len(), 2);
+		assert!(edit.iter().all(|indel| indel.insert == ";"));
+		assert_eq!(apply("a()\nb()\n", "a();\nb();\n"), "a();\nb();\n");
+	}
+
+	#[test]
+	fn removes_deleted_tokens() {
+		assert_eq!(apply("a = (b);\n", "a = b;\n"), "a = b;\n");
+	}
+
+	#[test]
+	fn moves_comments() {
+		assert_eq!(
+			apply("a( /* c */ b  )\n", "a(/* c */ b);\n"),
+			"a(/* c */ b);\n"
+		);
+	}
+
+	#[test]
+	fn handles_multi_byte_characters() {
+		assert_eq!(apply("let ä='ö'  ;\n", "let ä = 'ö';\n"), "let ä = 'ö';\n");
+	}
+
+	#[test]
+	fn unified_diff_of_changed_lines() {
+		let source = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+		let formatted = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl";
+
+		assert_eq!(
+			unified_diff("test.js", source, formatted),
+			"--- test.js\n+++ test.js\n\
+			@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n\
+			@@ -9,3 +9,4 @@\n i\n j\n k\n+l\n\\ No newline at end of file\n"
+		);
+	}
+
+	#[test]
+	fn unified_diff_of_equal_texts_is_empty() {
+		assert_eq!(unified_diff("test.js", "a\n", "a\n"), "");
+	}
+
+	#[test]
+	fn longest_common_subsequence_of_sequences() {
+		let left = ["a", "b", "c", "a", "b", "b", "a"];
+		let right = ["c", "b", "a", "b", "a", "c"];
+
+		let lcs = longest_common_subsequence(&left, &right).unwrap();
+
+		assert_eq!(lcs.len(), 4);
+		assert!(lcs.iter().all(|(l, r)| left[*l] == right[*r]));
+		assert!(lcs
+			.windows(2)
+			.all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+	}
+
+	#[test]
+	fn longest_common_subsequence_is_the_longest() {
+		// a small linear congruential generator, to compare with the quadratic algorithm
+		let mut seed = 7u32;
+		let mut sequence = |len: usize| {
+			(0..len)
+				.map(|_| {
+					seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+					(seed >> 16) % 4
+				})
+				.collect::<Vec<_>>()
+		};
+
+		for len in 0..40 {
+			let left = sequence(len);
+			let right = sequence(len / 2 + 3);
+
+			let mut lengths = vec![vec![0; right.len() + 1]; left.len() + 1];
+			for l in 0..left.len() {
+				for r in 0..right.len() {
+					lengths[l + 1][r + 1] = if left[l] == right[r] {
+						lengths[l][r] + 1
+					} else {
+						lengths[l][r + 1].max(lengths[l + 1][r])
+					};
+				}
+			}
+
+			let lcs = longest_common_subsequence(&left, &right).unwrap();
+			assert_eq!(lcs.len(), lengths[left.len()][right.len()]);
+			assert!(lcs.iter().all(|(l, r)| left[*l] == right[*r]));
+			assert!(lcs
+				.windows(2)
+				.all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+		}
+	}
+}
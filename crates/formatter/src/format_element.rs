@@ -1,5 +1,6 @@
-use crate::format_elements;
 use crate::intersperse::Intersperse;
+use crate::{format_elements, FormatOptions};
+use rslint_parser::TextRange;
 use std::ops::Deref;
 
 type Content = Box<FormatElement>;
@@ -154,6 +155,29 @@ pub fn token(text: &str) -> FormatElement {
 	}
 }
 
+/// Marks the range in the source text of the content that follows the marker. The marker
+/// doesn't print anything but the printer uses it to map the printed output back to the source text.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_elements, format_element, source_marker, token, FormatOptions, SourceMapping};
+/// use rslint_parser::{TextRange, TextSize};
+///
+/// let range = TextRange::new(TextSize::from(10), TextSize::from(13));
+/// let elements = format_elements![token("let"), source_marker(range), token(" a")];
+///
+/// let result = format_element(&elements, FormatOptions::default());
+///
+/// assert_eq!(
+///   &[SourceMapping { source: range, generated: TextSize::from(3) }],
+///   result.source_mappings()
+/// );
+/// ```
+pub fn source_marker(source: TextRange) -> FormatElement {
+	FormatElement::SourceMarker(SourceMarker { source })
+}
+
 /// Inserts a single space. Allows to separate different tokens.
 ///
 /// ## Examples
@@ -575,6 +599,96 @@ where
 	}
 }
 
+/// Fills the line with as many of the `elements` as fit on it before breaking the line. The
+/// elements are separated by the `separator`, which is printed flat if the next element fits
+/// on the current line and with its line breaks otherwise. Every element is printed flat if it
+/// fits on a line.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{fill_elements, format_element, format_elements, token, soft_line_break_or_space, FormatOptions};
+///
+/// let elements = fill_elements(
+///   format_elements![token(","), soft_line_break_or_space()],
+///   vec![token("100"), token("200"), token("300"), token("400"), token("500")],
+/// );
+///
+/// let options = FormatOptions { line_width: 10, ..FormatOptions::default() };
+/// assert_eq!("100, 200,\n300, 400,\n500", format_element(&elements, options).code());
+/// ```
+pub fn fill_elements<TSep, I>(separator: TSep, elements: I) -> FormatElement
+where
+	TSep: Into<FormatElement>,
+	I: IntoIterator<Item = FormatElement>,
+{
+	let content = elements
+		.into_iter()
+		.filter(|element| !element.is_empty())
+		.collect::<Vec<_>>();
+
+	match content.len() {
+		0 => empty_element(),
+		1 => content.into_iter().next().unwrap(),
+		_ => FormatElement::from(Fill::new(content, separator.into())),
+	}
+}
+
+/// Defers the printing of the content to the end of the line, right before the next line break.
+/// Mostly used for line comments that must stay at the end of the line of the token they follow.
+///
+/// The content doesn't count towards the width of the line when the printer checks if a [Group]
+/// fits on a single line.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, token, line_suffix, hard_line_break, space_token, FormatOptions};
+///
+/// let elements = format_elements![
+///   token("a"),
+///   line_suffix(format_elements![space_token(), token("// comment")]),
+///   token(";"),
+///   hard_line_break(),
+///   token("b;"),
+/// ];
+///
+/// assert_eq!("a; // comment\nb;", format_element(&elements, FormatOptions::default()).code());
+/// ```
+pub fn line_suffix<T: Into<FormatElement>>(content: T) -> FormatElement {
+	let content = content.into();
+
+	if content.is_empty() {
+		content
+	} else {
+		FormatElement::from(LineSuffix::new(content))
+	}
+}
+
+/// Forces all the groups that enclose it to be printed over multiple lines, without printing
+/// anything itself. Used together with a [line_suffix] that must not be moved past the content
+/// that follows it on the same line, e.g. a line comment in the middle of a list.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, group_elements, token, line_suffix, break_parent, soft_line_break_or_space, space_token, FormatOptions};
+///
+/// let elements = group_elements(format_elements![
+///   token("a,"),
+///   line_suffix(format_elements![space_token(), token("// comment")]),
+///   break_parent(),
+///   soft_line_break_or_space(),
+///   token("b"),
+/// ]);
+///
+/// assert_eq!("a, // comment\nb", format_element(&elements, FormatOptions::default()).code());
+/// ```
+#[inline]
+pub const fn break_parent() -> FormatElement {
+	FormatElement::BreakParent
+}
+
 /// Language agnostic IR for formatting source code.
 ///
 /// Use the helper functions like [space], [soft_line_break] etc. defined in this file to create elements.
@@ -605,8 +719,20 @@ pub enum FormatElement {
 	/// Concatenates multiple elements together. See [concat_elements] and [join_elements] for examples.
 	List(List),
 
+	/// Packs as many elements as possible on every line, see [fill_elements] for documentation.
+	Fill(Fill),
+
+	/// Content that is printed at the end of the line, see [line_suffix] for documentation.
+	LineSuffix(LineSuffix),
+
+	/// Forces the enclosing groups to break, see [break_parent] for documentation.
+	BreakParent,
+
 	/// A token that should be printed as is, see [token] for documentation and examples.
 	Token(Token),
+
+	/// Marks the source range of the content that follows, see [source_marker] for documentation.
+	SourceMarker(SourceMarker),
 }
 
 /// Inserts a new line
@@ -665,6 +791,36 @@ impl Deref for List {
 	}
 }
 
+/// The elements that get packed on as few lines as possible, see [fill_elements].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fill {
+	pub(crate) content: Vec<FormatElement>,
+	pub(crate) separator: Content,
+}
+
+impl Fill {
+	pub fn new(content: Vec<FormatElement>, separator: FormatElement) -> Self {
+		Self {
+			content,
+			separator: Box::new(separator),
+		}
+	}
+}
+
+/// Content that gets printed before the next line break, see [line_suffix].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LineSuffix {
+	pub(crate) content: Content,
+}
+
+impl LineSuffix {
+	pub fn new(content: FormatElement) -> Self {
+		Self {
+			content: Box::new(content),
+		}
+	}
+}
+
 /// Group is a special token that controls how the child tokens are printed.
 ///
 /// The printer first tries to print all tokens in the group onto a single line (ignoring soft line wraps)
@@ -682,9 +838,10 @@ impl Group {
 	}
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum GroupPrintMode {
 	Flat,
+	#[default]
 	Multiline,
 }
 
@@ -707,6 +864,12 @@ impl ConditionalGroupContent {
 	}
 }
 
+/// See [source_marker] for documentation
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourceMarker {
+	pub(crate) source: TextRange,
+}
+
 /// See [token] for documentation
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Token(String);
@@ -731,6 +894,105 @@ impl FormatElement {
 	pub fn is_empty(&self) -> bool {
 		self == &FormatElement::Empty
 	}
+
+	/// Returns the elements that print the IR of this element in the syntax of its [Display]
+	/// implementation.
+	///
+	/// [Display]: std::fmt::Display
+	fn to_debug_element(&self) -> FormatElement {
+		match self {
+			FormatElement::Empty => token("empty"),
+			FormatElement::Space => token("space"),
+			FormatElement::Line(line) => token(match line.mode {
+				LineMode::SoftOrSpace => "line",
+				LineMode::Soft => "softline",
+				LineMode::Hard => "hardline",
+			}),
+			FormatElement::Indent(indent) => debug_call("indent", &indent.content),
+			FormatElement::Group(group) => debug_call("group", &group.content),
+			FormatElement::ConditionalGroupContent(content) => {
+				let name = match content.mode {
+					GroupPrintMode::Flat => "if_group_fits_on_single_line",
+					GroupPrintMode::Multiline => "if_group_breaks",
+				};
+				debug_call(name, &content.content)
+			}
+			FormatElement::List(list) => {
+				let mut elements = list
+					.iter()
+					.filter(|element| !matches!(element, FormatElement::SourceMarker(_)))
+					.map(FormatElement::to_debug_element)
+					.collect::<Vec<_>>();
+
+				if elements.len() == 1 {
+					elements.pop().unwrap()
+				} else {
+					debug_list(elements)
+				}
+			}
+			FormatElement::Fill(fill) => group_elements(format_elements![
+				token("fill("),
+				fill.separator.to_debug_element(),
+				token(","),
+				space_token(),
+				debug_list(
+					fill.content
+						.iter()
+						.map(FormatElement::to_debug_element)
+						.collect()
+				),
+				token(")")
+			]),
+			FormatElement::LineSuffix(suffix) => debug_call("line_suffix", &suffix.content),
+			FormatElement::BreakParent => token("breakParent"),
+			FormatElement::Token(content) => token(&format!("{:?}", content.as_str())),
+			FormatElement::SourceMarker(marker) => {
+				token(&format!("source_marker({:?})", marker.source))
+			}
+		}
+	}
+}
+
+/// Prints the IR in a syntax similar to the documents of Prettier, e.g. `group([ "a", softline ])`,
+/// which helps to understand why the printer breaks a group or not.
+///
+/// The IR is laid out by the [Printer](crate::Printer) itself, so that nested elements are only
+/// broken across multiple lines if they don't fit on a single one. Lists omit the source markers
+/// because they don't change how the content gets printed.
+impl std::fmt::Display for FormatElement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let printed = crate::format_element(&self.to_debug_element(), FormatOptions::default());
+		f.write_str(printed.code())
+	}
+}
+
+/// Prints `content` as the argument of the IR function `name`: `group("a")`
+fn debug_call(name: &str, content: &FormatElement) -> FormatElement {
+	group_elements(format_elements![
+		token(name),
+		token("("),
+		content.to_debug_element(),
+		token(")")
+	])
+}
+
+/// Prints the `elements` of a list separated by commas: `[ "a", space, "b" ]`
+fn debug_list(elements: Vec<FormatElement>) -> FormatElement {
+	if elements.is_empty() {
+		return token("[]");
+	}
+
+	let separator = format_elements![token(","), soft_line_break_or_space()];
+
+	group_elements(format_elements![
+		token("["),
+		indent(format_elements![
+			soft_line_break_or_space(),
+			join_elements(separator, elements)
+		]),
+		soft_line_break_or_space(),
+		token("]")
+	])
 }
 
 impl From<Group> for FormatElement {
@@ -757,6 +1019,18 @@ impl From<Line> for FormatElement {
 	}
 }
 
+impl From<Fill> for FormatElement {
+	fn from(fill: Fill) -> Self {
+		FormatElement::Fill(fill)
+	}
+}
+
+impl From<LineSuffix> for FormatElement {
+	fn from(suffix: LineSuffix) -> Self {
+		FormatElement::LineSuffix(suffix)
+	}
+}
+
 impl From<Indent> for FormatElement {
 	fn from(token: Indent) -> Self {
 		FormatElement::Indent(token)
@@ -767,7 +1041,12 @@ impl From<Indent> for FormatElement {
 mod tests {
 
 	use crate::format_element::{empty_element, join_elements, List};
-	use crate::{concat_elements, space_token, token, FormatElement};
+	use crate::{
+		concat_elements, fill_elements, format_elements, group_elements, hard_line_break,
+		if_group_breaks, line_suffix, soft_indent, soft_line_break_or_space, source_marker,
+		space_token, token, FormatElement,
+	};
+	use rslint_parser::TextRange;
 
 	#[test]
 	fn concat_elements_returns_a_list_token_containing_the_passed_in_elements() {
@@ -874,4 +1153,53 @@ mod tests {
 			]))
 		);
 	}
+
+	#[test]
+	fn display_prints_the_ir_like_a_prettier_document() {
+		let element = group_elements(format_elements![
+			token("a"),
+			soft_indent(format_elements![token("b"), soft_line_break_or_space()]),
+			if_group_breaks(token(",")),
+		]);
+
+		assert_eq!(
+			element.to_string(),
+			r#"group([ "a", indent([ softline, "b", line ]), softline, if_group_breaks(",") ])"#
+		);
+	}
+
+	#[test]
+	fn display_omits_source_markers_and_escapes_tokens() {
+		let element = format_elements![
+			source_marker(TextRange::new(0.into(), 3.into())),
+			token("\"a\nb\""),
+			hard_line_break(),
+		];
+
+		assert_eq!(element.to_string(), r#"[ "\"a\nb\"", hardline ]"#);
+	}
+
+	#[test]
+	fn display_breaks_lists_that_dont_fit_on_a_single_line() {
+		let long_token = "a".repeat(40);
+		let element = join_elements(space_token(), vec![token(&long_token), token(&long_token)]);
+
+		assert_eq!(
+			element.to_string(),
+			format!("[\n\t\"{0}\",\n\tspace,\n\t\"{0}\"\n]", long_token)
+		);
+	}
+
+	#[test]
+	fn display_prints_fills_and_line_suffixes() {
+		let element = format_elements![
+			fill_elements(soft_line_break_or_space(), vec![token("1"), token("2")]),
+			line_suffix(token("// a")),
+		];
+
+		assert_eq!(
+			element.to_string(),
+			r#"[ fill(line, [ "1", "2" ]), line_suffix("// a") ]"#
+		);
+	}
 }
@@ -1,214 +1,212 @@
-use crate::format_element::{join_elements, soft_line_break_or_space};
+//! Formatting of the JSON tree created by [rslint_parser::parse_json] and its JSONC and JSON5
+//! variants.
+//!
+//! A node that contains a syntax error, e.g. the trailing comma in `[1,]`, isn't formatted but
+//! printed as it is in the source, see [Formatter::format_node].
 use crate::{
-	format_element::FormatElement, format_elements, group_elements, hard_line_break, soft_indent,
-	space_token, token,
+	empty_element, format_elements, group_elements, hard_line_break, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{
-	ArrayExpr, GroupingExpr, Literal, LiteralProp, ObjectExpr, ObjectProp, UnaryExpr,
+	JsonArray, JsonBoolean, JsonIdentifier, JsonKey, JsonMember, JsonNull, JsonNumber, JsonObject,
+	JsonRoot, JsonString, JsonValue,
 };
-use rslint_parser::{parse_text, AstNode, SyntaxKind, SyntaxNode, SyntaxToken};
-
-fn tokenize_token(syntax_token: SyntaxToken) -> FormatElement {
-	match syntax_token.kind() {
-		SyntaxKind::NULL_KW => token("null"),
-		SyntaxKind::TRUE_KW => token("true"),
-		SyntaxKind::FALSE_KW => token("false"),
-		SyntaxKind::STRING => token(syntax_token.text()),
-		SyntaxKind::NUMBER => token(syntax_token.text()),
-		SyntaxKind::MINUS => token("-"),
-		_ => panic!("Unsupported JSON token {:?}", syntax_token),
+
+impl ToFormatElement for JsonRoot {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.value()?)?,
+			hard_line_break()
+		])
 	}
 }
 
-fn tokenize_node(node: SyntaxNode) -> FormatElement {
-	match node.kind() {
-		SyntaxKind::LITERAL => {
-			let literal = Literal::cast(node).unwrap();
-			tokenize_token(literal.token())
-		}
-		SyntaxKind::UNARY_EXPR => {
-			let expr = UnaryExpr::cast(node).unwrap();
-			format_elements![
-				tokenize_token(expr.op_token().unwrap()),
-				tokenize_node(expr.expr().unwrap().syntax().clone())
-			]
+impl ToFormatElement for JsonValue {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			JsonValue::JsonObject(object) => object.to_format_element(formatter),
+			JsonValue::JsonArray(array) => array.to_format_element(formatter),
+			JsonValue::JsonString(string) => string.to_format_element(formatter),
+			JsonValue::JsonNumber(number) => number.to_format_element(formatter),
+			JsonValue::JsonBoolean(boolean) => boolean.to_format_element(formatter),
+			JsonValue::JsonNull(null) => null.to_format_element(formatter),
 		}
+	}
+}
 
-		SyntaxKind::LITERAL_PROP => {
-			let prop = LiteralProp::cast(node).unwrap();
-			format_elements![
-				tokenize_node(prop.key().unwrap().syntax().clone()),
-				token(":"),
-				space_token(),
-				tokenize_node(prop.value().unwrap().syntax().clone()),
-			]
-		}
+impl ToFormatElement for JsonObject {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let members = formatter.format_separated(self.members())?;
 
-		SyntaxKind::OBJECT_EXPR => {
-			let object = ObjectExpr::cast(node).unwrap();
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), members)),
+			formatter.format_token(&self.r_curly_token()?)?,
+		]))
+	}
+}
 
-			let separator = format_elements![token(","), soft_line_break_or_space()];
+impl ToFormatElement for JsonMember {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.key()?)?,
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.value()?)?,
+		])
+	}
+}
 
-			let properties_list: Vec<FormatElement> = object
-				.props()
-				.map(|prop| match prop {
-					ObjectProp::LiteralProp(prop) => {
-						format_elements![tokenize_node(prop.syntax().clone())]
-					}
-					_ => panic!("Unsupported prop type {:?}", prop),
-				})
-				.collect();
+impl ToFormatElement for JsonArray {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let elements = formatter.format_separated(self.elements())?;
 
-			let properties = join_elements(separator, properties_list);
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_brack_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), elements)),
+			formatter.format_token(&self.r_brack_token()?)?,
+		]))
+	}
+}
 
-			group_elements(format_elements![
-				token("{"),
-				soft_indent(properties),
-				token("}"),
-			])
+impl ToFormatElement for JsonKey {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			JsonKey::JsonString(string) => string.to_format_element(formatter),
+			JsonKey::JsonIdentifier(identifier) => identifier.to_format_element(formatter),
 		}
-		SyntaxKind::ARRAY_EXPR => {
-			let array = ArrayExpr::cast(node).unwrap();
-
-			let separator = format_elements![token(","), soft_line_break_or_space(),];
-
-			let elements = join_elements(
-				separator,
-				array
-					.elements()
-					.map(|element| tokenize_node(element.syntax().clone())),
-			);
-
-			group_elements(format_elements![
-				token("["),
-				soft_indent(elements),
-				token("]"),
-			])
-		}
-		_ => panic!("Unsupported JSON kind: {:?}", node.kind()),
 	}
 }
 
-pub fn tokenize_json(content: &str) -> FormatElement {
-	let script = parse_text(format!("({})", content).as_str(), 0);
-
-	// Unwrap the grouping to get to the JSON content. The grouping is only used as a trick to parse JSON
-	let json_content = GroupingExpr::cast(
-		script
-			.syntax()
-			.descendants()
-			.find(|e| e.kind() == SyntaxKind::GROUPING_EXPR)
-			.unwrap(),
-	)
-	.and_then(|grouping| grouping.inner())
-	.unwrap();
-
-	let tokenized_content = tokenize_node(json_content.syntax().clone());
-	format_elements![tokenized_content, hard_line_break()]
+impl ToFormatElement for JsonIdentifier {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		formatter.format_token(&self.name_token()?)
+	}
+}
+
+impl ToFormatElement for JsonString {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		formatter.format_token(&self.value_token()?)
+	}
+}
+
+impl ToFormatElement for JsonNumber {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let minus = match self.minus_token() {
+			Some(minus) => formatter.format_token(&minus)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![
+			minus,
+			formatter.format_token(&self.value_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for JsonBoolean {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let token = self.true_token().or_else(|| self.false_token())?;
+		formatter.format_token(&token)
+	}
+}
+
+impl ToFormatElement for JsonNull {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		formatter.format_token(&self.null_token()?)
+	}
 }
 
 #[cfg(test)]
 mod test {
-	use crate::{
-		format_elements, group_elements, hard_line_break, soft_line_break,
-		soft_line_break_or_space, space_token, token,
-	};
-
-	use super::tokenize_json;
-	use crate::format_element::Indent;
+	use crate::{format_text, FormatOptions};
+	use core::file_handlers::Language;
+	use core::App;
+
+	fn format_json(input: &str, line_width: u16) -> String {
+		let options = FormatOptions {
+			line_width,
+			..FormatOptions::default()
+		};
+
+		format_text(input, Language::Json, options, &App::new())
+			.unwrap()
+			.code()
+			.to_string()
+	}
 
 	#[test]
-	fn tokenize_number() {
-		let result = tokenize_json("6.45");
-
-		assert_eq!(format_elements![token("6.45"), hard_line_break()], result);
+	fn format_number() {
+		assert_eq!(format_json("6.45", 80), "6.45\n");
+		assert_eq!(format_json("-6.45", 80), "-6.45\n");
 	}
 
 	#[test]
-	fn tokenize_string() {
-		let result = tokenize_json(r#""foo""#);
+	fn format_string() {
+		assert_eq!(format_json(r#""foo""#, 80), "\"foo\"\n");
+	}
 
-		assert_eq!(
-			format_elements![token(r#""foo""#), hard_line_break()],
-			result
-		);
+	#[test]
+	fn format_boolean_false() {
+		assert_eq!(format_json("false", 80), "false\n");
 	}
 
 	#[test]
-	fn tokenize_boolean_false() {
-		let result = tokenize_json("false");
+	fn format_boolean_true() {
+		assert_eq!(format_json("true", 80), "true\n");
+	}
 
-		assert_eq!(format_elements![token("false"), hard_line_break()], result);
+	#[test]
+	fn format_null() {
+		assert_eq!(format_json("null", 80), "null\n");
 	}
 
 	#[test]
-	fn tokenize_boolean_true() {
-		let result = tokenize_json("true");
+	fn format_object() {
+		let input = r#"{ "foo": "bar", "num": 5 }"#;
 
-		assert_eq!(format_elements![token("true"), hard_line_break()], result);
+		assert_eq!(format_json(input, 80), "{\"foo\": \"bar\", \"num\": 5}\n");
+		assert_eq!(
+			format_json(input, 10),
+			"{\n\t\"foo\": \"bar\",\n\t\"num\": 5\n}\n"
+		);
 	}
 
 	#[test]
-	fn tokenize_boolean_null() {
-		let result = tokenize_json("null");
+	fn format_array() {
+		let input = r#"[ "foo", "bar", 5 ]"#;
 
-		assert_eq!(format_elements![token("null"), hard_line_break()], result);
+		assert_eq!(format_json(input, 80), "[\"foo\", \"bar\", 5]\n");
+		assert_eq!(
+			format_json(input, 10),
+			"[\n\t\"foo\",\n\t\"bar\",\n\t5\n]\n"
+		);
 	}
 
 	#[test]
-	fn tokenize_object() {
-		let input = r#"{ "foo": "bar", "num": 5 }"#;
-		let expected = format_elements![
-			group_elements(format_elements![
-				token("{"),
-				FormatElement::Indent(Indent::new(format_elements![
-					soft_line_break(),
-					token("\"foo\""),
-					token(":"),
-					space_token(),
-					token("\"bar\""),
-					token(","),
-					soft_line_break_or_space(),
-					token("\"num\""),
-					token(":"),
-					space_token(),
-					token("5"),
-				])),
-				soft_line_break(),
-				token("}"),
-			]),
-			hard_line_break(),
-		];
-
-		let result = tokenize_json(input);
-
-		assert_eq!(expected, result);
+	fn keeps_invalid_json_as_is() {
+		assert_eq!(format_json("[1,]", 80), "[1,]\n");
+		assert_eq!(
+			format_json("{ \"a\": [ 1,2 ], b: 1 }", 80),
+			"{\"a\": [1, 2], b: 1}\n"
+		);
 	}
 
 	#[test]
-	fn tokenize_array() {
-		let input = r#"[ "foo", "bar", 5 ]"#;
-		let expected = format_elements![
-			group_elements(format_elements![
-				token("["),
-				FormatElement::Indent(Indent::new(format_elements![
-					soft_line_break(),
-					token("\"foo\""),
-					token(","),
-					soft_line_break_or_space(),
-					token("\"bar\""),
-					token(","),
-					soft_line_break_or_space(),
-					token("5"),
-				])),
-				soft_line_break(),
-				token("]"),
-			]),
-			hard_line_break(),
-		];
-
-		let result = tokenize_json(input);
-
-		assert_eq!(expected, result);
+	fn returns_the_syntax_errors() {
+		let result = format_text(
+			"[1,]",
+			Language::Json,
+			FormatOptions::default(),
+			&App::new(),
+		)
+		.unwrap();
+		let titles = result
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| diagnostic.title.as_str())
+			.collect::<Vec<_>>();
+
+		assert_eq!(titles, vec!["trailing commas aren't allowed in JSON"]);
 	}
 }
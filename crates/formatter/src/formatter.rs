@@ -1,21 +1,51 @@
+use crate::comments::{
+	first_non_trivia_token, has_file_suppression_comment, has_suppression_comment,
+	is_closing_bracket, is_last_token_of_statement, is_opening_bracket, last_non_trivia_token,
+	leading_comments, non_trivia_range, trailing_comments,
+};
 use crate::printer::Printer;
-use crate::{concat_elements, token, FormatElement, FormatOptions, FormatResult, ToFormatElement};
+use crate::{
+	break_parent, concat_elements, empty_element, format_elements, hard_line_break, indent,
+	join_elements, line_suffix, source_marker, space_token, token, FormatElement, FormatOptions,
+	FormatResult, ToFormatElement,
+};
+use core::embedded_formatters::EmbeddedFormatter;
+use core::App;
 use rome_rowan::SyntaxElement;
 use rslint_parser::ast::AstChildren;
-use rslint_parser::{AstNode, SyntaxNode, SyntaxToken};
+use rslint_parser::util::CommentKind;
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
 
 /// Handles the formatting of a CST and stores the options how the CST should be formatted (user preferences).
 /// The formatter is passed to the [ToFormatElement] implementation of every node in the CST so that they
 /// can use it to format their children.
 #[derive(Debug, Default)]
-pub struct Formatter {
+pub struct Formatter<'app> {
 	options: FormatOptions,
+	/// The app providing the formatters of languages embedded in template literals
+	app: Option<&'app App>,
+	/// The comments that have already been printed. Comments are attached to tokens and
+	/// the same token may be visited by multiple nodes (e.g. the last token of an expression
+	/// is also the last token of the expression statement).
+	printed_comments: RefCell<PrintedComments>,
 }
 
-impl Formatter {
+impl<'app> Formatter<'app> {
 	/// Creates a new context that uses the given formatter options
 	pub fn new(options: FormatOptions) -> Self {
-		Self { options }
+		Self {
+			options,
+			app: None,
+			printed_comments: RefCell::default(),
+		}
+	}
+
+	/// Formats the languages embedded in tagged templates with the formatters registered in `app`
+	pub fn with_app(mut self, app: &'app App) -> Self {
+		self.app = Some(app);
+		self
 	}
 
 	/// Returns the [FormatOptions] specifying how to format the current CST
@@ -24,50 +54,145 @@ impl Formatter {
 		&self.options
 	}
 
+	/// Returns the formatter registered for the templates tagged with `tag`, if any
+	pub(crate) fn embedded_formatter(&self, tag: &str) -> Option<&dyn EmbeddedFormatter> {
+		self.app?.get_embedded_formatter(tag)
+	}
+
 	/// Formats a CST
+	///
+	/// A file starting with a `// rome-ignore-file format: <reason>` comment is left as it is.
+	/// [crate::LineEndingStyle::Auto] prints `\n` line endings, [crate::format_text] resolves it with
+	/// the source text before creating the formatter.
 	pub fn format_root(self, root: &SyntaxNode) -> FormatResult {
-		let element = self
-			.format_syntax_node(root)
-			.unwrap_or_else(|| self.format_raw(root));
+		if has_file_suppression_comment(root) {
+			return FormatResult::new(&root.text().to_string());
+		}
+
+		let element = self.format_root_element(root);
 
 		let printer = Printer::new(self.options);
 		printer.print(&element)
 	}
 
+	/// Returns the [FormatElement] IR that [Formatter::format_root] prints for the CST,
+	/// e.g. to inspect why the printer breaks a group, see the [Display](std::fmt::Display)
+	/// implementation of [FormatElement].
+	pub fn format_root_element(&self, root: &SyntaxNode) -> FormatElement {
+		if has_file_suppression_comment(root) {
+			return verbatim_token(&root.text().to_string());
+		}
+
+		// A file always ends with a new line, even if it is printed as is
+		self.format_syntax_node(root)
+			.unwrap_or_else(|| format_elements![self.format_raw(root), hard_line_break()])
+	}
+
 	fn format_syntax_node(&self, node: &SyntaxNode) -> Option<FormatElement> {
-		let start = self.format_node_start(node);
-		let content = node.to_format_element(self)?;
-		Some(concat_elements(vec![
-			start,
-			content,
-			self.format_node_end(node),
-		]))
+		if has_error_child(node) {
+			return Some(self.format_raw(node));
+		}
+
+		self.format_with_rollback(|| {
+			let start = self.format_node_start(node);
+			let content = node.to_format_element(self)?;
+			Some(concat_elements(vec![
+				start,
+				content,
+				self.format_node_end(node),
+			]))
+		})
 	}
 
 	/// Recursively formats the ast node and all its children
 	///
 	/// Returns `None` if the node couldn't be formatted because of syntax errors in its sub tree.
 	/// The parent may use `format_raw` to insert the node content as is.
+	///
+	/// A node with a leading `// rome-ignore format: <reason>` comment is printed as it is in the
+	/// source, e.g. to keep the alignment of a table. So is a node with an error node child because
+	/// its formatting would drop the error node.
 	pub fn format_node<T: AstNode + ToFormatElement>(&self, node: T) -> Option<FormatElement> {
-		Some(concat_elements(vec![
-			self.format_node_start(node.syntax()),
-			node.to_format_element(self)?,
-			self.format_node_end(node.syntax()),
-		]))
+		if has_suppression_comment(node.syntax()) || has_error_child(node.syntax()) {
+			return Some(self.format_raw(node.syntax()));
+		}
+
+		self.format_with_rollback(|| {
+			Some(concat_elements(vec![
+				self.format_node_start(node.syntax()),
+				node.to_format_element(self)?,
+				self.format_node_end(node.syntax()),
+			]))
+		})
+	}
+
+	/// Runs `format` and forgets about all comments it printed if it returns `None`,
+	/// so that the caller can print them when falling back to `format_raw`.
+	fn format_with_rollback<F>(&self, format: F) -> Option<FormatElement>
+	where
+		F: FnOnce() -> Option<FormatElement>,
+	{
+		let checkpoint = self.printed_comments.borrow().checkpoint();
+		let result = format();
+
+		if result.is_none() {
+			self.printed_comments.borrow_mut().rollback(checkpoint);
+		}
+
+		result
 	}
 
 	/// Helper function that returns what should be printed before the node that work on
 	/// the non-generic [SyntaxNode] to avoid unrolling the logic for every [AstNode] type.
-	fn format_node_start(&self, _node: &SyntaxNode) -> FormatElement {
-		// TODO: Set the marker for the start source map location, add leading comments, ...
-		concat_elements(vec![])
+	fn format_node_start(&self, node: &SyntaxNode) -> FormatElement {
+		match first_non_trivia_token(node) {
+			Some(first) => format_elements![
+				self.format_leading_comments(&first),
+				source_marker(non_trivia_range(node).unwrap_or_else(|| first.text_range()))
+			],
+			None => empty_element(),
+		}
 	}
 
 	/// Helper function that returns what should be printed after the node that work on
 	/// the non-generic [SyntaxNode] to avoid unrolling the logic for every [AstNode] type.
-	fn format_node_end(&self, _node: &SyntaxNode) -> FormatElement {
-		// TODO: Sets the marker for the end source map location, add trailing comments, ...
-		concat_elements(vec![])
+	fn format_node_end(&self, node: &SyntaxNode) -> FormatElement {
+		let range = non_trivia_range(node).unwrap_or_else(|| node.text_range());
+
+		// Comments inside of the node that haven't been printed because the node's
+		// formatting skips the token they're attached to.
+		let dangling = self
+			.printed_comments
+			.borrow_mut()
+			.unprinted_comments(node, range);
+
+		let dangling = concat_elements(dangling.into_iter().map(|token| {
+			self.mark_comment_printed(&token);
+
+			let has_line_break_before = match token.prev_token() {
+				Some(previous) => previous.text().contains('\n'),
+				None => true,
+			};
+			let follows_opening_bracket = previous_non_trivia_token(&token)
+				.is_some_and(|previous| is_opening_bracket(previous.kind()));
+
+			if token.text().starts_with("//") {
+				format_line_comment(format_comment(&token), has_line_break_before, true)
+			} else if has_line_break_before {
+				format_elements![hard_line_break(), format_comment(&token)]
+			} else if follows_opening_bracket {
+				format_comment(&token)
+			} else {
+				format_elements![space_token(), format_comment(&token)]
+			}
+		}));
+
+		let trailing = match last_non_trivia_token(node) {
+			Some(last) => self.format_trailing_comments(&last),
+			None => empty_element(),
+		};
+
+		format_elements![dangling, trailing]
 	}
 
 	/// Formats the passed in token.
@@ -97,7 +222,89 @@ impl Formatter {
 	/// assert_eq!(Some(token("=>")), result)
 	/// ```
 	pub fn format_token(&self, syntax_token: &SyntaxToken) -> Option<FormatElement> {
-		syntax_token.to_format_element(self)
+		Some(format_elements![
+			self.format_leading_comments(syntax_token),
+			syntax_token.to_format_element(self)?,
+			self.format_trailing_comments(syntax_token)
+		])
+	}
+
+	/// Formats the comments that precede the token and haven't been printed yet.
+	fn format_leading_comments(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		let mut elements = vec![];
+
+		for comment in leading_comments(syntax_token) {
+			if !self.mark_comment_printed(comment.token()) {
+				continue;
+			}
+
+			elements.push(format_comment(comment.token()));
+			elements.push(if comment.has_line_break_after() {
+				hard_line_break()
+			} else if is_closing_bracket(syntax_token.kind()) {
+				// The only comment of an empty list or object: `call(/* empty */)`
+				empty_element()
+			} else {
+				space_token()
+			});
+		}
+
+		concat_elements(elements)
+	}
+
+	/// Formats the comments that follow the token and haven't been printed yet.
+	fn format_trailing_comments(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		let mut elements = vec![];
+		let mut has_own_line_comment = false;
+
+		for comment in trailing_comments(syntax_token) {
+			if !self.mark_comment_printed(comment.token()) {
+				continue;
+			}
+
+			let content = format_comment(comment.token());
+
+			if comment.has_line_break_before() && is_opening_bracket(syntax_token.kind()) {
+				// The comments inside of an empty block or list
+				elements.push(indent(format_elements![hard_line_break(), content]));
+				has_own_line_comment = true;
+			} else if comment.kind() == CommentKind::Inline {
+				// A comment at the end of a statement doesn't break the groups that end before it,
+				// e.g. the arguments of `call(a, b) // comment`
+				elements.push(format_line_comment(
+					content,
+					comment.has_line_break_before(),
+					!is_last_token_of_statement(syntax_token),
+				));
+			} else if comment.has_line_break_before() {
+				elements.push(hard_line_break());
+				elements.push(content);
+			} else {
+				elements.push(space_token());
+				elements.push(content);
+			}
+		}
+
+		// The closing bracket goes on its own line, like the comments
+		if has_own_line_comment {
+			elements.push(hard_line_break());
+		}
+
+		concat_elements(elements)
+	}
+
+	/// Formats the comments of a token that isn't printed, e.g. the trailing comma of a list
+	/// that the printer replaces with its own.
+	fn format_skipped_token_comments(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		format_elements![
+			self.format_leading_comments(syntax_token),
+			self.format_trailing_comments(syntax_token)
+		]
+	}
+
+	/// Marks the comment as printed. Returns `false` if the comment has been printed before.
+	fn mark_comment_printed(&self, comment: &SyntaxToken) -> bool {
+		self.printed_comments.borrow_mut().insert(comment)
 	}
 
 	/// Formats each child and returns the result as a list.
@@ -120,6 +327,46 @@ impl Formatter {
 		Some(result.into_iter())
 	}
 
+	/// Formats each child of a comma separated list and appends the separator token following
+	/// the child in the source (if any) to every but the last child. Using the separator tokens of the
+	/// source text ensures that comments around them are preserved.
+	///
+	/// Returns [None] if a child couldn't be formatted.
+	pub fn format_separated<T: AstNode + ToFormatElement>(
+		&self,
+		children: impl IntoIterator<Item = T>,
+	) -> Option<impl Iterator<Item = FormatElement>> {
+		let mut result = Vec::new();
+		let mut children = children.into_iter().peekable();
+
+		while let Some(child) = children.next() {
+			let separator = last_non_trivia_token(child.syntax())
+				.and_then(|last| next_non_trivia_token(&last))
+				.filter(|next| next.kind() == SyntaxKind::COMMA);
+
+			let formatted = self.format_node(child)?;
+
+			if children.peek().is_some() {
+				let separator = match separator {
+					Some(separator) => self.format_token(&separator)?,
+					None => token(","),
+				};
+				result.push(format_elements![formatted, separator]);
+			} else if let Some(separator) = separator {
+				// The trailing comma of the source isn't printed, the comments around it stay
+				// after the last child, in front of the comma that the caller may print
+				result.push(format_elements![
+					formatted,
+					self.format_skipped_token_comments(&separator)
+				]);
+			} else {
+				result.push(formatted);
+			}
+		}
+
+		Some(result.into_iter())
+	}
+
 	/// "Formats" a node according to its original formatting in the source text. Being able to format
 	/// a node "as is" is useful if a node contains syntax errors. Formatting a node with syntax errors
 	/// has the risk that Rome misinterprets the structure of the code and formatting it could
@@ -128,13 +375,212 @@ impl Formatter {
 	/// You may be inclined to call `node.text` directly. However, using `text` doesn't track the nodes
 	///nor its children source mapping information, resulting in incorrect source maps for this subtree.
 	pub fn format_raw(&self, node: &SyntaxNode) -> FormatElement {
+		let range = match non_trivia_range(node) {
+			Some(range) => range,
+			None => return self.format_node_end(node),
+		};
+
+		// The leading and trailing trivia of the node are handled the same as for formatted nodes
+		concat_elements(vec![
+			self.format_node_start(node),
+			self.format_raw_range(node, range),
+			self.format_node_end(node),
+		])
+	}
+
+	fn format_raw_range(&self, node: &SyntaxNode, range: TextRange) -> FormatElement {
 		concat_elements(node.children_with_tokens().map(|child| match child {
-			SyntaxElement::Node(child_node) => {
-				// TODO: Add source map markers before/after node as well as any additional elements that
-				// need to be tracked for every node.
-				self.format_raw(&child_node)
+			SyntaxElement::Node(child_node) => self.format_raw_range(&child_node, range),
+			SyntaxElement::Token(syntax_token) => {
+				if !range.contains_range(syntax_token.text_range())
+					|| (syntax_token.kind() == SyntaxKind::COMMENT
+						&& !self.mark_comment_printed(&syntax_token))
+				{
+					empty_element()
+				} else {
+					format_elements![
+						source_marker(syntax_token.text_range()),
+						verbatim_token(syntax_token.text())
+					]
+				}
 			}
-			SyntaxElement::Token(syntax_token) => token(syntax_token.text()),
 		}))
 	}
 }
+
+/// Creates a token for text that is printed as it is in the source. The source may use `\r\n` or `\r`
+/// line breaks but tokens only use `\n`, the printer inserts the configured line ending instead.
+pub(crate) fn verbatim_token(text: &str) -> FormatElement {
+	if text.contains('\r') {
+		token(&text.replace("\r\n", "\n").replace('\r', "\n"))
+	} else {
+		token(text)
+	}
+}
+
+/// Formats a `//` comment, which ends at the end of its line. The comment is printed right before
+/// the next line break, so that the code that follows it in the IR isn't commented out. If
+/// `break_groups` is `true`, the enclosing groups break, so that the comment stays close to the
+/// code it follows instead of moving past the code that follows it on the same line.
+fn format_line_comment(
+	content: FormatElement,
+	has_line_break_before: bool,
+	break_groups: bool,
+) -> FormatElement {
+	let comment = if has_line_break_before {
+		format_elements![hard_line_break(), line_suffix(content)]
+	} else {
+		line_suffix(format_elements![space_token(), content])
+	};
+
+	if break_groups {
+		format_elements![comment, break_parent()]
+	} else {
+		comment
+	}
+}
+
+/// Formats the text of a comment. Multiline comments where every line starts with a `*`
+/// are re-indented to the current indention level, any other comment is printed as is.
+fn format_comment(comment: &SyntaxToken) -> FormatElement {
+	let text = comment.text();
+	let lines = text.lines().collect::<Vec<_>>();
+
+	if lines.len() > 1
+		&& lines[1..]
+			.iter()
+			.all(|line| line.trim_start().starts_with('*'))
+	{
+		let first = lines[0].trim_end();
+		let rest = lines[1..]
+			.iter()
+			.map(|line| token(&format!(" {}", line.trim())));
+
+		join_elements(hard_line_break(), std::iter::once(token(first)).chain(rest))
+	} else {
+		verbatim_token(text)
+	}
+}
+
+/// Returns `true` if the parser wrapped some of the children of `node` in an error node, e.g. the
+/// second comma in `call(a,,b)`.
+///
+/// Statement lists are an exception because `format_statements` prints the errors between the
+/// statements, unless the list only consists of errors.
+fn has_error_child(node: &SyntaxNode) -> bool {
+	let mut children = node.children().map(|child| child.kind());
+
+	if is_statement_list(node.kind()) {
+		let (errors, statements): (Vec<_>, Vec<_>) =
+			children.partition(|kind| *kind == SyntaxKind::ERROR);
+		!errors.is_empty() && statements.is_empty()
+	} else {
+		children.any(|kind| kind == SyntaxKind::ERROR)
+	}
+}
+
+/// Returns `true` for the nodes whose children are statements or module items
+pub(crate) fn is_statement_list(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::SCRIPT
+			| SyntaxKind::MODULE
+			| SyntaxKind::BLOCK_STMT
+			| SyntaxKind::TS_MODULE_BLOCK
+	)
+}
+
+fn next_non_trivia_token(token: &SyntaxToken) -> Option<SyntaxToken> {
+	let mut current = token.next_token();
+
+	while let Some(next) = current {
+		if !next.kind().is_trivia() {
+			return Some(next);
+		}
+		current = next.next_token();
+	}
+
+	None
+}
+
+fn previous_non_trivia_token(token: &SyntaxToken) -> Option<SyntaxToken> {
+	let mut current = token.prev_token();
+
+	while let Some(previous) = current {
+		if !previous.kind().is_trivia() {
+			return Some(previous);
+		}
+		current = previous.prev_token();
+	}
+
+	None
+}
+
+/// Tracks which comments have been printed so far and allows to roll back to an earlier state
+/// if formatting a node fails and the formatter falls back to [Formatter::format_raw].
+#[derive(Debug, Default)]
+struct PrintedComments {
+	/// The start offsets of the printed comments
+	printed: HashSet<TextSize>,
+	/// The printed comments in the order they've been added
+	log: Vec<SyntaxToken>,
+	/// The comments of the CST that haven't been printed yet by their start offset, collected
+	/// the first time that [PrintedComments::unprinted_comments] is called
+	unprinted: Option<BTreeMap<TextSize, SyntaxToken>>,
+}
+
+impl PrintedComments {
+	/// Marks the `comment` as printed. Returns `false` if it was printed before.
+	fn insert(&mut self, comment: &SyntaxToken) -> bool {
+		let offset = comment.text_range().start();
+		let inserted = self.printed.insert(offset);
+
+		if inserted {
+			self.log.push(comment.clone());
+			if let Some(unprinted) = &mut self.unprinted {
+				unprinted.remove(&offset);
+			}
+		}
+
+		inserted
+	}
+
+	/// Returns the comments inside of `range` of the CST of `node` that haven't been printed
+	fn unprinted_comments(&mut self, node: &SyntaxNode, range: TextRange) -> Vec<SyntaxToken> {
+		let printed = &self.printed;
+		let unprinted = self.unprinted.get_or_insert_with(|| {
+			let root = node.ancestors().last().unwrap_or_else(|| node.clone());
+			root.descendants_with_tokens()
+				.filter_map(|element| element.into_token())
+				.filter(|token| {
+					token.kind() == SyntaxKind::COMMENT
+						&& !printed.contains(&token.text_range().start())
+				})
+				.map(|token| (token.text_range().start(), token))
+				.collect()
+		});
+
+		unprinted
+			.range(range.start()..range.end())
+			.map(|(_, token)| token)
+			.filter(|token| range.contains_range(token.text_range()))
+			.cloned()
+			.collect()
+	}
+
+	fn checkpoint(&self) -> usize {
+		self.log.len()
+	}
+
+	/// Forgets all comments printed after the checkpoint was taken
+	fn rollback(&mut self, checkpoint: usize) {
+		for comment in self.log.drain(checkpoint..) {
+			let offset = comment.text_range().start();
+			self.printed.remove(&offset);
+
+			if let Some(unprinted) = &mut self.unprinted {
+				unprinted.insert(offset, comment);
+			}
+		}
+	}
+}
@@ -47,52 +47,59 @@
 //! ```
 //! [IR]: https://en.wikipedia.org/wiki/Intermediate_representation
 
+mod comments;
 mod cst;
+mod diff;
 mod format_element;
 mod format_elements;
 mod format_json;
 mod formatter;
 mod intersperse;
 mod printer;
+mod range;
+mod source_map;
 mod ts;
-
-use crate::format_json::tokenize_json;
+mod verify;
 
 pub use formatter::Formatter;
 
 use core::file_handlers::Language;
 use core::App;
 pub use format_element::{
-	block_indent, concat_elements, empty_element, group_elements, hard_line_break, if_group_breaks,
-	if_group_fits_on_single_line, indent, join_elements, soft_indent, soft_line_break,
-	soft_line_break_or_space, space_token, token, FormatElement,
+	block_indent, break_parent, concat_elements, empty_element, fill_elements, group_elements,
+	hard_line_break, if_group_breaks, if_group_fits_on_single_line, indent, join_elements,
+	line_suffix, soft_indent, soft_line_break, soft_line_break_or_space, source_marker,
+	space_token, token, FormatElement,
 };
-use path::RomePath;
+use path::{FileError, RomePath};
 pub use printer::Printer;
-pub use printer::PrinterOptions;
-use rslint_parser::parse_text;
-use std::io::Read;
+pub use printer::{LineEnding, PrinterOptions};
+pub use range::format_range;
+use rslint_errors::file::FileId;
+use rslint_errors::Diagnostic;
+use rslint_parser::{
+	parse_json, parse_json5, parse_jsonc, parse_module, parse_text, parse_with_syntax, Parse,
+	Syntax, SyntaxNode,
+};
+use rslint_text_edit::TextEdit;
+pub use source_map::{SourceMap, SourceMapping};
 use std::str::FromStr;
+pub use verify::verify_format;
 
 /// This trait should be implemented on each node/value that should have a formatted representation
 pub trait ToFormatElement {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement>;
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
 pub enum IndentStyle {
 	/// Tab
+	#[default]
 	Tab,
 	/// Space, with its quantity
 	Space(u8),
 }
 
-impl Default for IndentStyle {
-	fn default() -> Self {
-		Self::Tab
-	}
-}
-
 impl FromStr for IndentStyle {
 	type Err = &'static str;
 
@@ -106,13 +113,186 @@ impl FromStr for IndentStyle {
 	}
 }
 
-#[derive(Debug)]
+/// The quotes to use for string literals
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum QuoteStyle {
+	/// Double quotes: `"rome"`
+	#[default]
+	Double,
+	/// Single quotes: `'rome'`
+	Single,
+}
+
+impl QuoteStyle {
+	/// Returns the quote character
+	pub fn as_char(&self) -> char {
+		match self {
+			QuoteStyle::Double => '"',
+			QuoteStyle::Single => '\'',
+		}
+	}
+
+	/// Returns the other quote style
+	pub fn other(&self) -> Self {
+		match self {
+			QuoteStyle::Double => QuoteStyle::Single,
+			QuoteStyle::Single => QuoteStyle::Double,
+		}
+	}
+}
+
+impl FromStr for QuoteStyle {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"double" => Ok(Self::Double),
+			"single" => Ok(Self::Single),
+			// TODO: replace this error with a diagnostic
+			_ => Err("Value not supported for QuoteStyle"),
+		}
+	}
+}
+
+/// Where to print the semicolons that terminate statements
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum Semicolons {
+	/// Terminates every statement with a semicolon
+	#[default]
+	Always,
+	/// Only prints the semicolons that are needed to prevent automatic semicolon insertion
+	/// from changing the meaning of the code, e.g. before a statement that starts with a `(`
+	AsNeeded,
+}
+
+impl FromStr for Semicolons {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"always" => Ok(Self::Always),
+			"as-needed" => Ok(Self::AsNeeded),
+			// TODO: replace this error with a diagnostic
+			_ => Err("Value not supported for Semicolons"),
+		}
+	}
+}
+
+/// The lists that have a trailing comma when they're broken across multiple lines.
+/// The variants are ordered: every variant adds trailing commas to more lists than the previous one.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Default)]
+pub enum TrailingComma {
+	/// No trailing commas
+	None,
+	/// Trailing commas where they're valid in ES5: arrays, objects, imports, exports and enums
+	#[default]
+	Es5,
+	/// Trailing commas in all lists, including the parameters and arguments of functions
+	All,
+}
+
+impl FromStr for TrailingComma {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"none" => Ok(Self::None),
+			"es5" => Ok(Self::Es5),
+			"all" => Ok(Self::All),
+			// TODO: replace this error with a diagnostic
+			_ => Err("Value not supported for TrailingComma"),
+		}
+	}
+}
+
+/// The line endings of the formatted code
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum LineEndingStyle {
+	/// Line Feed only (\n)
+	#[default]
+	Lf,
+	/// Carriage Return + Line Feed characters (\r\n)
+	Crlf,
+	/// Carriage Return character only (\r)
+	Cr,
+	/// Uses the line ending that is the most common in the source text
+	Auto,
+}
+
+impl LineEndingStyle {
+	/// Returns the line ending that is used for formatting `source`: the detected line ending of
+	/// `source` for [LineEndingStyle::Auto] and the line ending itself otherwise.
+	pub fn resolve(self, source: &str) -> Self {
+		if self != LineEndingStyle::Auto {
+			return self;
+		}
+
+		let mut lf = 0;
+		let mut crlf = 0;
+		let mut cr = 0;
+		let mut chars = source.chars().peekable();
+
+		while let Some(char) = chars.next() {
+			match char {
+				'\r' if chars.peek() == Some(&'\n') => {
+					chars.next();
+					crlf += 1;
+				}
+				'\r' => cr += 1,
+				'\n' => lf += 1,
+				_ => {}
+			}
+		}
+
+		// prefers `\n` if the source has no line breaks or if it is a tie
+		if crlf > lf && crlf >= cr {
+			LineEndingStyle::Crlf
+		} else if cr > lf && cr > crlf {
+			LineEndingStyle::Cr
+		} else {
+			LineEndingStyle::Lf
+		}
+	}
+}
+
+impl FromStr for LineEndingStyle {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"lf" => Ok(Self::Lf),
+			"crlf" => Ok(Self::Crlf),
+			"cr" => Ok(Self::Cr),
+			"auto" => Ok(Self::Auto),
+			// TODO: replace this error with a diagnostic
+			_ => Err("Value not supported for LineEndingStyle"),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct FormatOptions {
 	/// The indent style
 	pub indent_style: IndentStyle,
 
 	/// What's the max width of a line. Defaults to 80
 	pub line_width: u16,
+
+	/// The quotes of string literals. Defaults to double quotes
+	pub quote_style: QuoteStyle,
+
+	/// Whether statements are always terminated with a semicolon. Defaults to always
+	pub semicolons: Semicolons,
+
+	/// The lists that get a trailing comma if they're broken across multiple lines. Defaults to es5
+	pub trailing_comma: TrailingComma,
+
+	/// Whether to print spaces between the braces and the members of an object
+	/// if it fits on a single line: `{ a: 1 }`. Defaults to false
+	pub bracket_spacing: bool,
+
+	/// The line endings of the formatted code. Defaults to `\n`
+	pub line_ending: LineEndingStyle,
 }
 
 impl FormatOptions {
@@ -129,76 +309,286 @@ impl Default for FormatOptions {
 		Self {
 			indent_style: IndentStyle::default(),
 			line_width: 80,
+			quote_style: QuoteStyle::default(),
+			semicolons: Semicolons::default(),
+			trailing_comma: TrailingComma::default(),
+			bracket_spacing: false,
+			line_ending: LineEndingStyle::default(),
 		}
 	}
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FormatResult {
 	code: String,
+	source_mappings: Vec<SourceMapping>,
+	diagnostics: Vec<Diagnostic>,
 }
 
 impl FormatResult {
 	pub fn new(code: &str) -> Self {
 		Self {
 			code: String::from(code),
+			source_mappings: Vec::new(),
+			diagnostics: Vec::new(),
 		}
 	}
 
 	pub fn code(&self) -> &String {
 		&self.code
 	}
+
+	/// Returns the syntax errors of the source. The nodes containing the errors have been printed
+	/// as they are in the source, the rest of the code has been formatted.
+	pub fn diagnostics(&self) -> &[Diagnostic] {
+		&self.diagnostics
+	}
+
+	/// Returns the mappings from positions in the formatted code to the ranges in the source text
+	pub fn source_mappings(&self) -> &[SourceMapping] {
+		&self.source_mappings
+	}
+
+	/// Creates a Source Map v3 that maps the formatted code back to the `source` it has been formatted from.
+	///
+	/// `file_name` is used as the name of the formatted and the source file.
+	pub fn source_map(&self, file_name: &str, source: &str) -> SourceMap {
+		SourceMap::new(
+			file_name,
+			file_name,
+			source,
+			&self.code,
+			&self.source_mappings,
+		)
+	}
+
+	/// Returns the edit that turns the `source` this result has been formatted from into the
+	/// formatted code. The edit consists of minimal indels that only touch the changed text,
+	/// e.g. to apply the formatting in an editor without moving the cursor.
+	pub fn text_edit(&self, source: &str) -> TextEdit {
+		diff::text_edit(source, &self.code)
+	}
+
+	/// Returns the unified diff of the lines that change when the `source` this result has been
+	/// formatted from is replaced by the formatted code, or an empty string if nothing changes.
+	///
+	/// `file_name` is used as the name of the source and the formatted file.
+	pub fn unified_diff(&self, file_name: &str, source: &str) -> String {
+		diff::unified_diff(file_name, source, &self.code)
+	}
 }
 
-// TODO: implement me + handle errors
-/// Main function
-pub fn format(rome_path: &mut RomePath, options: FormatOptions) -> Option<FormatResult> {
-	// we assume that file exists
-	let mut file = rome_path.open();
-	let mut buffer = String::new();
-	// we assume we have permissions
-	file.read_to_string(&mut buffer)
-		.expect("cannot read the file to format");
+/// The reasons why a file can't be formatted
+#[derive(Debug)]
+pub enum FormatError {
+	/// The file can't be read or written
+	File(FileError),
+	/// The code can't be read from a stream other than a file, e.g. stdin
+	Io(std::io::Error),
+	/// The formatter doesn't support the language of the file
+	UnsupportedLanguage,
+}
 
-	if let Some(handler) = rome_path.get_handler() {
-		if handler.capabilities().format {
-			let result = match handler.language() {
-				Language::Js => {
-					let parsed_result = parse_text(buffer.as_str(), 0);
-					Some(Formatter::new(options).format_root(&parsed_result.syntax()))
-				}
-				Language::Json => {
-					let element = tokenize_json(buffer.as_str());
-					Some(format_element(&element, options))
-				}
-				Language::Ts | Language::Unknown => None,
-			};
+impl FormatError {
+	/// Returns a diagnostic for the error in the file `file_id`
+	pub fn to_diagnostic(&self, file_id: FileId) -> Diagnostic {
+		match self {
+			FormatError::File(error) => error.to_diagnostic(file_id),
+			_ => Diagnostic::error(file_id, "FormatterError", self.to_string()),
+		}
+	}
+}
 
-			result
-		} else {
-			None
+impl std::fmt::Display for FormatError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			FormatError::File(error) => write!(f, "{}", error),
+			FormatError::Io(error) => write!(f, "{}", error),
+			FormatError::UnsupportedLanguage => {
+				write!(
+					f,
+					"the language of the file isn't supported by the formatter"
+				)
+			}
 		}
-	} else {
-		None
 	}
 }
 
-pub fn format_file_and_save(rome_path: &mut RomePath, options: FormatOptions) {
-	let result = format(rome_path, options);
-	if let Some(result) = result {
-		rome_path
-			.save(result.code())
-			.expect("Could not write the formatted code on file");
+impl std::error::Error for FormatError {}
+
+impl From<FileError> for FormatError {
+	fn from(error: FileError) -> Self {
+		FormatError::File(error)
+	}
+}
+
+impl From<std::io::Error> for FormatError {
+	fn from(error: std::io::Error) -> Self {
+		FormatError::Io(error)
+	}
+}
+
+/// Formats the file at `rome_path` with the language of its handler.
+///
+/// Syntax errors don't prevent the file from being formatted, see [format_text].
+pub fn format(
+	rome_path: &mut RomePath,
+	options: FormatOptions,
+	app: &App,
+) -> Result<FormatResult, FormatError> {
+	let (text, language) = read_file(rome_path)?;
+	format_text(&text, language, options, app)
+}
+
+/// Returns the [FormatElement] IR of the file at `rome_path`, see [format_text_element].
+pub fn format_file_element(
+	rome_path: &mut RomePath,
+	options: FormatOptions,
+	app: &App,
+) -> Result<FormatElement, FormatError> {
+	let (text, language) = read_file(rome_path)?;
+	format_text_element(&text, language, options, app)
+}
+
+/// Reads the file at `rome_path` and returns its content together with the language of its handler
+fn read_file(rome_path: &mut RomePath) -> Result<(String, Language), FormatError> {
+	let text = rome_path.read_to_string()?;
+
+	match rome_path.get_handler() {
+		Some(handler) if handler.capabilities().format => Ok((text, handler.language())),
+		_ => Err(FormatError::UnsupportedLanguage),
 	}
 }
 
-pub fn format_file(path_to_file: &str, options: FormatOptions, app: &App) -> FormatResult {
+/// Formats the source `text` written in `language`.
+///
+/// A file with syntax errors is still formatted: the nodes that contain errors are printed as they
+/// are in the source and the errors are returned as the [FormatResult::diagnostics] in the file `0`.
+///
+/// The code of other languages embedded in tagged templates, e.g. ``css`color: red;` ``, is
+/// formatted with the formatters registered in the `app`.
+pub fn format_text(
+	text: &str,
+	language: Language,
+	options: FormatOptions,
+	app: &App,
+) -> Result<FormatResult, FormatError> {
+	let options = FormatOptions {
+		line_ending: options.line_ending.resolve(text),
+		..options
+	};
+
+	let parse = parse(text, language).ok_or(FormatError::UnsupportedLanguage)?;
+	let mut result = Formatter::new(options)
+		.with_app(app)
+		.format_root(&parse.syntax());
+	result.diagnostics = parse.errors().to_vec();
+
+	Ok(result)
+}
+
+/// Returns the [FormatElement] IR that [format_text] prints for the source `text` written in
+/// `language`. Its [Display](std::fmt::Display) implementation helps to understand how the code
+/// gets formatted.
+pub fn format_text_element(
+	text: &str,
+	language: Language,
+	options: FormatOptions,
+	app: &App,
+) -> Result<FormatElement, FormatError> {
+	let parse = parse(text, language).ok_or(FormatError::UnsupportedLanguage)?;
+	Ok(Formatter::new(options)
+		.with_app(app)
+		.format_root_element(&parse.syntax()))
+}
+
+/// Parses the source `text` written in `language`.
+///
+/// Returns `None` if the language isn't supported by the formatter.
+fn parse(text: &str, language: Language) -> Option<Parse<SyntaxNode>> {
+	match language {
+		Language::Js => Some(parse_js(text)),
+		Language::Json => Some(parse_json(text, 0).to_syntax()),
+		Language::Jsonc => Some(parse_jsonc(text, 0).to_syntax()),
+		Language::Json5 => Some(parse_json5(text, 0).to_syntax()),
+		Language::Ts => Some(parse_ts(text)),
+		Language::Unknown => None,
+	}
+}
+
+/// Parses the source as a module, unless it is only valid as a script, e.g. because it uses
+/// `with` statements that aren't allowed in strict mode.
+fn parse_js(text: &str) -> Parse<SyntaxNode> {
+	let module = parse_module(text, 0);
+
+	if !module.errors().is_empty() {
+		let script = parse_text(text, 0);
+
+		if script.errors().is_empty() {
+			return script.to_syntax();
+		}
+	}
+
+	module.to_syntax()
+}
+
+/// Parses the source as a TypeScript module
+fn parse_ts(text: &str) -> Parse<SyntaxNode> {
+	let parse = parse_with_syntax(text, 0, Syntax::default().typescript());
+	let errors = parse.errors().to_vec();
+
+	Parse::new(parse.green(), errors)
+}
+
+/// Formats the file at `rome_path` and writes the formatted code to it
+pub fn format_file_and_save(
+	rome_path: &mut RomePath,
+	options: FormatOptions,
+	app: &App,
+) -> Result<FormatResult, FormatError> {
+	let result = format(rome_path, options, app)?;
+	rome_path.save(result.code())?;
+	Ok(result)
+}
+
+pub fn format_file(
+	path_to_file: &str,
+	options: FormatOptions,
+	app: &App,
+) -> Result<FormatResult, FormatError> {
 	let mut rome_path = RomePath::new(path_to_file).deduce_handler(app);
-	let element = format(&mut rome_path, options);
-	element.unwrap()
+	format(&mut rome_path, options, app)
 }
 
 pub fn format_element(element: &FormatElement, options: FormatOptions) -> FormatResult {
 	let printer = Printer::new(options);
 	printer.print(element)
 }
+
+#[cfg(test)]
+mod test {
+	use super::{format_file_and_save, FormatError, FormatOptions};
+	use core::create_app;
+	use core::file_system::MemoryFileSystem;
+	use path::{FileError, RomePath};
+
+	#[test]
+	fn formats_and_saves_files_of_the_file_system_of_the_app() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("src/a.js", "let  a=1");
+		let mut app = create_app();
+		app.set_file_system(Box::new(file_system.clone()));
+
+		let mut file = RomePath::new("src/a.js").deduce_handler(&app);
+		let result = format_file_and_save(&mut file, FormatOptions::default(), &app).unwrap();
+
+		assert_eq!(result.code(), "let a = 1;\n");
+		assert_eq!(file_system.get("src/a.js").unwrap(), b"let a = 1;\n");
+
+		let mut missing = RomePath::new("src/b.js").deduce_handler(&app);
+		assert!(matches!(
+			format_file_and_save(&mut missing, FormatOptions::default(), &app),
+			Err(FormatError::File(FileError::Read(_)))
+		));
+	}
+}
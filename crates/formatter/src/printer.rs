@@ -1,5 +1,12 @@
-use crate::format_element::{ConditionalGroupContent, Group, GroupPrintMode, LineMode};
-use crate::{FormatElement, FormatOptions, FormatResult, IndentStyle};
+use crate::format_element::{
+	ConditionalGroupContent, Fill, Group, GroupPrintMode, Indent, Line, LineMode, LineSuffix,
+	SourceMarker,
+};
+use crate::{
+	FormatElement, FormatOptions, FormatResult, IndentStyle, LineEndingStyle, SourceMapping,
+};
+use rslint_parser::{TextRange, TextSize};
+use std::collections::HashMap;
 
 /// Options that affect how the [Printer] prints the format tokens
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -31,16 +38,22 @@ impl From<FormatOptions> for PrinterOptions {
 			IndentStyle::Space(width) => indent_string = " ".repeat(width as usize),
 		};
 
+		// the formatter resolves `auto` with the source text, there's no source to detect it from here
+		let line_ending = match options.line_ending {
+			LineEndingStyle::Lf | LineEndingStyle::Auto => LineEnding::LineFeed,
+			LineEndingStyle::Crlf => LineEnding::CarriageReturnLineFeed,
+			LineEndingStyle::Cr => LineEnding::CarriageReturn,
+		};
+
 		PrinterOptions {
 			indent_string,
 			tab_width,
 			print_width: options.line_width,
-			..PrinterOptions::default()
+			line_ending,
 		}
 	}
 }
 
-#[allow(dead_code)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LineEnding {
 	///  Line Feed only (\n), common on Linux and macOS as well as inside git repos
@@ -75,14 +88,12 @@ impl Default for PrinterOptions {
 	}
 }
 
-/// Error returned if printing an item as a flat string fails because it either contains
-/// explicit line breaks or would otherwise exceed the specified line width.
-struct LineBreakRequiredError;
-
 /// Prints the format elements into a string
 #[derive(Debug, Clone, Default)]
 pub struct Printer {
 	options: PrinterOptions,
+	/// The indention printed at the start of every line but the first, see [Printer::with_base_indention]
+	base_indention: String,
 	state: PrinterState,
 }
 
@@ -90,21 +101,41 @@ impl Printer {
 	pub fn new<T: Into<PrinterOptions>>(options: T) -> Self {
 		Self {
 			options: options.into(),
+			base_indention: String::new(),
 			state: PrinterState::default(),
 		}
 	}
 
+	/// Prints `indention` as it is at the start of every line but the first, before the indention
+	/// of the line. Used when the printed code is inserted after `indention` into existing code.
+	pub(crate) fn with_base_indention(mut self, indention: &str) -> Self {
+		self.state.line_width = self.str_width(indention);
+		self.base_indention = String::from(indention);
+		self
+	}
+
 	/// Prints the passed in element as well as all its content
 	pub fn print(mut self, element: &FormatElement) -> FormatResult {
+		self.print_all(element, PrintElementArgs::default());
+		self.flush_line_suffixes();
+		self.flush_source_markers();
+
+		FormatResult {
+			code: self.state.buffer,
+			source_mappings: self.state.source_mappings,
+			diagnostics: Vec::new(),
+		}
+	}
+
+	/// Prints the element and all its content
+	fn print_all(&mut self, element: &FormatElement, args: PrintElementArgs) {
 		let mut queue = ElementCallQueue::new();
 
-		queue.enqueue(PrintElementCall::new(element, PrintElementArgs::default()));
+		queue.enqueue(PrintElementCall::new(element, args));
 
 		while let Some(print_element_call) = queue.dequeue() {
 			queue.extend(self.print_element(print_element_call.element, print_element_call.args));
 		}
-
-		FormatResult::new(self.state.buffer.as_str())
 	}
 
 	/// Prints a single element and returns the elements to queue (that should be printed next).
@@ -118,9 +149,28 @@ impl Printer {
 				self.state.pending_spaces += 1;
 				vec![]
 			}
-			FormatElement::Empty => vec![],
+			FormatElement::Empty | FormatElement::BreakParent => vec![],
+			FormatElement::SourceMarker(SourceMarker { source }) => {
+				self.state.pending_source_markers.push(*source);
+
+				// Resolve the marker immediately unless there's pending whitespace
+				// that gets printed before the content following the marker.
+				if self.state.pending_spaces == 0
+					&& self.state.pending_indent == 0
+					&& !self.state.pending_base_indention
+				{
+					self.flush_source_markers();
+				}
+
+				vec![]
+			}
 			FormatElement::Token(token) => {
 				// Print pending indention
+				if self.state.pending_base_indention {
+					self.print_str(self.base_indention.clone().as_str());
+					self.state.pending_base_indention = false;
+				}
+
 				if self.state.pending_indent > 0 {
 					self.print_str(
 						self.options
@@ -137,18 +187,25 @@ impl Printer {
 					self.state.pending_spaces = 0;
 				}
 
+				self.flush_source_markers();
 				self.print_str(token);
 				vec![]
 			}
 
 			FormatElement::Group(Group { content }) => {
-				match self.try_print_flat(element, args.clone()) {
-					Err(_) => {
-						// Flat printing didn't work, print with line breaks
-						vec![PrintElementCall::new(content.as_ref(), args)]
+				let mode = match args.mode {
+					// The enclosing group fits on the line, and so does all its content
+					GroupPrintMode::Flat => GroupPrintMode::Flat,
+					GroupPrintMode::Multiline if self.fits_on_line(&[element]) => {
+						GroupPrintMode::Flat
 					}
-					Ok(_) => vec![],
-				}
+					GroupPrintMode::Multiline => GroupPrintMode::Multiline,
+				};
+
+				vec![PrintElementCall::new(
+					content.as_ref(),
+					args.with_print_mode(mode),
+				)]
 			}
 
 			FormatElement::List(list) => list
@@ -156,6 +213,31 @@ impl Printer {
 				.map(|t| PrintElementCall::new(t, args.clone()))
 				.collect(),
 
+			FormatElement::Fill(fill) => {
+				if args.mode == GroupPrintMode::Flat {
+					let mut calls = Vec::with_capacity(fill.content.len() * 2);
+
+					for (index, item) in fill.content.iter().enumerate() {
+						if index > 0 {
+							calls.push(PrintElementCall::new(&fill.separator, args.clone()));
+						}
+						calls.push(PrintElementCall::new(item, args.clone()));
+					}
+
+					calls
+				} else {
+					self.print_fill(fill, args);
+					vec![]
+				}
+			}
+
+			FormatElement::LineSuffix(LineSuffix { content }) => {
+				self.state
+					.line_suffixes
+					.push((content.as_ref().clone(), args));
+				vec![]
+			}
+
 			FormatElement::Indent(indent) => {
 				vec![PrintElementCall::new(
 					&indent.content,
@@ -163,113 +245,267 @@ impl Printer {
 				)]
 			}
 
-			FormatElement::ConditionalGroupContent(ConditionalGroupContent {
-				mode: GroupPrintMode::Multiline,
-				content,
-			}) => {
-				vec![PrintElementCall::new(content, args)]
+			FormatElement::ConditionalGroupContent(ConditionalGroupContent { mode, content }) => {
+				if *mode == args.mode {
+					vec![PrintElementCall::new(content, args)]
+				} else {
+					vec![]
+				}
 			}
 
-			FormatElement::ConditionalGroupContent(ConditionalGroupContent {
-				mode: GroupPrintMode::Flat,
-				..
-			}) => {
-				vec![]
-			}
+			FormatElement::Line(line) => {
+				match (args.mode, line.mode) {
+					// We want a flat structure, so omit soft line wraps
+					(GroupPrintMode::Flat, LineMode::Soft) => {}
+					(GroupPrintMode::Flat, LineMode::SoftOrSpace) => {
+						self.state.pending_spaces += 1;
+					}
+					// A group containing a hard line break never fits on a single line,
+					// so hard line breaks are always printed in multiline mode
+					(_, mode) => {
+						self.flush_line_suffixes();
+
+						match mode {
+							// Soft line breaks at the start of a line are omitted, so that an
+							// element that starts with a line break doesn't print an empty line
+							LineMode::Soft | LineMode::SoftOrSpace => {
+								if self.state.generated_column > 0 {
+									self.print_line_break();
+								}
+							}
+							LineMode::Hard => self.print_line_break(),
+						}
+						self.state.pending_spaces = 0;
+						self.state.pending_indent = args.indent;
+					}
+				}
 
-			FormatElement::Line { .. } => {
-				self.print_str("\n");
-				self.state.pending_spaces = 0;
-				self.state.pending_indent = args.indent;
 				vec![]
 			}
 		}
 	}
 
-	/// Tries to print an element without any line breaks. Reverts any made `state` changes (by this function)
-	/// and returns with a [LineBreakRequiredError] if the `element` contains any hard line breaks
-	/// or printing the group exceeds the configured maximal print width.
-	fn try_print_flat(
-		&mut self,
-		element: &FormatElement,
-		args: PrintElementArgs,
-	) -> Result<(), LineBreakRequiredError> {
-		let snapshot = self.state.snapshot();
+	/// Prints as many items of the `fill` on a line as fit on it. The separator is printed flat
+	/// if the following item fits on the current line and with its line breaks otherwise.
+	fn print_fill(&mut self, fill: &Fill, args: PrintElementArgs) {
+		let mut items = fill.content.iter();
 
-		let mut queue = ElementCallQueue::new();
-		queue.enqueue(PrintElementCall::new(element, args));
+		if let Some(first) = items.next() {
+			self.print_flat_or_break(first, args.clone());
+		}
 
-		while let Some(call) = queue.dequeue() {
-			match self.try_print_flat_element(call.element, call.args) {
-				Ok(to_queue) => queue.extend(to_queue),
-				Err(err) => {
-					self.state.restore(snapshot);
-					return Err(err);
-				}
+		for item in items {
+			let separator_mode = if self.fits_on_line(&[&fill.separator, item]) {
+				GroupPrintMode::Flat
+			} else {
+				GroupPrintMode::Multiline
+			};
+
+			self.print_all(
+				&fill.separator,
+				args.clone().with_print_mode(separator_mode),
+			);
+			self.print_flat_or_break(item, args.clone());
+		}
+	}
+
+	/// Prints the element flat if it fits on the current line and with its line breaks otherwise
+	fn print_flat_or_break(&mut self, element: &FormatElement, args: PrintElementArgs) {
+		let mode = if self.fits_on_line(&[element]) {
+			GroupPrintMode::Flat
+		} else {
+			GroupPrintMode::Multiline
+		};
+
+		self.print_all(element, args.with_print_mode(mode));
+	}
+
+	/// Returns `true` if the `elements` fit on the rest of the current line when they're printed flat
+	fn fits_on_line(&mut self, elements: &[&FormatElement]) -> bool {
+		let mut width = FlatWidth::default();
+
+		for element in elements {
+			match self.measure_flat(element) {
+				Some(element_width) => width = width.concat(element_width),
+				None => return false,
 			}
 		}
 
-		Ok(())
+		let content = match width.content {
+			Some(content) => content,
+			// Content without tokens doesn't print anything, not even the pending whitespace
+			None => return true,
+		};
+
+		// The pending indention and spaces are printed before the first token
+		let mut indent =
+			self.state.pending_indent as usize * self.str_width(&self.options.indent_string);
+		if self.state.pending_base_indention {
+			indent += self.str_width(&self.base_indention);
+		}
+		let start = self.state.line_width
+			+ indent + self.state.pending_spaces as usize
+			+ width.leading_spaces;
+
+		start + content <= self.options.print_width as usize
 	}
 
-	fn try_print_flat_element<'a>(
-		&mut self,
-		element: &'a FormatElement,
-		args: PrintElementArgs,
-	) -> Result<Vec<PrintElementCall<'a>>, LineBreakRequiredError> {
-		let next_calls = match element {
-			FormatElement::Token(_) => {
-				let current_line = self.state.generated_line;
+	/// Measures the width of the element if it's printed flat, or returns `None` if it contains
+	/// a hard line break or a multiline token or is wider than a line and can't be printed flat.
+	///
+	/// The widths of the groups are cached, so that the content of every group is only measured
+	/// once, even if it is nested inside of other groups that don't fit on a line. Measuring
+	/// stops as soon as the content exceeds the line width.
+	fn measure_flat(&mut self, element: &FormatElement) -> Option<FlatWidth> {
+		// The line suffixes are clones that are dropped once printed, and so the groups of
+		// a later clone may be at the same addresses as the groups of an earlier one
+		let cache = !self.state.printing_line_suffixes;
+
+		enum Measure<'a> {
+			Element(&'a FormatElement),
+			/// Caches the width of the group and adds it to the width of the content before it
+			GroupEnd(*const Group, FlatWidth),
+		}
 
-				// Delegate to generic string printing
-				let calls = self.print_element(element, args);
+		let mut stack = vec![Measure::Element(element)];
+		let mut width = FlatWidth::default();
 
-				// If the line is too long, break the group
-				if self.state.line_width > self.options.print_width as usize {
-					return Err(LineBreakRequiredError);
+		while let Some(next) = stack.pop() {
+			let element = match next {
+				Measure::Element(element) => element,
+				Measure::GroupEnd(group, before) => {
+					if cache {
+						self.state.flat_widths.insert(group as usize, Some(width));
+					}
+					width = before.concat(width);
+					continue;
 				}
-
-				// If a new line was printed, break the group
-				if current_line != self.state.generated_line {
-					return Err(LineBreakRequiredError);
+			};
+
+			let must_break = match element {
+				FormatElement::Empty
+				| FormatElement::SourceMarker(_)
+				| FormatElement::LineSuffix(_)
+				| FormatElement::Line(Line {
+					mode: LineMode::Soft,
+				})
+				| FormatElement::ConditionalGroupContent(ConditionalGroupContent {
+					mode: GroupPrintMode::Multiline,
+					..
+				}) => false,
+				FormatElement::Space
+				| FormatElement::Line(Line {
+					mode: LineMode::SoftOrSpace,
+				}) => {
+					width = width.concat(FlatWidth::spaces(1));
+					false
 				}
-
-				calls
-			}
-			FormatElement::Line(line) => {
-				match line.mode {
-					LineMode::SoftOrSpace => {
-						self.state.pending_spaces += 1;
-						vec![]
+				FormatElement::Line(Line {
+					mode: LineMode::Hard,
+				})
+				| FormatElement::BreakParent => true,
+				FormatElement::Token(token) => {
+					if token.contains('\n') {
+						true
+					} else {
+						width = width.concat(FlatWidth::token(self.str_width(token)));
+
+						// The content since the start of the innermost group is wider than a line,
+						// neither this group nor the groups enclosing it fit on any line
+						width.leading_spaces + width.content.unwrap_or(0)
+							> self.options.print_width as usize
+					}
+				}
+				FormatElement::Group(group) => {
+					let key = group as *const Group;
+					let cached = if cache {
+						self.state.flat_widths.get(&(key as usize))
+					} else {
+						None
+					};
+
+					match cached {
+						Some(Some(group_width)) => {
+							width = width.concat(*group_width);
+							false
+						}
+						Some(None) => true,
+						None => {
+							stack.push(Measure::GroupEnd(key, width));
+							stack.push(Measure::Element(&group.content));
+							width = FlatWidth::default();
+							false
+						}
+					}
+				}
+				FormatElement::Indent(Indent { content })
+				| FormatElement::ConditionalGroupContent(ConditionalGroupContent {
+					mode: GroupPrintMode::Flat,
+					content,
+				}) => {
+					stack.push(Measure::Element(content));
+					false
+				}
+				FormatElement::List(list) => {
+					stack.extend(list.iter().rev().map(Measure::Element));
+					false
+				}
+				FormatElement::Fill(fill) => {
+					for (index, item) in fill.content.iter().enumerate().rev() {
+						stack.push(Measure::Element(item));
+						if index > 0 {
+							stack.push(Measure::Element(&fill.separator));
+						}
+					}
+					false
+				}
+			};
+
+			if must_break {
+				if cache {
+					// None of the groups that are being measured fits
+					for measure in stack {
+						if let Measure::GroupEnd(group, _) = measure {
+							self.state.flat_widths.insert(group as usize, None);
+						}
 					}
-					// We want a flat structure, so omit soft line wraps
-					LineMode::Soft => vec![],
-					LineMode::Hard => return Err(LineBreakRequiredError),
 				}
-			}
 
-			FormatElement::Group(group) => {
-				vec![PrintElementCall::new(group.content.as_ref(), args)]
+				return None;
 			}
+		}
 
-			FormatElement::ConditionalGroupContent(ConditionalGroupContent {
-				mode: GroupPrintMode::Flat,
-				content,
-			}) => vec![PrintElementCall::new(content, args)],
-
-			// Omit if there's no flat_contents
-			FormatElement::ConditionalGroupContent(ConditionalGroupContent {
-				mode: GroupPrintMode::Multiline,
-				..
-			}) => vec![],
-
-			FormatElement::Empty
-			| FormatElement::Space
-			| FormatElement::Indent { .. }
-			| FormatElement::List { .. } => self.print_element(element, args),
-		};
+		Some(width)
+	}
+
+	/// Prints the pending line suffixes, before the printer moves to the next line
+	fn flush_line_suffixes(&mut self) {
+		let line_suffixes = std::mem::take(&mut self.state.line_suffixes);
+		let printing_line_suffixes =
+			std::mem::replace(&mut self.state.printing_line_suffixes, true);
+
+		for (suffix, args) in line_suffixes {
+			self.print_all(&suffix, args);
+		}
+
+		self.state.printing_line_suffixes = printing_line_suffixes;
+	}
+
+	/// Maps the pending source markers to the current position in the output
+	fn flush_source_markers(&mut self) {
+		let generated = TextSize::of(self.state.buffer.as_str());
+
+		for source in self.state.pending_source_markers.drain(..) {
+			self.state
+				.source_mappings
+				.push(SourceMapping { source, generated });
+		}
+	}
 
-		Ok(next_calls)
+	/// Moves to the next line, whose first token is preceded by the base indention
+	fn print_line_break(&mut self) {
+		self.print_str("\n");
+		self.state.pending_base_indention = !self.base_indention.is_empty();
 	}
 
 	fn print_str(&mut self, content: &str) {
@@ -277,27 +513,29 @@ impl Printer {
 
 		for char in content.chars() {
 			if char == '\n' {
-				for char in self.options.line_ending.as_str().chars() {
-					self.state.generated_index += 1;
-					self.state.buffer.push(char);
-				}
-
-				self.state.generated_line += 1;
+				self.state
+					.buffer
+					.push_str(self.options.line_ending.as_str());
 				self.state.generated_column = 0;
 				self.state.line_width = 0;
 			} else {
 				self.state.buffer.push(char);
-				self.state.generated_index += 1;
 				self.state.generated_column += 1;
+				self.state.line_width += self.char_width(char);
+			}
+		}
+	}
 
-				let char_width = if char == '\t' {
-					self.options.tab_width as usize
-				} else {
-					1
-				};
+	/// Returns the width of a line containing `content`
+	fn str_width(&self, content: &str) -> usize {
+		content.chars().map(|char| self.char_width(char)).sum()
+	}
 
-				self.state.line_width += char_width;
-			}
+	fn char_width(&self, char: char) -> usize {
+		if char == '\t' {
+			self.options.tab_width as usize
+		} else {
+			1
 		}
 	}
 }
@@ -309,53 +547,75 @@ impl Printer {
 struct PrinterState {
 	buffer: String,
 	pending_indent: u16,
+	/// `true` if the base indention gets printed before the next token
+	pending_base_indention: bool,
 	pending_spaces: u16,
-	generated_index: usize,
-	generated_line: usize,
 	generated_column: usize,
 	line_width: usize,
-	// mappings: Mapping[];
-	// We'll need to clone the line suffixes elements into the state.
-	// I guess that's fine. They're only used for comments and should, therefore, be very limited
-	// in size.
-	// lineSuffixes: [FormatElement, PrintElementArgs][];
+	/// The resolved source markers
+	source_mappings: Vec<SourceMapping>,
+	/// Source markers that get resolved when printing the next token
+	pending_source_markers: Vec<TextRange>,
+	/// The line suffixes that get printed before the next line break. The elements are cloned
+	/// into the state, which is fine because they're only used for comments and therefore small.
+	line_suffixes: Vec<(FormatElement, PrintElementArgs)>,
+	/// The flat widths of the measured groups by their address, `None` for the groups that
+	/// can't be printed flat. The printed element is borrowed while printing, so the addresses
+	/// of its groups don't change. This doesn't hold for the cloned line suffixes, whose groups
+	/// are therefore never cached.
+	flat_widths: HashMap<usize, Option<FlatWidth>>,
+	/// `true` while the line suffixes are printed
+	printing_line_suffixes: bool,
 }
 
-impl PrinterState {
-	/// Allows creating a snapshot of the state that can be restored using [restore]
-	pub fn snapshot(&self) -> PrinterStateSnapshot {
-		PrinterStateSnapshot {
-			pending_spaces: self.pending_spaces,
-			pending_indents: self.pending_indent,
-			generated_index: self.generated_index,
-			generated_line: self.generated_line,
-			generated_column: self.generated_column,
-			line_width: self.line_width,
-			buffer_position: self.buffer.len(),
+/// The width of content that gets printed flat. Pending spaces are only printed before the
+/// next token, which is why the spaces before the first token and after the last token of
+/// the content are tracked separately.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+struct FlatWidth {
+	/// The spaces before the first token, or all spaces if the content has no tokens
+	leading_spaces: usize,
+	/// The width from the start of the first token to the end of the last token,
+	/// `None` if the content has no tokens
+	content: Option<usize>,
+	/// The spaces after the last token
+	trailing_spaces: usize,
+}
+
+impl FlatWidth {
+	fn spaces(count: usize) -> Self {
+		Self {
+			leading_spaces: count,
+			..Self::default()
 		}
 	}
 
-	/// Restores the printer state to the state stored in the snapshot.
-	pub fn restore(&mut self, snapshot: PrinterStateSnapshot) {
-		self.pending_spaces = snapshot.pending_spaces;
-		self.pending_indent = snapshot.pending_indents;
-		self.generated_index = snapshot.generated_index;
-		self.generated_column = snapshot.generated_column;
-		self.generated_line = snapshot.generated_line;
-		self.line_width = snapshot.line_width;
-		self.buffer.truncate(snapshot.buffer_position);
+	fn token(width: usize) -> Self {
+		Self {
+			content: Some(width),
+			..Self::default()
+		}
 	}
-}
 
-/// Snapshot of a printer state.
-struct PrinterStateSnapshot {
-	pending_indents: u16,
-	pending_spaces: u16,
-	generated_index: usize,
-	generated_column: usize,
-	generated_line: usize,
-	line_width: usize,
-	buffer_position: usize,
+	/// Returns the width of this content followed by the `next` content
+	fn concat(self, next: FlatWidth) -> Self {
+		match (self.content, next.content) {
+			(None, None) => Self::spaces(self.leading_spaces + next.leading_spaces),
+			(Some(_), None) => Self {
+				trailing_spaces: self.trailing_spaces + next.leading_spaces,
+				..self
+			},
+			(None, Some(_)) => Self {
+				leading_spaces: self.leading_spaces + next.leading_spaces,
+				..next
+			},
+			(Some(content), Some(next_content)) => Self {
+				leading_spaces: self.leading_spaces,
+				content: Some(content + self.trailing_spaces + next.leading_spaces + next_content),
+				trailing_spaces: next.trailing_spaces,
+			},
+		}
+	}
 }
 
 /// Stores arguments passed to `print_element` call, holding the state specific to printing an element.
@@ -367,15 +627,21 @@ struct PrinterStateSnapshot {
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 struct PrintElementArgs {
 	indent: u16,
+	/// Whether the enclosing group fits on a single line. The content outside of any group is
+	/// printed in multiline mode.
+	mode: GroupPrintMode,
 }
 
 impl PrintElementArgs {
-	pub fn new(indent: u16) -> Self {
-		Self { indent }
+	pub fn with_incremented_indent(self) -> Self {
+		Self {
+			indent: self.indent + 1,
+			..self
+		}
 	}
 
-	pub fn with_incremented_indent(self) -> Self {
-		Self::new(self.indent + 1)
+	pub fn with_print_mode(self, mode: GroupPrintMode) -> Self {
+		Self { mode, ..self }
 	}
 }
 
@@ -439,9 +705,12 @@ mod tests {
 	use crate::format_element::join_elements;
 	use crate::printer::{LineEnding, Printer, PrinterOptions};
 	use crate::{
-		block_indent, format_elements, group_elements, hard_line_break, if_group_breaks,
-		soft_indent, soft_line_break, soft_line_break_or_space, token, FormatElement, FormatResult,
+		block_indent, break_parent, fill_elements, format_elements, group_elements,
+		hard_line_break, if_group_breaks, line_suffix, soft_indent, soft_line_break,
+		soft_line_break_or_space, source_marker, space_token, token, FormatElement, FormatResult,
+		SourceMapping,
 	};
+	use rslint_parser::{TextRange, TextSize};
 
 	/// Prints the given element with the default printer options
 	fn print_element<T: Into<FormatElement>>(element: T) -> FormatResult {
@@ -580,6 +849,208 @@ two lines`,
 		assert_eq!("[\n\t'a',\n\t\'b',\n\t\'c',\n\t'd',\n]", result.code());
 	}
 
+	#[test]
+	fn it_omits_soft_line_breaks_at_the_start_of_a_line() {
+		let result = print_element(format_elements![
+			token("a"),
+			hard_line_break(),
+			soft_line_break(),
+			token("b"),
+			soft_line_break_or_space(),
+			soft_line_break(),
+			token("c"),
+		]);
+
+		assert_eq!("a\nb\nc", result.code());
+	}
+
+	#[test]
+	fn it_prints_every_hard_line_break() {
+		let result = print_element(format_elements![
+			token("a"),
+			hard_line_break(),
+			hard_line_break(),
+			token("b"),
+			hard_line_break(),
+			token("c"),
+		]);
+
+		assert_eq!("a\n\nb\nc", result.code());
+	}
+
+	#[test]
+	fn it_breaks_the_groups_that_enclose_a_break_parent() {
+		let result = print_element(create_array_element(vec![
+			format_elements![
+				token("1"),
+				line_suffix(format_elements![space_token(), token("// comment")]),
+				break_parent(),
+			],
+			token("2"),
+		]));
+
+		assert_eq!("[\n  1, // comment\n  2,\n]", result.code());
+	}
+
+	#[test]
+	fn it_maps_source_markers_to_the_position_after_the_indention() {
+		let range = TextRange::new(TextSize::from(4), TextSize::from(5));
+		let result = print_element(format_elements![
+			token("{"),
+			block_indent(format_elements![source_marker(range), token("a")]),
+			token("}"),
+		]);
+
+		assert_eq!("{\n  a\n}", result.code());
+		assert_eq!(
+			&[SourceMapping {
+				source: range,
+				generated: TextSize::from(4)
+			}],
+			result.source_mappings()
+		);
+	}
+
+	#[test]
+	fn it_discards_the_source_markers_of_a_group_that_does_not_fit() {
+		let range = TextRange::new(TextSize::from(0), TextSize::from(1));
+		let result = print_element(create_array_element(vec![
+			format_elements![source_marker(range), token("a")],
+			hard_line_break(),
+		]));
+
+		assert_eq!(
+			&[SourceMapping {
+				source: range,
+				generated: TextSize::from(4)
+			}],
+			result.source_mappings()
+		);
+	}
+
+	#[test]
+	fn it_measures_a_group_from_the_current_position_in_the_line() {
+		let printer = Printer::new(PrinterOptions {
+			indent_string: String::from("  "),
+			print_width: 12,
+			..PrinterOptions::default()
+		});
+
+		let result = printer.print(&format_elements![
+			token("let a ="),
+			space_token(),
+			create_array_element(vec![token("1"), token("2")]),
+		]);
+
+		assert_eq!("let a = [\n  1,\n  2,\n]", result.code());
+	}
+
+	#[test]
+	fn it_breaks_all_groups_that_enclose_a_hard_line_break() {
+		let result = print_element(group_elements(format_elements![
+			token("a"),
+			soft_line_break_or_space(),
+			group_elements(format_elements![
+				token("b"),
+				soft_line_break_or_space(),
+				token("c"),
+				hard_line_break(),
+			]),
+		]));
+
+		assert_eq!("a\nb\nc\n", result.code());
+	}
+
+	#[test]
+	fn it_fills_the_lines_with_as_many_items_as_fit() {
+		let printer = Printer::new(PrinterOptions {
+			print_width: 12,
+			..PrinterOptions::default()
+		});
+		let numbers = (1..=7).map(|number| token(&format!("{}0", number)));
+
+		let result = printer.print(&group_elements(format_elements![
+			token("["),
+			soft_indent(fill_elements(
+				format_elements![token(","), soft_line_break_or_space()],
+				numbers
+			)),
+			token("]"),
+		]));
+
+		assert_eq!("[\n\t10, 20, 30,\n\t40, 50, 60,\n\t70\n]", result.code());
+	}
+
+	#[test]
+	fn it_prints_a_fill_flat_if_it_fits() {
+		let result = print_element(create_array_element(vec![fill_elements(
+			format_elements![token(","), soft_line_break_or_space()],
+			vec![token("1"), token("2"), token("3")],
+		)]));
+
+		assert_eq!("[1, 2, 3]", result.code());
+	}
+
+	#[test]
+	fn it_prints_line_suffixes_before_the_next_line_break() {
+		let result = print_element(format_elements![
+			token("a"),
+			line_suffix(format_elements![space_token(), token("// a")]),
+			token(";"),
+			hard_line_break(),
+			token("b;"),
+			line_suffix(format_elements![space_token(), token("// b")]),
+		]);
+
+		assert_eq!("a; // a\nb; // b", result.code());
+	}
+
+	#[test]
+	fn it_ignores_line_suffixes_when_measuring_a_group() {
+		let printer = Printer::new(PrinterOptions {
+			print_width: 10,
+			..PrinterOptions::default()
+		});
+
+		let result = printer.print(&format_elements![
+			create_array_element(vec![
+				format_elements![
+					token("1"),
+					line_suffix(format_elements![space_token(), token("// a long comment")])
+				],
+				token("2"),
+			]),
+			token(";"),
+			hard_line_break(),
+		]);
+
+		assert_eq!("[1, 2]; // a long comment\n", result.code());
+	}
+
+	#[test]
+	fn it_measures_the_groups_of_every_line_suffix() {
+		let printer = Printer::new(PrinterOptions {
+			print_width: 10,
+			..PrinterOptions::default()
+		});
+
+		// The line suffixes are printed one after the other, their groups may be at the same address
+		let result = printer.print(&format_elements![
+			token("a;"),
+			line_suffix(group_elements(token("// a"))),
+			hard_line_break(),
+			token("b;"),
+			line_suffix(group_elements(format_elements![
+				token("// a long"),
+				soft_line_break_or_space(),
+				token("comment"),
+			])),
+			hard_line_break(),
+		]);
+
+		assert_eq!("a;// a\nb;// a long\ncomment\n", result.code());
+	}
+
 	fn create_array_element(items: Vec<FormatElement>) -> FormatElement {
 		let separator = format_elements![token(","), soft_line_break_or_space(),];
 
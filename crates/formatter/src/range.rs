@@ -0,0 +1,261 @@
+//! Formatting of a range of a CST, e.g. the selection in an editor.
+//!
+//! Formatting a range only formats the statements or class members that are covered by the range
+//! and leaves the rest of the source as it is. The formatted nodes keep the indention of the line
+//! they start on, as it is, so that they fit into the surrounding, unformatted, code.
+use crate::comments::{
+	first_non_trivia_token, last_non_trivia_token, leading_comments, non_trivia_range,
+	trailing_comments,
+};
+use crate::diff;
+use crate::{FormatElement, FormatOptions, Formatter, Printer};
+use rslint_parser::ast::{ClassElement, ModuleItem};
+use rslint_parser::{AstNode, NodeOrToken, SyntaxKind, SyntaxNode, TextRange};
+use rslint_text_edit::TextEdit;
+
+/// Formats the statements or class members of `root` that intersect with `range`.
+///
+/// Returns a [TextEdit] that changes the source text of the formatted nodes (and their comments)
+/// to the formatted code. The edit doesn't touch the source outside of the formatted nodes.
+/// It is empty if there's nothing to format in the range or if the nodes can't be formatted
+/// because of syntax errors.
+pub fn format_range(root: &SyntaxNode, range: TextRange, options: FormatOptions) -> TextEdit {
+	let range = match root.text_range().intersect(range) {
+		Some(range) => range,
+		None => return TextEdit::default(),
+	};
+
+	let covering = match root.covering_element(range) {
+		NodeOrToken::Node(node) => Some(node),
+		NodeOrToken::Token(token) => token.parent(),
+	};
+	let nodes = match covering {
+		Some(covering) => range_nodes(covering, range),
+		None => return TextEdit::default(),
+	};
+
+	let source = root.text().to_string();
+	let options = FormatOptions {
+		line_ending: options.line_ending.resolve(&source),
+		..options
+	};
+	let formatter = Formatter::new(options);
+	let mut builder = TextEdit::builder();
+
+	for node in nodes {
+		let edit_range = match formatted_range(&node) {
+			Some(range) => range,
+			None => continue,
+		};
+
+		let element = match format_range_node(&node, &formatter) {
+			Some(element) => element,
+			None => return TextEdit::default(),
+		};
+
+		let printed = Printer::new(formatter.options().clone())
+			.with_base_indention(line_indention(&source, edit_range))
+			.print(&element);
+		let edit = diff::text_edit(&source[edit_range], printed.code().trim_end());
+
+		for indel in edit {
+			builder.replace(indel.delete + edit_range.start(), indel.insert);
+		}
+	}
+
+	builder.finish()
+}
+
+/// Returns the smallest nodes around `range` that can be formatted on their own, starting at `node`.
+///
+/// A range inside of a list of statements or class members that doesn't cover the list itself
+/// (e.g. the braces of a block) results in the items that intersect with the range.
+fn range_nodes(node: SyntaxNode, range: TextRange) -> Vec<SyntaxNode> {
+	for ancestor in node.ancestors() {
+		if is_list(&ancestor) {
+			let covers_list = match non_trivia_range(&ancestor) {
+				Some(list_range) => range.contains_range(list_range),
+				None => false,
+			};
+
+			if ancestor.parent().is_none() || !covers_list {
+				let items = ancestor
+					.children()
+					.filter(is_range_formattable)
+					.filter(|child| match non_trivia_range(child) {
+						Some(child_range) => child_range.intersect(range).is_some(),
+						None => false,
+					})
+					.collect::<Vec<_>>();
+
+				if !items.is_empty() {
+					return items;
+				}
+			}
+		}
+
+		if is_range_formattable(&ancestor) {
+			return vec![ancestor];
+		}
+	}
+
+	vec![]
+}
+
+/// Returns `true` if `node` is a list of statements or class members
+fn is_list(node: &SyntaxNode) -> bool {
+	matches!(
+		node.kind(),
+		SyntaxKind::SCRIPT
+			| SyntaxKind::MODULE
+			| SyntaxKind::BLOCK_STMT
+			| SyntaxKind::CLASS_BODY
+			| SyntaxKind::TS_MODULE_BLOCK
+			| SyntaxKind::CASE_CLAUSE
+			| SyntaxKind::DEFAULT_CLAUSE
+	)
+}
+
+/// Returns `true` if `node` is a statement, a module item or a class member
+fn is_range_formattable(node: &SyntaxNode) -> bool {
+	match node.parent() {
+		Some(parent) if parent.kind() == SyntaxKind::CLASS_BODY => {
+			ClassElement::can_cast(node.kind())
+		}
+		Some(_) => ModuleItem::can_cast(node.kind()),
+		None => false,
+	}
+}
+
+fn format_range_node(node: &SyntaxNode, formatter: &Formatter) -> Option<FormatElement> {
+	if node.parent()?.kind() == SyntaxKind::CLASS_BODY {
+		formatter.format_node(ClassElement::cast(node.clone())?)
+	} else {
+		formatter.format_node(ModuleItem::cast(node.clone())?)
+	}
+}
+
+/// Returns the range of the source text that is replaced by the formatted `node`. That's the range
+/// of the node without its leading and trailing trivia but including the comments that are printed
+/// together with the node.
+fn formatted_range(node: &SyntaxNode) -> Option<TextRange> {
+	let first = first_non_trivia_token(node)?;
+	let last = last_non_trivia_token(node)?;
+
+	let start = match leading_comments(&first).first() {
+		Some(comment) => comment.token().text_range().start(),
+		None => first.text_range().start(),
+	};
+	let end = match trailing_comments(&last).last() {
+		Some(comment) => comment.token().text_range().end(),
+		None => last.text_range().end(),
+	};
+
+	Some(TextRange::new(start, end))
+}
+
+/// Returns the indention of the line in `source` on which `range` starts: the tabs and spaces
+/// at the start of the line
+fn line_indention(source: &str, range: TextRange) -> &str {
+	let start = usize::from(range.start());
+	let line_start = match source[..start].rfind(['\n', '\r']) {
+		Some(index) => index + 1,
+		None => 0,
+	};
+	let line = &source[line_start..start];
+
+	&line[..line.len() - line.trim_start_matches(['\t', ' ']).len()]
+}
+
+#[cfg(test)]
+mod test {
+	use super::format_range;
+	use crate::FormatOptions;
+	use rslint_parser::{parse_module, TextRange, TextSize};
+
+	fn format(source: &str, start: usize, end: usize) -> String {
+		let root = parse_module(source, 0).syntax();
+		let range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+		let mut result = source.to_string();
+
+		format_range(&root, range, FormatOptions::default()).apply(&mut result);
+
+		result
+	}
+
+	#[test]
+	fn formats_only_the_statement_in_the_range() {
+		let source = "let   a=1;\nlet   b=2;\nlet   c=3;\n";
+
+		assert_eq!(
+			format(source, 13, 14),
+			"let   a=1;\nlet b = 2;\nlet   c=3;\n"
+		);
+	}
+
+	#[test]
+	fn formats_all_statements_intersecting_the_range() {
+		let source = "let   a=1;\n\n\nlet   b=2;  let   c=3;\n";
+
+		assert_eq!(
+			format(source, 5, 16),
+			"let a = 1;\n\n\nlet b = 2;  let   c=3;\n"
+		);
+	}
+
+	#[test]
+	fn keeps_the_indention_of_nested_statements() {
+		let source = "function f() {\n\tif(a){call(  a,b)}\n\tlet   b=2;\n}\n";
+
+		assert_eq!(
+			format(source, 16, 18),
+			"function f() {\n\tif (a) {\n\t\tcall(a, b);\n\t}\n\tlet   b=2;\n}\n"
+		);
+	}
+
+	#[test]
+	fn keeps_the_indention_text_of_the_line() {
+		let source = "function f() {\n  \t  if(a){call(  a,b)}\n}\n";
+
+		assert_eq!(
+			format(source, 21, 23),
+			"function f() {\n  \t  if (a) {\n  \t  \tcall(a, b);\n  \t  }\n}\n"
+		);
+	}
+
+	#[test]
+	fn formats_class_members() {
+		let source = "class A {\n\tm(  ){return   1}\n\tn(  ){}\n}\n";
+
+		assert_eq!(
+			format(source, 12, 13),
+			"class A {\n\tm() {\n\t\treturn 1;\n\t}\n\tn(  ){}\n}\n"
+		);
+	}
+
+	#[test]
+	fn includes_the_comments_of_the_formatted_node() {
+		let source = "let   a=1;\n// leading\nlet   b=2; // trailing\nlet c = 3;\n";
+
+		assert_eq!(
+			format(source, 23, 24),
+			"let   a=1;\n// leading\nlet b = 2; // trailing\nlet c = 3;\n"
+		);
+	}
+
+	#[test]
+	fn formatted_statements_result_in_an_empty_edit() {
+		let source = "let a = 1;\nlet   b=2;\n";
+		let root = parse_module(source, 0).syntax();
+		let range = TextRange::new(TextSize::from(0), TextSize::from(3));
+
+		assert!(format_range(&root, range, FormatOptions::default()).is_empty());
+	}
+
+	#[test]
+	fn range_outside_of_statements_is_a_no_op() {
+		let source = "let a = 1;\n\n\nlet b = 2;\n";
+
+		assert_eq!(format(source, 11, 12), source);
+	}
+}
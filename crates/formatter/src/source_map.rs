@@ -0,0 +1,289 @@
+//! Source map support for the printed output.
+//!
+//! The formatter inserts [crate::FormatElement::SourceMarker] elements into the IR that store the
+//! range of the source text of the content following the marker. The [crate::Printer] resolves
+//! these markers to positions in the printed output while printing, resulting in a list of
+//! [SourceMapping]s that can be serialized as a [Source Map v3](https://sourcemaps.info/spec.html).
+use rslint_parser::{TextRange, TextSize};
+use std::fmt::Write;
+
+/// Maps a position in the printed output to the range in the source text it has been printed from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourceMapping {
+	/// The range in the source text
+	pub source: TextRange,
+	/// The offset in the printed output
+	pub generated: TextSize,
+}
+
+/// A Source Map v3 mapping the printed output back to the source text.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SourceMap {
+	file: String,
+	source: String,
+	source_content: String,
+	mappings: String,
+}
+
+impl SourceMap {
+	/// Creates the source map for the `generated` output that has been printed from `source_content`.
+	///
+	/// * `file`: the name of the printed file
+	/// * `source`: the name of the source file, which may be the same as `file` if the file
+	///   is formatted in place.
+	pub fn new(
+		file: &str,
+		source: &str,
+		source_content: &str,
+		generated: &str,
+		mappings: &[SourceMapping],
+	) -> Self {
+		Self {
+			file: String::from(file),
+			source: String::from(source),
+			source_content: String::from(source_content),
+			mappings: encode_mappings(source_content, generated, mappings),
+		}
+	}
+
+	/// Returns the encoded mappings, the `mappings` field of the source map
+	pub fn mappings(&self) -> &str {
+		&self.mappings
+	}
+
+	/// Serializes the source map to its JSON representation
+	pub fn to_json(&self) -> String {
+		format!(
+			r#"{{"version":3,"file":{},"sources":[{}],"sourcesContent":[{}],"names":[],"mappings":{}}}"#,
+			json_string(&self.file),
+			json_string(&self.source),
+			json_string(&self.source_content),
+			json_string(&self.mappings)
+		)
+	}
+}
+
+/// Encodes the mappings as semicolon separated lines of comma separated segments where
+/// each segment stores the generated column, the source index, the source line and column
+/// as base 64 VLQs relative to the previous segment.
+fn encode_mappings(source: &str, generated: &str, mappings: &[SourceMapping]) -> String {
+	let source_lines = LineIndex::new(source);
+	let generated_lines = LineIndex::new(generated);
+
+	let mut mappings = mappings.to_vec();
+	// Sort by the generated position but keep the order of mappings with the same position.
+	// The outermost node is the first at any given position.
+	mappings.sort_by_key(|mapping| mapping.generated);
+	mappings.dedup_by_key(|mapping| mapping.generated);
+
+	let mut result = String::new();
+	let mut current_line = 0;
+	let mut previous_generated_column = 0;
+	let mut previous_source_line = 0;
+	let mut previous_source_column = 0;
+	let mut is_first_segment_on_line = true;
+
+	for mapping in mappings {
+		let (generated_line, generated_column) = generated_lines.line_column(mapping.generated);
+		let (source_line, source_column) = source_lines.line_column(mapping.source.start());
+
+		while current_line < generated_line {
+			result.push(';');
+			current_line += 1;
+			previous_generated_column = 0;
+			is_first_segment_on_line = true;
+		}
+
+		if !is_first_segment_on_line {
+			result.push(',');
+		}
+
+		encode_vlq(&mut result, generated_column - previous_generated_column);
+		// The index of the source file, always the first
+		encode_vlq(&mut result, 0);
+		encode_vlq(&mut result, source_line - previous_source_line);
+		encode_vlq(&mut result, source_column - previous_source_column);
+
+		previous_generated_column = generated_column;
+		previous_source_line = source_line;
+		previous_source_column = source_column;
+		is_first_segment_on_line = false;
+	}
+
+	result
+}
+
+const BASE64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes the value as a base 64 variable length quantity
+fn encode_vlq(buffer: &mut String, value: i64) {
+	// The sign is stored in the least significant bit
+	let mut value = if value < 0 {
+		((-value) << 1) | 1
+	} else {
+		value << 1
+	};
+
+	loop {
+		let mut digit = value & 0b11111;
+		value >>= 5;
+
+		if value > 0 {
+			// Set the continuation bit
+			digit |= 0b100000;
+		}
+
+		buffer.push(BASE64_CHARS[digit as usize] as char);
+
+		if value == 0 {
+			break;
+		}
+	}
+}
+
+/// Escapes the string and wraps it in double quotes
+fn json_string(content: &str) -> String {
+	let mut result = String::with_capacity(content.len() + 2);
+	result.push('"');
+
+	for char in content.chars() {
+		match char {
+			'"' => result.push_str("\\\""),
+			'\\' => result.push_str("\\\\"),
+			'\n' => result.push_str("\\n"),
+			'\r' => result.push_str("\\r"),
+			'\t' => result.push_str("\\t"),
+			char if char.is_control() => {
+				write!(result, "\\u{:04x}", char as u32).unwrap();
+			}
+			char => result.push(char),
+		}
+	}
+
+	result.push('"');
+	result
+}
+
+/// Converts offsets into zero based line and column numbers. Columns are measured in UTF-16 code units
+/// as mandated by the source map specification. Lines end with `\r\n`, `\r` or `\n`.
+struct LineIndex<'a> {
+	text: &'a str,
+	line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+	fn new(text: &'a str) -> Self {
+		let bytes = text.as_bytes();
+		let line_starts = std::iter::once(0)
+			.chain(
+				bytes
+					.iter()
+					.enumerate()
+					.filter(|(index, byte)| match byte {
+						b'\n' => true,
+						// The `\n` of a `\r\n` ends the line
+						b'\r' => bytes.get(index + 1) != Some(&b'\n'),
+						_ => false,
+					})
+					.map(|(index, _)| index + 1),
+			)
+			.collect();
+
+		Self { text, line_starts }
+	}
+
+	fn line_column(&self, offset: TextSize) -> (i64, i64) {
+		let offset = usize::from(offset).min(self.text.len());
+		let line = self
+			.line_starts
+			.binary_search(&offset)
+			.unwrap_or_else(|next_line| next_line - 1);
+
+		let column = self.text[self.line_starts[line]..offset]
+			.chars()
+			.map(char::len_utf16)
+			.sum::<usize>();
+
+		(line as i64, column as i64)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{encode_vlq, SourceMap, SourceMapping};
+	use rslint_parser::{TextRange, TextSize};
+
+	fn vlq(value: i64) -> String {
+		let mut result = String::new();
+		encode_vlq(&mut result, value);
+		result
+	}
+
+	#[test]
+	fn encodes_vlqs() {
+		assert_eq!(vlq(0), "A");
+		assert_eq!(vlq(1), "C");
+		assert_eq!(vlq(-1), "D");
+		assert_eq!(vlq(15), "e");
+		assert_eq!(vlq(16), "gB");
+		assert_eq!(vlq(-123), "3H");
+	}
+
+	#[test]
+	fn encodes_mappings_per_line() {
+		let source = "let a=1;\nlet  b=2;";
+		let generated = "let a = 1;\nlet b = 2;\n";
+
+		let mapping = |source: u32, generated: u32| SourceMapping {
+			source: TextRange::at(TextSize::from(source), TextSize::from(1)),
+			generated: TextSize::from(generated),
+		};
+
+		let source_map = SourceMap::new(
+			"test.js",
+			"test.js",
+			source,
+			generated,
+			&[
+				mapping(0, 0),
+				mapping(4, 4),
+				mapping(6, 8),
+				mapping(9, 11),
+				mapping(14, 15),
+			],
+		);
+
+		assert_eq!(source_map.mappings(), "AAAA,IAAI,IAAE;AACN,IAAK");
+		assert_eq!(
+			source_map.to_json(),
+			r#"{"version":3,"file":"test.js","sources":["test.js"],"sourcesContent":["let a=1;\nlet  b=2;"],"names":[],"mappings":"AAAA,IAAI,IAAE;AACN,IAAK"}"#
+		);
+	}
+
+	#[test]
+	fn encodes_mappings_of_carriage_return_line_endings() {
+		let source = "let a=1;\r\nlet  b=2;";
+		let generated = "let a = 1;\rlet b = 2;\r";
+
+		let mapping = |source: u32, generated: u32| SourceMapping {
+			source: TextRange::at(TextSize::from(source), TextSize::from(1)),
+			generated: TextSize::from(generated),
+		};
+
+		let source_map = SourceMap::new(
+			"test.js",
+			"test.js",
+			source,
+			generated,
+			&[
+				mapping(0, 0),
+				mapping(4, 4),
+				mapping(6, 8),
+				mapping(10, 11),
+				mapping(15, 15),
+			],
+		);
+
+		assert_eq!(source_map.mappings(), "AAAA,IAAI,IAAE;AACN,IAAK");
+	}
+}
@@ -1,19 +1,65 @@
+use crate::ts::format_trailing_comma;
 use crate::{
-	format_elements, group_elements, join_elements, token, FormatElement, Formatter,
-	ToFormatElement,
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, Formatter, ToFormatElement,
+	TrailingComma,
 };
-use rslint_parser::ast::ArgList;
+use rslint_parser::ast::{ArgList, Expr, ExprOrSpread};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for ArgList {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_paren_token()?)?;
-		let args = formatter.format_children(self.args())?;
+		// `args` only returns the expressions but not the spread arguments: `...rest`
+		let args = self
+			.syntax()
+			.children()
+			.filter_map(ExprOrSpread::cast)
+			.collect::<Vec<_>>();
+		let hug_args = args.iter().any(is_hugged);
+		let has_args = !args.is_empty();
+		let args = formatter.format_separated(args)?;
 		let r_bracket = formatter.format_token(&self.r_paren_token()?)?;
 
+		// Functions, classes, objects and arrays stay on the line of the call even if they
+		// break across multiple lines: `test("name", () => {`
+		if hug_args {
+			return Some(group_elements(format_elements![
+				l_bracket,
+				join_elements(space_token(), args),
+				r_bracket
+			]));
+		}
+
+		let trailing_comma = if has_args {
+			format_trailing_comma(TrailingComma::All, formatter)
+		} else {
+			empty_element()
+		};
+
 		Some(group_elements(format_elements![
 			l_bracket,
-			join_elements(token(", "), args),
+			soft_indent(format_elements![
+				join_elements(soft_line_break_or_space(), args),
+				trailing_comma
+			]),
 			r_bracket
 		]))
 	}
 }
+
+/// Returns `true` if the argument is kept on the line of the call rather than moving all
+/// arguments on their own lines if they don't fit on a single line.
+fn is_hugged(arg: &ExprOrSpread) -> bool {
+	matches!(
+		arg,
+		ExprOrSpread::Expr(
+			Expr::ArrowExpr(_)
+				| Expr::FnExpr(_)
+				| Expr::ClassExpr(_)
+				| Expr::ObjectExpr(_)
+				| Expr::ArrayExpr(_)
+				| Expr::Template(_)
+		)
+	)
+}
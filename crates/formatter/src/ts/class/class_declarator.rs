@@ -1,19 +1,57 @@
+use crate::ts::typescript::{format_decorators, format_modifiers};
 use crate::{
 	block_indent, empty_element, format_elements, group_elements, hard_line_break, join_elements,
-	space_token, FormatElement, Formatter, ToFormatElement,
+	space_token, token, FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{ClassBody, ClassDecl, ClassElement, SuperCall};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for ClassDecl {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let decorators = format_decorators(self.decorators(), formatter)?;
+		let modifiers = format_modifiers(self.syntax(), formatter)?;
 		let class_token = formatter.format_token(&self.class_token()?)?;
-		let name = formatter.format_node(self.name()?)?;
+		// the name is optional for `export default class {}`
+		let name = if let Some(name) = self.name() {
+			formatter.format_node(name)?
+		} else {
+			empty_element()
+		};
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+		let name = if self.name().is_some() || self.type_params().is_some() {
+			format_elements![name, type_params, space_token()]
+		} else {
+			empty_element()
+		};
 		let extends = if let Some(parent) = self.parent() {
 			let extends_token = formatter.format_token(&self.extends_token()?)?;
+			let parent_type_args = if let Some(type_args) = self.parent_type_args() {
+				formatter.format_node(type_args)?
+			} else {
+				empty_element()
+			};
 			format_elements![
 				extends_token,
 				space_token(),
 				formatter.format_node(parent)?,
+				parent_type_args,
+				space_token()
+			]
+		} else {
+			empty_element()
+		};
+		let implements = if let Some(implements_token) = self.implements_token() {
+			format_elements![
+				formatter.format_token(&implements_token)?,
+				space_token(),
+				join_elements(
+					space_token(),
+					formatter.format_separated(self.implements())?
+				),
 				space_token()
 			]
 		} else {
@@ -23,11 +61,13 @@ impl ToFormatElement for ClassDecl {
 		let body = formatter.format_node(self.body()?)?;
 
 		Some(format_elements![
+			decorators,
+			modifiers,
 			class_token,
 			space_token(),
 			name,
-			space_token(),
 			extends,
+			implements,
 			body
 		])
 	}
@@ -54,10 +94,13 @@ impl ToFormatElement for ClassElement {
 				empty_statement.to_format_element(formatter)
 			}
 			ClassElement::Method(method) => method.to_format_element(formatter),
-			ClassElement::PrivateProp(_) => todo!(),
+			ClassElement::PrivateProp(private_prop) => private_prop.to_format_element(formatter),
 			ClassElement::ClassProp(class_prop) => class_prop.to_format_element(formatter),
 			ClassElement::Constructor(constructor) => constructor.to_format_element(formatter),
-			ClassElement::TsIndexSignature(_) => todo!(),
+			ClassElement::TsIndexSignature(index_signature) => Some(format_elements![
+				index_signature.to_format_element(formatter)?,
+				token(";")
+			]),
 			ClassElement::Getter(getter) => getter.to_format_element(formatter),
 			ClassElement::Setter(setter) => setter.to_format_element(formatter),
 		}
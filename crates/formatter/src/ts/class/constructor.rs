@@ -1,15 +1,21 @@
+use crate::ts::typescript::{format_decorators, format_modifiers};
 use crate::{
-	format_elements, group_elements, join_elements, soft_line_break_or_space, space_token, token,
+	format_elements, group_elements, join_elements, soft_line_break_or_space, space_token,
 	FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{Constructor, ConstructorParamOrPat, ConstructorParameters};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for Constructor {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let decorators = format_decorators(self.decorators(), formatter)?;
+		let modifiers = format_modifiers(self.syntax(), formatter)?;
 		let constructor_token = formatter.format_node(self.name()?)?;
 		let params = formatter.format_node(self.parameters()?)?;
 		let body = formatter.format_node(self.body()?)?;
 		Some(format_elements![
+			decorators,
+			modifiers,
 			constructor_token,
 			params,
 			space_token(),
@@ -21,15 +27,12 @@ impl ToFormatElement for Constructor {
 impl ToFormatElement for ConstructorParameters {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_paren_token()?)?;
-		let params = formatter.format_children(self.parameters())?;
+		let params = formatter.format_separated(self.parameters())?;
 		let r_bracket = formatter.format_token(&self.r_paren_token()?)?;
 
 		Some(format_elements![group_elements(format_elements![
 			l_bracket,
-			join_elements(
-				format_elements![token(","), soft_line_break_or_space()],
-				params
-			),
+			join_elements(soft_line_break_or_space(), params),
 			r_bracket
 		])])
 	}
@@ -38,7 +41,7 @@ impl ToFormatElement for ConstructorParameters {
 impl ToFormatElement for ConstructorParamOrPat {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		match self {
-			ConstructorParamOrPat::TsConstructorParam(_) => todo!(),
+			ConstructorParamOrPat::TsConstructorParam(param) => param.to_format_element(formatter),
 			ConstructorParamOrPat::Pattern(pattern) => pattern.to_format_element(formatter),
 		}
 	}
@@ -1,3 +1,44 @@
+use crate::comments::{first_non_trivia_token, last_non_trivia_token};
+use crate::{empty_element, token, FormatElement, Formatter, Semicolons};
+use rslint_parser::{SyntaxKind, SyntaxNode};
+
 mod class_declarator;
 mod constructor;
+mod private_prop;
 mod prop;
+
+/// Returns the `;` that terminates a class property.
+///
+/// The semicolon is kept even if semicolons are only printed as needed if the property would
+/// otherwise merge with the next member, e.g. a computed member `[a]() {}` or a generator method
+/// `*a() {}`, or if the property ends with a name that would become a modifier of the next
+/// member: `static`.
+pub(crate) fn format_class_prop_semicolon(
+	prop: &SyntaxNode,
+	formatter: &Formatter,
+) -> FormatElement {
+	if formatter.options().semicolons == Semicolons::Always {
+		return token(";");
+	}
+
+	let ends_with_modifier_name = match last_non_trivia_token(prop) {
+		Some(last) => matches!(last.text(), "static" | "get" | "set"),
+		None => false,
+	};
+	let next_is_hazard = match prop
+		.next_sibling()
+		.and_then(|next| first_non_trivia_token(&next))
+	{
+		Some(first) => {
+			matches!(first.kind(), SyntaxKind::L_BRACK | SyntaxKind::STAR)
+				|| matches!(first.text(), "in" | "instanceof")
+		}
+		None => false,
+	};
+
+	if ends_with_modifier_name || next_is_hazard {
+		token(";")
+	} else {
+		empty_element()
+	}
+}
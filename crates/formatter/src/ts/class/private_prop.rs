@@ -0,0 +1,49 @@
+use crate::ts::class::format_class_prop_semicolon;
+use crate::ts::typescript::{format_decorators, format_modifiers, format_type_annotation};
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{PrivateName, PrivateProp};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for PrivateProp {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let optional_or_definite = if let Some(question_mark) = self.question_mark_token() {
+			formatter.format_token(&question_mark)?
+		} else if let Some(excl) = self.excl_token() {
+			formatter.format_token(&excl)?
+		} else {
+			empty_element()
+		};
+
+		let equal_and_value = match (self.eq_token(), self.value()) {
+			(None, None) => empty_element(),
+			(Some(equal), Some(value)) => format_elements![
+				space_token(),
+				formatter.format_token(&equal)?,
+				space_token(),
+				formatter.format_node(value)?,
+			],
+			_ => return None,
+		};
+
+		Some(format_elements![
+			format_decorators(self.decorators(), formatter)?,
+			format_modifiers(self.syntax(), formatter)?,
+			formatter.format_node(self.key()?)?,
+			optional_or_definite,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?,
+			equal_and_value,
+			format_class_prop_semicolon(self.syntax(), formatter)
+		])
+	}
+}
+
+impl ToFormatElement for PrivateName {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.hash_token()?)?,
+			formatter.format_node(self.name()?)?
+		])
+	}
+}
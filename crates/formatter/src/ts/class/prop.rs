@@ -1,15 +1,23 @@
+use crate::ts::class::format_class_prop_semicolon;
+use crate::ts::typescript::{format_decorators, format_modifiers, format_type_annotation};
 use crate::{
-	empty_element, format_elements, space_token, token, FormatElement, Formatter, ToFormatElement,
+	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::ClassProp;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for ClassProp {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let static_token = if let Some(static_token) = self.static_token() {
-			format_elements![formatter.format_token(&static_token)?, space_token()]
+		let decorators = format_decorators(self.decorators(), formatter)?;
+		let modifiers = format_modifiers(self.syntax(), formatter)?;
+		let optional_or_definite = if let Some(question_mark) = self.question_mark_token() {
+			formatter.format_token(&question_mark)?
+		} else if let Some(excl) = self.excl_token() {
+			formatter.format_token(&excl)?
 		} else {
 			empty_element()
 		};
+		let type_annotation = format_type_annotation(self.colon_token(), self.ty(), formatter)?;
 		let value = self.value();
 		let equal = self.eq();
 
@@ -29,10 +37,13 @@ impl ToFormatElement for ClassProp {
 				let key = formatter.format_node(self.key()?)?;
 
 				Some(format_elements![
-					static_token,
+					decorators,
+					modifiers,
 					key,
+					optional_or_definite,
+					type_annotation,
 					equal_and_value,
-					token(";")
+					format_class_prop_semicolon(self.syntax(), formatter)
 				])
 			}
 			None => None,
@@ -7,11 +7,11 @@ impl ToFormatElement for Decl {
 			Decl::FnDecl(fn_decl) => fn_decl.to_format_element(formatter),
 			Decl::ClassDecl(class_declarator) => class_declarator.to_format_element(formatter),
 			Decl::VarDecl(var_decl) => var_decl.to_format_element(formatter),
-			Decl::TsEnum(_) => todo!(),
-			Decl::TsTypeAliasDecl(_) => todo!(),
-			Decl::TsNamespaceDecl(_) => todo!(),
-			Decl::TsModuleDecl(_) => todo!(),
-			Decl::TsInterfaceDecl(_) => todo!(),
+			Decl::TsEnum(ts_enum) => ts_enum.to_format_element(formatter),
+			Decl::TsTypeAliasDecl(type_alias) => type_alias.to_format_element(formatter),
+			Decl::TsNamespaceDecl(namespace) => namespace.to_format_element(formatter),
+			Decl::TsModuleDecl(module) => module.to_format_element(formatter),
+			Decl::TsInterfaceDecl(interface) => interface.to_format_element(formatter),
 		}
 	}
 }
@@ -1,9 +1,10 @@
-use crate::{concat_elements, space_token, FormatElement, Formatter, ToFormatElement};
-use rslint_parser::ast::FnDecl;
+use crate::ts::typescript::{format_modifiers, format_type_annotation};
+use crate::{concat_elements, space_token, token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{AstNode, FnDecl};
 
 impl ToFormatElement for FnDecl {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let mut tokens = vec![];
+		let mut tokens = vec![format_modifiers(self.syntax(), formatter)?];
 
 		if let Some(token) = self.async_token() {
 			tokens.push(formatter.format_token(&token)?);
@@ -17,10 +18,31 @@ impl ToFormatElement for FnDecl {
 		}
 
 		tokens.push(space_token());
-		tokens.push(formatter.format_node(self.name()?)?);
+
+		// the name is optional for `export default function() {}`
+		if let Some(name) = self.name() {
+			tokens.push(formatter.format_node(name)?);
+		}
+
+		if let Some(type_parameters) = self.type_parameters() {
+			tokens.push(formatter.format_node(type_parameters)?);
+		}
+
 		tokens.push(formatter.format_node(self.parameters()?)?);
-		tokens.push(space_token());
-		tokens.push(formatter.format_node(self.body()?)?);
+		tokens.push(format_type_annotation(
+			self.colon_token(),
+			self.return_type(),
+			formatter,
+		)?);
+
+		// overloads and ambient declarations have no body: `declare function a(): void;`
+		match self.body() {
+			Some(body) => {
+				tokens.push(space_token());
+				tokens.push(formatter.format_node(body)?);
+			}
+			None => tokens.push(token(";")),
+		}
 
 		Some(concat_elements(tokens))
 	}
@@ -1,9 +1,13 @@
-use crate::{concat_elements, space_token, token, FormatElement, Formatter, ToFormatElement};
+use crate::ts::format_semicolon;
+use crate::ts::typescript::format_modifiers;
+use crate::{
+	concat_elements, join_elements, space_token, FormatElement, Formatter, ToFormatElement,
+};
 use rslint_parser::ast::{AstNode, ForStmtInit, VarDecl};
 
 impl ToFormatElement for VarDecl {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let mut tokens = vec![];
+		let mut tokens = vec![format_modifiers(self.syntax(), formatter)?];
 
 		if let Some(token) = self.const_token() {
 			tokens.push(formatter.format_token(&token)?);
@@ -17,14 +21,15 @@ impl ToFormatElement for VarDecl {
 
 		tokens.push(space_token());
 
-		for declarator in self.declared() {
-			tokens.push(formatter.format_node(declarator)?);
-		}
+		tokens.push(join_elements(
+			space_token(),
+			formatter.format_separated(self.declared())?,
+		));
 
 		// don't add a semicolon if the var decl is in the init section of a for statement to avoid
 		// terminating the `init` with two semicolons.
 		if self.syntax().parent().and_then(ForStmtInit::cast).is_none() {
-			tokens.push(token(";"));
+			tokens.push(format_semicolon(formatter));
 		}
 
 		Some(concat_elements(tokens))
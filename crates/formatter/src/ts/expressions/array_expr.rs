@@ -1,20 +1,27 @@
+use crate::ts::format_trailing_comma;
 use crate::{
-	format_elements, group_elements, if_group_breaks, join_elements, soft_indent,
-	soft_line_break_or_space, token, FormatElement, Formatter, ToFormatElement,
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, FormatElement, Formatter, ToFormatElement, TrailingComma,
 };
 use rslint_parser::ast::ArrayExpr;
 
 impl ToFormatElement for ArrayExpr {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let elements = formatter.format_children(self.elements())?;
+		let has_elements = self.elements().next().is_some();
+		let elements = formatter.format_separated(self.elements())?;
+
+		let trailing_comma = if has_elements {
+			format_trailing_comma(TrailingComma::Es5, formatter)
+		} else {
+			empty_element()
+		};
 
 		Some(group_elements(format_elements!(
 			formatter.format_token(&self.l_brack_token()?)?,
-			soft_indent(join_elements(
-				format_elements!(token(","), soft_line_break_or_space()),
-				elements
-			)),
-			if_group_breaks(token(",")),
+			soft_indent(format_elements![
+				join_elements(soft_line_break_or_space(), elements),
+				trailing_comma
+			]),
 			formatter.format_token(&self.r_brack_token()?)?,
 		)))
 	}
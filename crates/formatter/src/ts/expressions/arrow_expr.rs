@@ -1,5 +1,6 @@
 use rslint_parser::ast::{ArrowExpr, ArrowExprParams};
 
+use crate::ts::typescript::format_type_annotation;
 use crate::{
 	concat_elements, format_elements, space_token, token, FormatElement, Formatter, ToFormatElement,
 };
@@ -15,6 +16,10 @@ impl ToFormatElement for ArrowExpr {
 			));
 		}
 
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
 		match self.params()? {
 			ArrowExprParams::Name(name) => {
 				tokens.push(token("("));
@@ -24,6 +29,12 @@ impl ToFormatElement for ArrowExpr {
 			ArrowExprParams::ParameterList(params) => tokens.push(formatter.format_node(params)?),
 		}
 
+		tokens.push(format_type_annotation(
+			self.colon_token(),
+			self.return_type(),
+			formatter,
+		)?);
+
 		tokens.push(space_token());
 		tokens.push(formatter.format_token(&self.fat_arrow_token()?)?);
 		tokens.push(space_token());
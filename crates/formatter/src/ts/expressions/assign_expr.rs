@@ -0,0 +1,23 @@
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{AssignExpr, PatternOrExpr};
+
+impl ToFormatElement for AssignExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.lhs()?)?,
+			space_token(),
+			formatter.format_token(&self.op_token()?)?,
+			space_token(),
+			formatter.format_node(self.rhs()?)?
+		])
+	}
+}
+
+impl ToFormatElement for PatternOrExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			PatternOrExpr::Pattern(pattern) => pattern.to_format_element(formatter),
+			PatternOrExpr::Expr(expr) => expr.to_format_element(formatter),
+		}
+	}
+}
@@ -0,0 +1,12 @@
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::AwaitExpr;
+
+impl ToFormatElement for AwaitExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.await_token()?)?,
+			space_token(),
+			formatter.format_node(self.expr()?)?
+		])
+	}
+}
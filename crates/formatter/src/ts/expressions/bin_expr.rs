@@ -0,0 +1,14 @@
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::BinExpr;
+
+impl ToFormatElement for BinExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.lhs()?)?,
+			space_token(),
+			formatter.format_token(&self.op_token()?)?,
+			space_token(),
+			formatter.format_node(self.rhs()?)?
+		])
+	}
+}
@@ -0,0 +1,26 @@
+use crate::{empty_element, format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::BracketExpr;
+
+impl ToFormatElement for BracketExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `super[prop]` has no object expression, making the property the first expression child
+		let (object, prop) = if let Some(super_token) = self.super_token() {
+			(formatter.format_token(&super_token)?, self.object()?)
+		} else {
+			(formatter.format_node(self.object()?)?, self.prop()?)
+		};
+		let opt_chain = if let Some(opt_chain_token) = self.opt_chain_token() {
+			formatter.format_token(&opt_chain_token)?
+		} else {
+			empty_element()
+		};
+
+		Some(format_elements![
+			object,
+			opt_chain,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(prop)?,
+			formatter.format_token(&self.r_brack_token()?)?
+		])
+	}
+}
@@ -0,0 +1,48 @@
+use crate::{
+	concat_elements, join_elements, space_token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::ClassExpr;
+
+impl ToFormatElement for ClassExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![formatter.format_token(&self.class_token()?)?, space_token()];
+
+		if let Some(name) = self.name() {
+			tokens.push(formatter.format_node(name)?);
+		}
+
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		if self.name().is_some() || self.type_params().is_some() {
+			tokens.push(space_token());
+		}
+
+		if let Some(parent) = self.parent() {
+			tokens.push(formatter.format_token(&self.extends_token()?)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(parent)?);
+
+			if let Some(type_args) = self.parent_type_args() {
+				tokens.push(formatter.format_node(type_args)?);
+			}
+
+			tokens.push(space_token());
+		}
+
+		if let Some(implements_token) = self.implements_token() {
+			tokens.push(formatter.format_token(&implements_token)?);
+			tokens.push(space_token());
+			tokens.push(join_elements(
+				space_token(),
+				formatter.format_separated(self.implements())?,
+			));
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_node(self.body()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
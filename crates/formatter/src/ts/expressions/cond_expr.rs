@@ -0,0 +1,18 @@
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::CondExpr;
+
+impl ToFormatElement for CondExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.test()?)?,
+			space_token(),
+			formatter.format_token(&self.question_mark_token()?)?,
+			space_token(),
+			formatter.format_node(self.cons()?)?,
+			space_token(),
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.alt()?)?
+		])
+	}
+}
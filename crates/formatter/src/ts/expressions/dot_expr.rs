@@ -0,0 +1,21 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::DotExpr;
+
+impl ToFormatElement for DotExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let object = if let Some(super_token) = self.super_token() {
+			formatter.format_token(&super_token)?
+		} else {
+			formatter.format_node(self.object()?)?
+		};
+		// `a?.b` has no dot token
+		let dot = if let Some(opt_chain_token) = self.opt_chain_token() {
+			formatter.format_token(&opt_chain_token)?
+		} else {
+			formatter.format_token(&self.dot_token()?)?
+		};
+		let prop = formatter.format_node(self.prop()?)?;
+
+		Some(format_elements![object, dot, prop])
+	}
+}
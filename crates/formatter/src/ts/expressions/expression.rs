@@ -6,33 +6,35 @@ impl ToFormatElement for Expr {
 		match self {
 			Expr::ArrowExpr(arrow) => arrow.to_format_element(formatter),
 			Expr::Literal(literal) => literal.to_format_element(formatter),
-			Expr::Template(_) => todo!(),
+			Expr::Template(template) => template.to_format_element(formatter),
 			Expr::NameRef(name_ref) => name_ref.to_format_element(formatter),
-			Expr::ThisExpr(_) => todo!(),
+			Expr::ThisExpr(this_expr) => this_expr.to_format_element(formatter),
 			Expr::ArrayExpr(array_expression) => array_expression.to_format_element(formatter),
 			Expr::ObjectExpr(object_expression) => object_expression.to_format_element(formatter),
-			Expr::GroupingExpr(_) => todo!(),
-			Expr::BracketExpr(_) => todo!(),
-			Expr::DotExpr(_) => todo!(),
-			Expr::NewExpr(_) => todo!(),
+			Expr::GroupingExpr(grouping_expr) => grouping_expr.to_format_element(formatter),
+			Expr::BracketExpr(bracket_expr) => bracket_expr.to_format_element(formatter),
+			Expr::DotExpr(dot_expr) => dot_expr.to_format_element(formatter),
+			Expr::NewExpr(new_expr) => new_expr.to_format_element(formatter),
 			Expr::CallExpr(call_expression) => call_expression.to_format_element(formatter),
-			Expr::UnaryExpr(_) => todo!(),
-			Expr::BinExpr(_) => todo!(),
-			Expr::CondExpr(_) => todo!(),
-			Expr::AssignExpr(_) => todo!(),
+			Expr::UnaryExpr(unary_expr) => unary_expr.to_format_element(formatter),
+			Expr::BinExpr(bin_expr) => bin_expr.to_format_element(formatter),
+			Expr::CondExpr(cond_expr) => cond_expr.to_format_element(formatter),
+			Expr::AssignExpr(assign_expr) => assign_expr.to_format_element(formatter),
 			Expr::SequenceExpr(expr) => expr.to_format_element(formatter),
-			Expr::FnExpr(_) => todo!(),
-			Expr::ClassExpr(_) => todo!(),
-			Expr::NewTarget(_) => todo!(),
-			Expr::ImportMeta(_) => todo!(),
+			Expr::FnExpr(fn_expr) => fn_expr.to_format_element(formatter),
+			Expr::ClassExpr(class_expr) => class_expr.to_format_element(formatter),
+			Expr::NewTarget(new_target) => new_target.to_format_element(formatter),
+			Expr::ImportMeta(import_meta) => import_meta.to_format_element(formatter),
 			Expr::SuperCall(super_call) => super_call.to_format_element(formatter),
-			Expr::ImportCall(_) => todo!(),
-			Expr::YieldExpr(_) => todo!(),
-			Expr::AwaitExpr(_) => todo!(),
-			Expr::PrivatePropAccess(_) => todo!(),
-			Expr::TsNonNull(_) => todo!(),
-			Expr::TsAssertion(_) => todo!(),
-			Expr::TsConstAssertion(_) => todo!(),
+			Expr::ImportCall(import_call) => import_call.to_format_element(formatter),
+			Expr::YieldExpr(yield_expr) => yield_expr.to_format_element(formatter),
+			Expr::AwaitExpr(await_expr) => await_expr.to_format_element(formatter),
+			Expr::PrivatePropAccess(private_prop_access) => {
+				private_prop_access.to_format_element(formatter)
+			}
+			Expr::TsNonNull(non_null) => non_null.to_format_element(formatter),
+			Expr::TsAssertion(assertion) => assertion.to_format_element(formatter),
+			Expr::TsConstAssertion(const_assertion) => const_assertion.to_format_element(formatter),
 		}
 	}
 }
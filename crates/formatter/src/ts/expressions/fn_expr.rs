@@ -0,0 +1,41 @@
+use crate::ts::typescript::format_type_annotation;
+use crate::{concat_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::FnExpr;
+
+impl ToFormatElement for FnExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(token) = self.async_token() {
+			tokens.push(formatter.format_token(&token)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_token(&self.function_token()?)?);
+
+		if let Some(token) = self.star_token() {
+			tokens.push(formatter.format_token(&token)?);
+		}
+
+		tokens.push(space_token());
+
+		if let Some(name) = self.name() {
+			tokens.push(formatter.format_node(name)?);
+		}
+
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		tokens.push(formatter.format_node(self.parameters()?)?);
+		tokens.push(format_type_annotation(
+			self.colon_token(),
+			self.return_type(),
+			formatter,
+		)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.body()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
@@ -0,0 +1,12 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::GroupingExpr;
+
+impl ToFormatElement for GroupingExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_node(self.inner()?)?,
+			formatter.format_token(&self.r_paren_token()?)?
+		])
+	}
+}
@@ -0,0 +1,13 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::ImportCall;
+
+impl ToFormatElement for ImportCall {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.import_token()?)?,
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_node(self.argument()?)?,
+			formatter.format_token(&self.r_paren_token()?)?
+		])
+	}
+}
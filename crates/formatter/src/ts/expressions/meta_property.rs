@@ -0,0 +1,30 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{ImportMeta, NewTarget};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, SyntaxToken};
+
+impl ToFormatElement for NewTarget {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_token(&property_token(self.syntax())?)?
+		])
+	}
+}
+
+impl ToFormatElement for ImportMeta {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.import_token()?)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_token(&property_token(self.syntax())?)?
+		])
+	}
+}
+
+/// Returns the `target` or `meta` identifier of a meta property
+fn property_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|child| child.into_token())
+		.find(|token| token.kind() == SyntaxKind::IDENT)
+}
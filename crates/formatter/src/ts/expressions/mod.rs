@@ -1,8 +1,25 @@
 mod array_expr;
 mod arrow_expr;
+mod assign_expr;
+mod await_expr;
+mod bin_expr;
+mod bracket_expr;
 mod call_expression;
+mod class_expr;
+mod cond_expr;
+mod dot_expr;
 mod expression;
+mod fn_expr;
+mod grouping_expr;
+mod import_call;
 mod literal;
+mod meta_property;
 mod name_ref;
+mod new_expr;
 mod object_expression;
+mod private_prop_access;
 mod sequence_expression;
+mod template;
+mod this_expr;
+mod unary_expr;
+mod yield_expr;
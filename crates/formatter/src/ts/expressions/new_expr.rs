@@ -0,0 +1,29 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::NewExpr;
+
+impl ToFormatElement for NewExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `new Foo` has no arguments
+		let arguments = if let Some(arguments) = self.arguments() {
+			formatter.format_node(arguments)?
+		} else {
+			empty_element()
+		};
+
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
+
+		Some(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			formatter.format_node(self.object()?)?,
+			type_args,
+			arguments
+		])
+	}
+}
@@ -0,0 +1,12 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::PrivatePropAccess;
+
+impl ToFormatElement for PrivatePropAccess {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.lhs()?)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_node(self.rhs()?)?
+		])
+	}
+}
@@ -1,9 +1,12 @@
 use rslint_parser::ast::SequenceExpr;
 
-use crate::{concat_elements, FormatElement, Formatter, ToFormatElement};
+use crate::{join_elements, space_token, FormatElement, Formatter, ToFormatElement};
 
 impl ToFormatElement for SequenceExpr {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		Some(concat_elements(formatter.format_children(self.exprs())?))
+		Some(join_elements(
+			space_token(),
+			formatter.format_separated(self.exprs())?,
+		))
 	}
 }
@@ -0,0 +1,182 @@
+use crate::formatter::verbatim_token;
+use crate::{
+	concat_elements, format_elements, hard_line_break, indent, token, FormatElement, Formatter,
+	IndentStyle, ToFormatElement,
+};
+use rslint_parser::ast::{Template, TemplateElement};
+use rslint_parser::{AstNode, NodeOrToken, SyntaxKind};
+
+/// Prefix of the placeholders that replace the expressions of a template with embedded code
+const PLACEHOLDER_PREFIX: &str = "__rome_placeholder_";
+
+impl ToFormatElement for Template {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		if let Some(code) = format_embedded_code(self, formatter) {
+			return format_embedded_template(self, &code, formatter);
+		}
+
+		let mut elements = vec![];
+
+		if let Some(tag) = self.tag() {
+			elements.push(formatter.format_node(tag)?);
+		}
+
+		for child in self.syntax().children_with_tokens() {
+			match child {
+				NodeOrToken::Token(child_token) => match child_token.kind() {
+					SyntaxKind::BACKTICK => elements.push(formatter.format_token(&child_token)?),
+					// The chunks are printed verbatim because any change to them changes the value of the template
+					SyntaxKind::TEMPLATE_CHUNK => elements.push(verbatim_token(child_token.text())),
+					_ => {}
+				},
+				NodeOrToken::Node(node) => {
+					if let Some(element) = TemplateElement::cast(node) {
+						elements.push(formatter.format_node(element)?);
+					}
+				}
+			}
+		}
+
+		Some(concat_elements(elements))
+	}
+}
+
+impl ToFormatElement for TemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let dollar_curly = self
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|child| child.into_token())
+			.find(|child| child.kind() == SyntaxKind::DOLLARCURLY)?;
+
+		Some(concat_elements(vec![
+			formatter.format_token(&dollar_curly)?,
+			formatter.format_node(self.expr()?)?,
+			formatter.format_token(&self.r_curly_token()?)?,
+		]))
+	}
+}
+
+/// Formats the code of a template whose tag has a registered embedded formatter, e.g.
+/// ``css`color: red;` ``, with the expressions of the template replaced by placeholders.
+///
+/// Returns `None` if the template isn't tagged with a known tag or if its code can't be
+/// formatted, in which case the template is printed as it is.
+fn format_embedded_code(template: &Template, formatter: &Formatter) -> Option<String> {
+	let tag = template.tag()?.syntax().text().to_string();
+	let embedded_formatter = formatter.embedded_formatter(tag.trim())?;
+
+	let mut code = String::new();
+	let mut placeholders = 0;
+
+	for child in template.syntax().children_with_tokens() {
+		match child {
+			NodeOrToken::Token(child_token) if child_token.kind() == SyntaxKind::TEMPLATE_CHUNK => {
+				let text = child_token.text();
+				// the placeholders must be distinguishable from the code
+				if text.contains(PLACEHOLDER_PREFIX) {
+					return None;
+				}
+				code.push_str(&text.replace("\r\n", "\n").replace('\r', "\n"));
+			}
+			NodeOrToken::Node(node) if TemplateElement::can_cast(node.kind()) => {
+				code.push_str(&placeholder(placeholders));
+				placeholders += 1;
+			}
+			_ => {}
+		}
+	}
+
+	if code.trim().is_empty() {
+		return None;
+	}
+
+	let formatted = embedded_formatter.format(&code, &indent_text(formatter))?;
+
+	// Every expression must be printed exactly once and in the same order
+	let mut rest = formatted.as_str();
+	for index in 0..placeholders {
+		let placeholder = placeholder(index);
+		let position = rest.find(&placeholder)?;
+		rest = &rest[position + placeholder.len()..];
+	}
+	if formatted.matches(PLACEHOLDER_PREFIX).count() != placeholders {
+		return None;
+	}
+
+	Some(formatted)
+}
+
+/// Prints the formatted embedded `code` on its own lines, indented by one level more than the
+/// template, and replaces the placeholders with the formatted expressions of the template.
+///
+/// The indention of the embedded code is printed with [indent] so that the expressions spanning
+/// multiple lines, e.g. a nested template, are indented relative to their line.
+fn format_embedded_template(
+	template: &Template,
+	code: &str,
+	formatter: &Formatter,
+) -> Option<FormatElement> {
+	let tag = formatter.format_node(template.tag()?)?;
+	let backticks: Vec<_> = template
+		.syntax()
+		.children_with_tokens()
+		.filter_map(|child| child.into_token())
+		.filter(|child| child.kind() == SyntaxKind::BACKTICK)
+		.collect();
+	let (open, close) = match backticks.as_slice() {
+		[open, close] => (formatter.format_token(open)?, close),
+		_ => return None,
+	};
+
+	let mut expressions = template
+		.syntax()
+		.children()
+		.filter_map(TemplateElement::cast);
+	let indent_text = indent_text(formatter);
+	let mut lines = vec![];
+
+	for line in code.lines().filter(|line| !line.trim().is_empty()) {
+		let mut elements = vec![hard_line_break()];
+		let mut rest = line;
+		let mut level = 0;
+
+		while let Some(unindented) = rest.strip_prefix(indent_text.as_str()) {
+			rest = unindented;
+			level += 1;
+		}
+
+		while let Some(position) = rest.find(PLACEHOLDER_PREFIX) {
+			elements.push(token(&rest[..position]));
+
+			let end = rest[position + PLACEHOLDER_PREFIX.len()..]
+				.find("__")
+				.map(|end| position + PLACEHOLDER_PREFIX.len() + end + 2)?;
+			elements.push(formatter.format_node(expressions.next()?)?);
+			rest = &rest[end..];
+		}
+
+		elements.push(token(rest));
+		lines.push((0..level).fold(concat_elements(elements), |line, _| indent(line)));
+	}
+
+	Some(format_elements![
+		tag,
+		open,
+		indent(concat_elements(lines)),
+		hard_line_break(),
+		formatter.format_token(close)?,
+	])
+}
+
+/// Returns the text of one level of indention
+fn indent_text(formatter: &Formatter) -> String {
+	match formatter.options().indent_style {
+		IndentStyle::Tab => String::from("\t"),
+		IndentStyle::Space(width) => " ".repeat(width as usize),
+	}
+}
+
+fn placeholder(index: usize) -> String {
+	format!("{}{}__", PLACEHOLDER_PREFIX, index)
+}
@@ -0,0 +1,8 @@
+use crate::{FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::ThisExpr;
+
+impl ToFormatElement for ThisExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		formatter.format_token(&self.this_token()?)
+	}
+}
@@ -0,0 +1,41 @@
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{Expr, UnaryExpr};
+
+impl ToFormatElement for UnaryExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let op_token = self.op_token()?;
+		let op = formatter.format_token(&op_token)?;
+		let expr = self.expr()?;
+
+		// only update expressions (`++`, `--`) can be postfix
+		if self.is_prefix() == Some(false) {
+			return Some(format_elements![formatter.format_node(expr)?, op]);
+		}
+
+		if needs_space(op_token.text(), &expr) {
+			Some(format_elements![
+				op,
+				space_token(),
+				formatter.format_node(expr)?
+			])
+		} else {
+			Some(format_elements![op, formatter.format_node(expr)?])
+		}
+	}
+}
+
+/// Keyword operators like `typeof` must be separated from their operand and so must be `-` from an
+/// operand that starts with a `-`, to not print `- -a` as `--a`.
+fn needs_space(op: &str, expr: &Expr) -> bool {
+	if op.chars().all(char::is_alphabetic) {
+		return true;
+	}
+
+	match expr {
+		Expr::UnaryExpr(inner) if inner.is_prefix() != Some(false) => match inner.op_token() {
+			Some(inner_op) => inner_op.text().starts_with(op),
+			None => false,
+		},
+		_ => false,
+	}
+}
@@ -0,0 +1,19 @@
+use crate::{concat_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::YieldExpr;
+
+impl ToFormatElement for YieldExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![formatter.format_token(&self.yield_token()?)?];
+
+		if let Some(star_token) = self.star_token() {
+			tokens.push(formatter.format_token(&star_token)?);
+		}
+
+		if let Some(value) = self.value() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(value)?);
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
@@ -1,24 +1,52 @@
-use crate::{
-	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
+use crate::ts::typescript::{
+	find_token, format_decorators, format_modifiers, format_type_annotation,
 };
+use crate::{concat_elements, space_token, token, FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::Method;
+use rslint_parser::{AstNode, SyntaxKind};
 
 impl ToFormatElement for Method {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let private_token = if let Some(static_token) = self.static_token() {
-			format_elements![formatter.format_token(&static_token)?, space_token()]
-		} else {
-			empty_element()
-		};
-		let name = formatter.format_node(self.name()?)?;
-		let params = formatter.format_node(self.parameters()?)?;
-		let body = formatter.format_node(self.body()?)?;
-		Some(format_elements![
-			private_token,
-			name,
-			params,
-			space_token(),
-			body
-		])
+		let mut tokens = vec![
+			format_decorators(self.decorators(), formatter)?,
+			format_modifiers(self.syntax(), formatter)?,
+		];
+
+		if let Some(async_token) = self.async_token() {
+			tokens.push(formatter.format_token(&async_token)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(star_token) = self.star_token() {
+			tokens.push(formatter.format_token(&star_token)?);
+		}
+
+		tokens.push(formatter.format_node(self.name()?)?);
+
+		if let Some(question_mark) = find_token(self.syntax(), SyntaxKind::QUESTION) {
+			tokens.push(formatter.format_token(&question_mark)?);
+		}
+
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		tokens.push(formatter.format_node(self.parameters()?)?);
+		tokens.push(format_type_annotation(
+			self.colon_token(),
+			self.return_type(),
+			formatter,
+		)?);
+
+		// abstract methods and overloads have no body
+		match self.body() {
+			Some(body) => {
+				tokens.push(space_token());
+				tokens.push(formatter.format_node(body)?);
+			}
+			None => tokens.push(token(";")),
+		}
+
+		Some(concat_elements(tokens))
 	}
 }
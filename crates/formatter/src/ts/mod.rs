@@ -7,6 +7,7 @@ mod expr_or_spread;
 mod expressions;
 mod getter;
 mod method;
+mod module;
 mod name;
 mod parameter_list;
 mod patterns;
@@ -16,12 +17,88 @@ mod setter;
 mod spread;
 mod statements;
 mod tokens;
+mod typescript;
+
+use crate::{
+	empty_element, format_elements, if_group_breaks, indent, join_elements, soft_indent,
+	soft_line_break_or_space, token, FormatElement, Formatter, Semicolons, TrailingComma,
+};
+use rslint_parser::SyntaxKind;
+
+/// Returns the `;` that terminates a statement, or an empty element if the statement doesn't need
+/// a semicolon because the [Semicolons] option is set to as needed.
+pub(crate) fn format_semicolon(formatter: &Formatter) -> FormatElement {
+	match formatter.options().semicolons {
+		Semicolons::Always => token(";"),
+		Semicolons::AsNeeded => empty_element(),
+	}
+}
+
+/// Returns `true` if a statement starting with a token of the given kind must be preceded by a `;`
+/// if semicolons are only printed as needed. Automatic semicolon insertion otherwise joins the
+/// statement with the previous line, e.g. `a\n(b)` is the call `a(b)`.
+pub(crate) fn is_asi_hazard(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::L_PAREN
+			| SyntaxKind::L_BRACK
+			| SyntaxKind::BACKTICK
+			| SyntaxKind::PLUS
+			| SyntaxKind::MINUS
+			| SyntaxKind::REGEX
+			| SyntaxKind::L_ANGLE
+	)
+}
+
+/// Returns the trailing `,` of a list that's only printed if the enclosing group breaks
+/// and the [TrailingComma] option allows trailing commas in lists of the given `kind`.
+pub(crate) fn format_trailing_comma(kind: TrailingComma, formatter: &Formatter) -> FormatElement {
+	if formatter.options().trailing_comma >= kind {
+		if_group_breaks(token(","))
+	} else {
+		empty_element()
+	}
+}
+
+/// Formats the members of an object-like node (e.g. an object literal or the named imports) that
+/// are enclosed by braces. The members are separated by a line break or a space, the last member is
+/// followed by the `trailing_separator`.
+///
+/// The members are separated from the braces by a space if the `bracket_spacing` option is set and
+/// the object fits on a single line. Objects without members are always printed as `{}`.
+pub(crate) fn format_object_members(
+	members: impl IntoIterator<Item = FormatElement>,
+	trailing_separator: FormatElement,
+	formatter: &Formatter,
+) -> FormatElement {
+	let members = members.into_iter().collect::<Vec<_>>();
+
+	if members.is_empty() {
+		return empty_element();
+	}
+
+	let members = format_elements![
+		join_elements(soft_line_break_or_space(), members),
+		trailing_separator
+	];
+
+	if formatter.options().bracket_spacing {
+		format_elements![
+			indent(format_elements![soft_line_break_or_space(), members]),
+			soft_line_break_or_space()
+		]
+	} else {
+		soft_indent(members)
+	}
+}
 
 #[cfg(test)]
 mod test {
+	use core::file_handlers::Language;
+	use core::App;
 	use rslint_parser::parse_text;
 
-	use crate::Formatter;
+	use crate::{format_text, FormatOptions, Formatter, LineEndingStyle};
 
 	#[test]
 	fn arrow_function() {
@@ -73,4 +150,62 @@ mod test {
 "#
 		);
 	}
+
+	#[test]
+	fn source_map() {
+		let src = "let  a =   10;\nfoo(  a );";
+		let tree = parse_text(src, 0);
+		let result = Formatter::default().format_root(&tree.syntax());
+
+		assert_eq!(result.code(), "let a = 10;\nfoo(a);\n");
+		assert_eq!(
+			result.source_map("test.js", src).mappings(),
+			"AAAA,IAAK,IAAM;AACX,GAAG,CAAG"
+		);
+	}
+
+	#[test]
+	fn line_ending_auto_uses_the_line_ending_of_the_source() {
+		let src = "let a = `multi\r\nline`;\r\nfoo(  a );\r\n";
+		let options = FormatOptions {
+			line_ending: LineEndingStyle::Auto,
+			..FormatOptions::default()
+		};
+		let result = format_text(src, Language::Js, options, &App::new()).unwrap();
+
+		assert_eq!(result.code(), "let a = `multi\r\nline`;\r\nfoo(a);\r\n");
+	}
+
+	#[test]
+	fn line_ending_converts_the_line_endings_of_the_source() {
+		let src = "let a = `multi\r\nline`;\r\n/* a\r\n * b */\r\nfoo(  a );\r\n";
+		let tree = parse_text(src, 0);
+		let result = Formatter::default().format_root(&tree.syntax());
+
+		assert_eq!(
+			result.code(),
+			"let a = `multi\nline`;\n/* a\n * b */\nfoo(a);\n"
+		);
+	}
+
+	#[test]
+	fn line_ending_auto_detects_the_most_common_line_ending() {
+		assert_eq!(
+			LineEndingStyle::Auto.resolve("a\r\nb\r\nc\n"),
+			LineEndingStyle::Crlf
+		);
+		assert_eq!(
+			LineEndingStyle::Auto.resolve("a\rb\rc\n"),
+			LineEndingStyle::Cr
+		);
+		assert_eq!(
+			LineEndingStyle::Auto.resolve("a\r\nb\n"),
+			LineEndingStyle::Lf
+		);
+		assert_eq!(LineEndingStyle::Auto.resolve("a"), LineEndingStyle::Lf);
+		assert_eq!(
+			LineEndingStyle::Crlf.resolve("a\nb\n"),
+			LineEndingStyle::Crlf
+		);
+	}
 }
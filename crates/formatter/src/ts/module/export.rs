@@ -0,0 +1,167 @@
+use crate::ts::typescript::format_decorators;
+use crate::ts::{format_object_members, format_semicolon, format_trailing_comma};
+use crate::{
+	concat_elements, empty_element, format_elements, group_elements, space_token, FormatElement,
+	Formatter, ToFormatElement, TrailingComma,
+};
+use rslint_parser::ast::{
+	DefaultDecl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportNamed, ExportWildcard,
+	Expr, Literal, TsInterfaceDecl,
+};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for ExportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// decorators of an exported class are attached to the export: `@dec export class A {}`
+		let decorators = format_decorators(self.decorators(), formatter)?;
+		let export_token = formatter.format_token(&self.export_token()?)?;
+		let type_token = match self.type_token() {
+			Some(type_token) => {
+				format_elements![formatter.format_token(&type_token)?, space_token()]
+			}
+			None => empty_element(),
+		};
+
+		// The parser wraps `export { a }` in an export declaration without a declaration
+		let exported = match self.decl() {
+			Some(decl) => formatter.format_node(decl)?,
+			None => formatter.format_node(self.syntax().children().find_map(ExportNamed::cast)?)?,
+		};
+
+		Some(format_elements![
+			decorators,
+			export_token,
+			space_token(),
+			type_token,
+			exported
+		])
+	}
+}
+
+impl ToFormatElement for ExportNamed {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(export_token) = self.export_token() {
+			tokens.push(formatter.format_token(&export_token)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(type_token) = self.type_token() {
+			tokens.push(formatter.format_token(&type_token)?);
+			tokens.push(space_token());
+		}
+
+		let specifiers = formatter.format_separated(self.specifiers())?;
+
+		tokens.push(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			format_object_members(
+				specifiers,
+				format_trailing_comma(TrailingComma::Es5, formatter),
+				formatter
+			),
+			formatter.format_token(&self.r_curly_token()?)?
+		]));
+
+		if let Some(from_token) = self.from_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&from_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(self.syntax().children().find_map(Literal::cast)?)?);
+		}
+
+		tokens.push(format_semicolon(formatter));
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for ExportWildcard {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+		];
+
+		if let Some(type_token) = self.type_token() {
+			tokens.push(formatter.format_token(&type_token)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_token(&self.star_token()?)?);
+
+		if let Some(as_token) = self.as_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&as_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&self.ident_token()?)?);
+		}
+
+		tokens.push(space_token());
+		tokens.push(formatter.format_token(&self.from_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.syntax().children().find_map(Literal::cast)?)?);
+		tokens.push(format_semicolon(formatter));
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for ExportDefaultDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		if self.type_token().is_some() {
+			return None;
+		}
+
+		// `DefaultDecl` doesn't include interfaces: `export default interface A {}`
+		let decl = match self.decl() {
+			Some(decl) => formatter.format_node(decl)?,
+			None => {
+				formatter.format_node(self.syntax().children().find_map(TsInterfaceDecl::cast)?)?
+			}
+		};
+
+		Some(format_elements![
+			format_decorators(self.decorators(), formatter)?,
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			formatter.format_token(&self.default_token()?)?,
+			space_token(),
+			decl
+		])
+	}
+}
+
+impl ToFormatElement for DefaultDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			DefaultDecl::FnDecl(fn_decl) => fn_decl.to_format_element(formatter),
+			DefaultDecl::ClassDecl(class_decl) => class_decl.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for ExportDefaultExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		if self.type_token().is_some() {
+			return None;
+		}
+
+		let expr = self.expr()?;
+		// functions and classes are declarations that don't need a terminating semicolon
+		let semicolon = match &expr {
+			Expr::FnExpr(_) | Expr::ClassExpr(_) => empty_element(),
+			_ => format_semicolon(formatter),
+		};
+
+		Some(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			formatter.format_token(&self.default_token()?)?,
+			space_token(),
+			formatter.format_node(expr)?,
+			semicolon
+		])
+	}
+}
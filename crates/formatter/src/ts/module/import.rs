@@ -0,0 +1,124 @@
+use crate::ts::{format_object_members, format_semicolon, format_trailing_comma};
+use crate::{
+	concat_elements, format_elements, group_elements, join_elements, space_token, FormatElement,
+	Formatter, ToFormatElement, TrailingComma,
+};
+use rslint_parser::ast::{
+	ImportClause, ImportDecl, ImportStringSpecifier, Name, NamedImports, Specifier, WildcardImport,
+};
+use rslint_parser::{AstNode, SyntaxKind};
+
+impl ToFormatElement for ImportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![formatter.format_token(&self.import_token()?)?];
+
+		if let Some(type_token) = self.type_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&type_token)?);
+		}
+
+		let imports = formatter
+			.format_separated(self.imports())?
+			.collect::<Vec<_>>();
+		if !imports.is_empty() {
+			tokens.push(space_token());
+			tokens.push(join_elements(space_token(), imports));
+		}
+
+		if let Some(from_token) = self.from_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&from_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(self.source()?)?);
+		}
+
+		if let Some(assert_token) = self.assert_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&assert_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(self.asserted_object()?)?);
+		}
+
+		tokens.push(format_semicolon(formatter));
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for ImportClause {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			ImportClause::WildcardImport(wildcard) => wildcard.to_format_element(formatter),
+			ImportClause::NamedImports(named_imports) => named_imports.to_format_element(formatter),
+			ImportClause::Name(name) => name.to_format_element(formatter),
+			ImportClause::ImportStringSpecifier(specifier) => {
+				specifier.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for WildcardImport {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.star_token()?)?,
+			space_token(),
+			formatter.format_token(&self.as_token()?)?,
+			space_token(),
+			formatter.format_node(self.alias()?)?
+		])
+	}
+}
+
+impl ToFormatElement for NamedImports {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let specifiers = formatter.format_separated(self.specifiers())?;
+
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			format_object_members(
+				specifiers,
+				format_trailing_comma(TrailingComma::Es5, formatter),
+				formatter
+			),
+			formatter.format_token(&self.r_curly_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for ImportStringSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let source = self
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|child| child.into_token())
+			.find(|child| child.kind() == SyntaxKind::STRING)?;
+
+		formatter.format_token(&source)
+	}
+}
+
+impl ToFormatElement for Specifier {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let name = formatter.format_node(Name::cast(self.name()?)?)?;
+
+		match self.alias() {
+			Some(alias) => {
+				let as_token = self
+					.syntax()
+					.children_with_tokens()
+					.filter_map(|child| child.into_token())
+					.find(|child| child.kind() == SyntaxKind::AS_KW)?;
+
+				Some(format_elements![
+					name,
+					space_token(),
+					formatter.format_token(&as_token)?,
+					space_token(),
+					formatter.format_node(alias)?
+				])
+			}
+			None => Some(name),
+		}
+	}
+}
@@ -0,0 +1,66 @@
+use crate::ts::statements::format_statements;
+use crate::{format_elements, hard_line_break, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{Module, ModuleItem};
+use rslint_parser::{AstNode, SyntaxKind};
+
+mod export;
+mod import;
+
+impl ToFormatElement for Module {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// The parser emits the tokens of some (malformed) exports, e.g. `export * as a from "a"`,
+		// directly into the module instead of wrapping them in an item.
+		let has_loose_tokens = self
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|child| child.into_token())
+			.any(|token| !token.kind().is_trivia() && token.kind() != SyntaxKind::SHEBANG);
+
+		if has_loose_tokens {
+			return None;
+		}
+
+		let mut elements = vec![];
+
+		if let Some(shebang) = self.shebang_token() {
+			elements.push(formatter.format_token(&shebang)?);
+			elements.push(hard_line_break());
+		}
+
+		elements.push(format_statements(self.items(), formatter));
+
+		Some(format_elements![
+			concat_elements(elements),
+			hard_line_break()
+		])
+	}
+}
+
+impl ToFormatElement for ModuleItem {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			ModuleItem::ImportDecl(import_decl) => import_decl.to_format_element(formatter),
+			ModuleItem::ExportNamed(export_named) => export_named.to_format_element(formatter),
+			ModuleItem::ExportDefaultDecl(export_default_decl) => {
+				export_default_decl.to_format_element(formatter)
+			}
+			ModuleItem::ExportDefaultExpr(export_default_expr) => {
+				export_default_expr.to_format_element(formatter)
+			}
+			ModuleItem::ExportWildcard(export_wildcard) => {
+				export_wildcard.to_format_element(formatter)
+			}
+			ModuleItem::ExportDecl(export_decl) => export_decl.to_format_element(formatter),
+			ModuleItem::TsImportEqualsDecl(import_equals) => {
+				import_equals.to_format_element(formatter)
+			}
+			ModuleItem::TsExportAssignment(export_assignment) => {
+				export_assignment.to_format_element(formatter)
+			}
+			ModuleItem::TsNamespaceExportDecl(namespace_export) => {
+				namespace_export.to_format_element(formatter)
+			}
+			ModuleItem::Stmt(stmt) => stmt.to_format_element(formatter),
+		}
+	}
+}
@@ -1,19 +1,35 @@
+use crate::ts::format_trailing_comma;
 use crate::{
-	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space, token,
-	FormatElement, Formatter, ToFormatElement,
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, FormatElement, Formatter, ToFormatElement, TrailingComma,
 };
-use rslint_parser::ast::ParameterList;
+use rslint_parser::ast::{ConstructorParamOrPat, ParameterList};
+use rslint_parser::{AstNode, SyntaxKind};
 
 impl ToFormatElement for ParameterList {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let param_tokens = formatter.format_children(self.parameters())?;
+		// the parameters of a constructor can also be TypeScript parameter properties, which
+		// aren't patterns: `constructor(private a: number)`
+		let params = self
+			.syntax()
+			.children()
+			.filter_map(ConstructorParamOrPat::cast);
+
+		// a rest parameter must be the last parameter and can't be followed by a comma
+		let trailing_comma = match params.clone().last() {
+			Some(last) if last.syntax().kind() != SyntaxKind::REST_PATTERN => {
+				format_trailing_comma(TrailingComma::All, formatter)
+			}
+			_ => empty_element(),
+		};
+		let param_tokens = formatter.format_separated(params)?;
 
 		Some(group_elements(format_elements![
 			formatter.format_token(&self.l_paren_token()?)?,
-			soft_indent(join_elements(
-				format_elements![token(","), soft_line_break_or_space()],
-				param_tokens,
-			),),
+			soft_indent(format_elements![
+				join_elements(soft_line_break_or_space(), param_tokens),
+				trailing_comma
+			]),
 			formatter.format_token(&self.r_paren_token()?)?
 		]))
 	}
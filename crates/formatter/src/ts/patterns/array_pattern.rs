@@ -1,5 +1,6 @@
+use crate::ts::typescript::format_type_annotation;
 use crate::{
-	format_elements, group_elements, join_elements, token, FormatElement, Formatter,
+	format_elements, group_elements, join_elements, space_token, FormatElement, Formatter,
 	ToFormatElement,
 };
 use rslint_parser::ast::ArrayPattern;
@@ -7,13 +8,16 @@ use rslint_parser::ast::ArrayPattern;
 impl ToFormatElement for ArrayPattern {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_brack_token()?)?;
-		let elements = formatter.format_children(self.elements())?;
+		let elements = formatter.format_separated(self.elements())?;
 		let r_bracket = formatter.format_token(&self.r_brack_token()?)?;
 
-		Some(format_elements![group_elements(format_elements![
-			l_bracket,
-			join_elements(token(", "), elements),
-			r_bracket
-		])])
+		Some(format_elements![
+			group_elements(format_elements![
+				l_bracket,
+				join_elements(space_token(), elements),
+				r_bracket
+			]),
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?
+		])
 	}
 }
@@ -1,22 +1,24 @@
-use crate::{empty_element, format_elements, FormatElement, Formatter, ToFormatElement};
-use rslint_parser::ast::AssignPattern;
+use crate::ts::typescript::{format_decorators, format_type_annotation};
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{AssignPattern, Name};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for AssignPattern {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		let key = if let Some(key) = self.key() {
-			formatter.format_node(key)?
-		} else {
-			empty_element()
-		};
-		let assign = if let Some(eq_token) = self.eq_token() {
-			formatter.format_token(&eq_token)?
-		} else if let Some(colon_token) = self.colon_token() {
-			formatter.format_token(&colon_token)?
-		} else {
-			empty_element()
+		// the parser doesn't wrap the name of a parameter with a default value in a pattern
+		let key = match self.key() {
+			Some(key) => formatter.format_node(key)?,
+			None => formatter.format_node(self.syntax().children().find_map(Name::cast)?)?,
 		};
 
-		let value = formatter.format_node(self.value()?)?;
-		Some(format_elements![key, assign, value])
+		Some(format_elements![
+			format_decorators(self.decorators(), formatter)?,
+			key,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?,
+			space_token(),
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.value()?)?
+		])
 	}
 }
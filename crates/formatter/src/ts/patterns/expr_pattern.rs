@@ -0,0 +1,8 @@
+use crate::{FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::ExprPattern;
+
+impl ToFormatElement for ExprPattern {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		formatter.format_node(self.expr()?)
+	}
+}
@@ -1,4 +1,7 @@
 mod array_pattern;
 mod assign_pattern;
+mod expr_pattern;
+mod object_pattern;
 mod pattern;
+mod rest_pattern;
 mod single_pattern;
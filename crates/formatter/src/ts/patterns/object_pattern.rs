@@ -0,0 +1,49 @@
+use crate::ts::typescript::format_type_annotation;
+use crate::ts::{format_object_members, format_trailing_comma};
+use crate::{
+	empty_element, format_elements, group_elements, space_token, FormatElement, Formatter,
+	ToFormatElement, TrailingComma,
+};
+use rslint_parser::ast::{KeyValuePattern, ObjectPattern, ObjectPatternProp};
+
+impl ToFormatElement for ObjectPattern {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// a rest element must be the last element and can't be followed by a comma
+		let trailing_comma = match self.elements().last() {
+			Some(ObjectPatternProp::RestPattern(_)) => empty_element(),
+			_ => format_trailing_comma(TrailingComma::Es5, formatter),
+		};
+		let elements = formatter.format_separated(self.elements())?;
+
+		Some(format_elements![
+			group_elements(format_elements![
+				formatter.format_token(&self.l_curly_token()?)?,
+				format_object_members(elements, trailing_comma, formatter),
+				formatter.format_token(&self.r_curly_token()?)?,
+			]),
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for ObjectPatternProp {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			ObjectPatternProp::AssignPattern(pattern) => pattern.to_format_element(formatter),
+			ObjectPatternProp::KeyValuePattern(pattern) => pattern.to_format_element(formatter),
+			ObjectPatternProp::RestPattern(pattern) => pattern.to_format_element(formatter),
+			ObjectPatternProp::SinglePattern(pattern) => pattern.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for KeyValuePattern {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.key()?)?,
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.value()?)?
+		])
+	}
+}
@@ -4,11 +4,11 @@ use rslint_parser::ast::Pattern;
 impl ToFormatElement for Pattern {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		match self {
-			Pattern::RestPattern(_) => todo!(),
+			Pattern::RestPattern(pattern) => pattern.to_format_element(formatter),
 			Pattern::AssignPattern(pattern) => pattern.to_format_element(formatter),
-			Pattern::ObjectPattern(_) => todo!(),
+			Pattern::ObjectPattern(pattern) => pattern.to_format_element(formatter),
 			Pattern::ArrayPattern(array_pattern) => array_pattern.to_format_element(formatter),
-			Pattern::ExprPattern(_) => todo!(),
+			Pattern::ExprPattern(pattern) => pattern.to_format_element(formatter),
 			Pattern::SinglePattern(single) => single.to_format_element(formatter),
 		}
 	}
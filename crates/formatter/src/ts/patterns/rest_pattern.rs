@@ -0,0 +1,13 @@
+use crate::ts::typescript::format_type_annotation;
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::RestPattern;
+
+impl ToFormatElement for RestPattern {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.dotdotdot_token()?)?,
+			formatter.format_node(self.pat()?)?,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?
+		])
+	}
+}
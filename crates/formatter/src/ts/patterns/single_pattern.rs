@@ -1,9 +1,24 @@
-use crate::{FormatElement, Formatter, ToFormatElement};
+use crate::ts::typescript::{format_decorators, format_type_annotation};
+use crate::{empty_element, format_elements, FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::SinglePattern;
 
 impl ToFormatElement for SinglePattern {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
-		// TODO: implementation not finished
-		formatter.format_node(self.name()?)
+		let question_mark = match self.question_mark_token() {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+		let excl = match self.excl_token() {
+			Some(excl) => formatter.format_token(&excl)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![
+			format_decorators(self.decorators(), formatter)?,
+			formatter.format_node(self.name()?)?,
+			question_mark,
+			excl,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?
+		])
 	}
 }
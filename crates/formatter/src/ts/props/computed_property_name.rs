@@ -0,0 +1,12 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::ComputedPropertyName;
+
+impl ToFormatElement for ComputedPropertyName {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(self.prop()?)?,
+			formatter.format_token(&self.r_brack_token()?)?
+		])
+	}
+}
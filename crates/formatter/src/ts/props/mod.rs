@@ -1,4 +1,7 @@
+mod computed_property_name;
 mod ident_prop;
+mod initialized_prop;
 mod literal_prop;
 mod object_prop;
 mod prop_name;
+mod spread_prop;
@@ -7,10 +7,10 @@ impl ToFormatElement for ObjectProp {
 			ObjectProp::LiteralProp(literal_prop) => literal_prop.to_format_element(formatter),
 			ObjectProp::Getter(getter) => getter.to_format_element(formatter),
 			ObjectProp::Setter(setter) => setter.to_format_element(formatter),
-			ObjectProp::SpreadProp(_) => todo!(),
-			ObjectProp::InitializedProp(_) => todo!(),
+			ObjectProp::SpreadProp(spread) => spread.to_format_element(formatter),
+			ObjectProp::InitializedProp(initialized) => initialized.to_format_element(formatter),
 			ObjectProp::IdentProp(ident) => ident.to_format_element(formatter),
-			ObjectProp::Method(_) => todo!(),
+			ObjectProp::Method(method) => method.to_format_element(formatter),
 		}
 	}
 }
@@ -5,8 +5,8 @@ use crate::{FormatElement, Formatter, ToFormatElement};
 impl ToFormatElement for PropName {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		match self {
-			PropName::Computed(_) => todo!(),
-			PropName::Literal(_) => todo!(),
+			PropName::Computed(computed) => computed.to_format_element(formatter),
+			PropName::Literal(literal) => literal.to_format_element(formatter),
 			PropName::Ident(ident) => ident.to_format_element(formatter),
 		}
 	}
@@ -1,17 +1,32 @@
+use crate::ts::typescript::{
+	find_token, format_decorators, format_modifiers, format_type_annotation,
+};
 use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
-use rslint_parser::ast::Setter;
+use rslint_parser::ast::{Setter, TsType};
+use rslint_parser::{AstNode, SyntaxKind};
 
 impl ToFormatElement for Setter {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let decorators = format_decorators(self.decorators(), formatter)?;
+		let modifiers = format_modifiers(self.syntax(), formatter)?;
 		let token = formatter.format_token(&self.set_token()?)?;
 		let name = formatter.format_node(self.key()?)?;
 		let params = formatter.format_node(self.parameters()?)?;
+		// the return type has no accessor
+		let return_type = format_type_annotation(
+			find_token(self.syntax(), SyntaxKind::COLON),
+			self.syntax().children().find_map(TsType::cast),
+			formatter,
+		)?;
 		let body = formatter.format_node(self.body()?)?;
 		Some(format_elements![
+			decorators,
+			modifiers,
 			token,
 			space_token(),
 			name,
 			params,
+			return_type,
 			space_token(),
 			body
 		])
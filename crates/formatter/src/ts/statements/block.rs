@@ -1,6 +1,7 @@
 use rslint_parser::ast::{BlockStmt, IfStmt};
 use rslint_parser::AstNode;
 
+use crate::comments::trailing_comments;
 use crate::ts::statements::format_statements;
 use crate::{
 	block_indent, format_elements, hard_line_break, FormatElement, Formatter, ToFormatElement,
@@ -14,14 +15,23 @@ impl ToFormatElement for BlockStmt {
 		// * empty block: same line `{}`,
 		// * empty block that is the 'cons' or 'alt' of an if statement: two lines `{\n}`
 		// * non empty block: put each stmt on its own line: `{\nstmt1;\nstmt2;\n}`
-		let body = if stmts.is_empty() && self.syntax().parent().and_then(IfStmt::cast).is_some() {
+		// An empty block with comments on their own lines already breaks after the comments.
+		let l_curly = self.l_curly_token()?;
+		let has_own_line_comment = trailing_comments(&l_curly)
+			.iter()
+			.any(|comment| comment.has_line_break_before());
+
+		let body = if stmts.is_empty()
+			&& !has_own_line_comment
+			&& self.syntax().parent().and_then(IfStmt::cast).is_some()
+		{
 			hard_line_break()
 		} else {
 			block_indent(stmts)
 		};
 
 		Some(format_elements![
-			formatter.format_token(&self.l_curly_token()?)?,
+			formatter.format_token(&l_curly)?,
 			body,
 			formatter.format_token(&self.r_curly_token()?)?
 		])
@@ -1,5 +1,6 @@
+use crate::ts::format_semicolon;
 use crate::{
-	empty_element, format_elements, group_elements, space_token, token, FormatElement, Formatter,
+	empty_element, format_elements, group_elements, space_token, FormatElement, Formatter,
 	ToFormatElement,
 };
 use rslint_parser::ast::BreakStmt;
@@ -16,6 +17,10 @@ impl ToFormatElement for BreakStmt {
 			empty_element()
 		};
 
-		Some(format_elements![break_element, ident, token(";")])
+		Some(format_elements![
+			break_element,
+			ident,
+			format_semicolon(formatter)
+		])
 	}
 }
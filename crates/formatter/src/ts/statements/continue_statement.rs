@@ -1,5 +1,6 @@
+use crate::ts::format_semicolon;
 use crate::{
-	empty_element, format_elements, space_token, token, FormatElement, Formatter, ToFormatElement,
+	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::ContinueStmt;
 
@@ -12,6 +13,10 @@ impl ToFormatElement for ContinueStmt {
 			empty_element()
 		};
 		let continue_token = formatter.format_token(&self.continue_token()?)?;
-		Some(format_elements![continue_token, ident, token(";")])
+		Some(format_elements![
+			continue_token,
+			ident,
+			format_semicolon(formatter)
+		])
 	}
 }
@@ -1,11 +1,12 @@
-use crate::{format_elements, token, FormatElement, Formatter, ToFormatElement};
+use crate::ts::format_semicolon;
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::DebuggerStmt;
 
 impl ToFormatElement for DebuggerStmt {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
 		Some(format_elements![
 			formatter.format_token(&self.debugger_token()?)?,
-			token(";")
+			format_semicolon(formatter)
 		])
 	}
 }
@@ -1,4 +1,5 @@
-use crate::{format_elements, space_token, token, FormatElement, Formatter, ToFormatElement};
+use crate::ts::format_semicolon;
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::DoWhileStmt;
 
 impl ToFormatElement for DoWhileStmt {
@@ -17,7 +18,7 @@ impl ToFormatElement for DoWhileStmt {
 			while_token,
 			space_token(),
 			condition,
-			token(";")
+			format_semicolon(formatter)
 		])
 	}
 }
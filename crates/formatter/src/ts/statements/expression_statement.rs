@@ -1,12 +1,31 @@
+use crate::comments::first_non_trivia_token;
+use crate::ts::{format_semicolon, is_asi_hazard};
+use crate::{
+	empty_element, format_elements, token, FormatElement, Formatter, Semicolons, ToFormatElement,
+};
 use rslint_parser::ast::ExprStmt;
-
-use crate::{format_elements, token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for ExprStmt {
 	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let expr = self.expr()?;
+
+		// Without semicolons, a statement starting with e.g. a `(` would continue the previous
+		// statement. The `;` in front of it prevents that: `;(a || b).c()`
+		let leading_semicolon = match first_non_trivia_token(expr.syntax()) {
+			Some(first)
+				if formatter.options().semicolons == Semicolons::AsNeeded
+					&& is_asi_hazard(first.kind()) =>
+			{
+				token(";")
+			}
+			_ => empty_element(),
+		};
+
 		Some(format_elements![
-			formatter.format_node(self.expr()?)?,
-			token(";")
+			leading_semicolon,
+			formatter.format_node(expr)?,
+			format_semicolon(formatter)
 		])
 	}
 }
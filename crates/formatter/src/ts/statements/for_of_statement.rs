@@ -0,0 +1,48 @@
+use rslint_parser::ast::ForOfStmt;
+use rslint_parser::{AstNode, SyntaxKind};
+
+use crate::{
+	format_elements, group_elements, soft_indent, soft_line_break_or_space, space_token,
+	FormatElement, Formatter, ToFormatElement,
+};
+
+impl ToFormatElement for ForOfStmt {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut for_tokens = vec![formatter.format_token(&self.for_token()?)?];
+
+		if let Some(await_token) = self.await_token() {
+			for_tokens.push(space_token());
+			for_tokens.push(formatter.format_token(&await_token)?);
+		}
+
+		// `of` is a contextual keyword that the parser emits as an identifier
+		let of_token = self
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|child| child.into_token())
+			.find(|child| child.kind() == SyntaxKind::IDENT && child.text() == "of")?;
+
+		let l_paren = formatter.format_token(&self.l_paren_token()?)?;
+		let left = formatter.format_node(self.left()?)?;
+		let of_token = formatter.format_token(&of_token)?;
+		let right = formatter.format_node(self.right()?)?;
+		let r_paren = formatter.format_token(&self.r_paren_token()?)?;
+		let cons = formatter.format_node(self.cons()?)?;
+
+		Some(format_elements![
+			concat_elements(for_tokens),
+			space_token(),
+			l_paren,
+			group_elements(soft_indent(format_elements![
+				left,
+				soft_line_break_or_space(),
+				of_token,
+				soft_line_break_or_space(),
+				right,
+			])),
+			r_paren,
+			space_token(),
+			cons
+		])
+	}
+}
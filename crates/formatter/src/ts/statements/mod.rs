@@ -1,6 +1,7 @@
-use crate::{hard_line_break, join_elements, FormatElement, Formatter};
-use rslint_parser::ast::{AstChildren, Stmt};
-use rslint_parser::AstNode;
+use crate::formatter::is_statement_list;
+use crate::{hard_line_break, join_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::AstChildren;
+use rslint_parser::{AstNode, Direction, SyntaxKind, SyntaxNode, WalkEvent};
 
 mod block;
 mod break_statement;
@@ -10,6 +11,7 @@ mod do_while_statement;
 mod empty_statement;
 mod expression_statement;
 mod for_in_statement;
+mod for_of_statement;
 mod for_stmt;
 mod if_stmt;
 mod label_statement;
@@ -20,14 +22,80 @@ mod throw_statement;
 mod try_statement;
 mod while_statement;
 mod with_statement;
-/// Formats a list of statements
-pub fn format_statements(stmts: AstChildren<Stmt>, formatter: &Formatter) -> FormatElement {
-	join_elements(
-		hard_line_break(),
-		stmts.map(|stmt| {
+/// Formats a list of statements or module items. Empty statements are removed.
+///
+/// The syntax errors between the statements, e.g. the `)` in `a);`, are printed as they are in
+/// the source.
+pub fn format_statements<T: AstNode + ToFormatElement + Clone>(
+	stmts: AstChildren<T>,
+	formatter: &Formatter,
+) -> FormatElement {
+	let mut elements = vec![];
+	let mut last = None;
+
+	for stmt in stmts {
+		let mut errors = error_siblings(stmt.syntax(), Direction::Prev).collect::<Vec<_>>();
+		errors.reverse();
+		elements.extend(errors.iter().map(|error| formatter.format_raw(error)));
+
+		let element = if contains_error(stmt.syntax()) {
+			formatter.format_raw(stmt.syntax())
+		} else {
 			formatter
 				.format_node(stmt.clone())
 				.unwrap_or_else(|| formatter.format_raw(stmt.syntax()))
-		}),
-	)
+		};
+
+		if !prints_nothing(&element) {
+			elements.push(element);
+		}
+		last = Some(stmt);
+	}
+
+	if let Some(last) = last {
+		elements.extend(
+			error_siblings(last.syntax(), Direction::Next)
+				.map(|error| formatter.format_raw(&error)),
+		);
+	}
+
+	join_elements(hard_line_break(), elements)
+}
+
+/// Returns `true` if the element doesn't print any text, e.g. the element of an empty statement
+/// that only consists of its source marker.
+fn prints_nothing(element: &FormatElement) -> bool {
+	match element {
+		FormatElement::Empty | FormatElement::SourceMarker(_) => true,
+		FormatElement::List(list) => list.iter().all(prints_nothing),
+		_ => false,
+	}
+}
+
+/// Returns `true` if `stmt` contains a syntax error that isn't part of a nested statement list,
+/// which prints its own errors. The statement is printed as it is in the source because formatting
+/// it may add tokens after the error, e.g. a semicolon after `let a = ;`.
+fn contains_error(stmt: &SyntaxNode) -> bool {
+	let mut preorder = stmt.preorder();
+
+	while let Some(event) = preorder.next() {
+		if let WalkEvent::Enter(node) = event {
+			if node.kind() == SyntaxKind::ERROR {
+				return true;
+			}
+
+			if node != *stmt && is_statement_list(node.kind()) {
+				preorder.skip_subtree();
+			}
+		}
+	}
+
+	false
+}
+
+/// Returns the error nodes that directly precede or follow `node`
+fn error_siblings(node: &SyntaxNode, direction: Direction) -> impl Iterator<Item = SyntaxNode> {
+	node.siblings(direction)
+		.skip(1)
+		.take_while(|sibling| sibling.kind() == SyntaxKind::ERROR)
 }
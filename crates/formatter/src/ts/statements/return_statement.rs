@@ -1,3 +1,4 @@
+use crate::ts::format_semicolon;
 use crate::{concat_elements, space_token, token, FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::ReturnStmt;
 
@@ -10,7 +11,7 @@ impl ToFormatElement for ReturnStmt {
 			tokens.push(formatter.format_node(value)?);
 		}
 
-		tokens.push(token(";"));
+		tokens.push(format_semicolon(formatter));
 
 		Some(concat_elements(tokens))
 	}
@@ -14,6 +14,7 @@ impl ToFormatElement for Stmt {
 			Stmt::WhileStmt(while_statement) => while_statement.to_format_element(formatter),
 			Stmt::ForStmt(for_stmt) => for_stmt.to_format_element(formatter),
 			Stmt::ForInStmt(for_in_statement) => for_in_statement.to_format_element(formatter),
+			Stmt::ForOfStmt(for_of_statement) => for_of_statement.to_format_element(formatter),
 			Stmt::ContinueStmt(continue_statement) => {
 				continue_statement.to_format_element(formatter)
 			}
@@ -1,4 +1,5 @@
-use crate::{format_elements, space_token, token, FormatElement, Formatter, ToFormatElement};
+use crate::ts::format_semicolon;
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
 use rslint_parser::ast::ThrowStmt;
 
 impl ToFormatElement for ThrowStmt {
@@ -9,7 +10,7 @@ impl ToFormatElement for ThrowStmt {
 			throw_token,
 			space_token(),
 			exception,
-			token(";")
+			format_semicolon(formatter)
 		])
 	}
 }
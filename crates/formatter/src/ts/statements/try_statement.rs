@@ -1,5 +1,5 @@
 use crate::{
-	empty_element, format_elements, space_token, token, FormatElement, Formatter, ToFormatElement,
+	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{CatchClause, Finalizer, TryStmt};
 
@@ -47,7 +47,7 @@ impl ToFormatElement for CatchClause {
 		let cons = formatter.format_node(self.cons()?)?;
 		let catch_token = formatter.format_token(&self.catch_token()?)?;
 		match (l_paren, r_paren, error) {
-			(None, None, None) => Some(format_elements![token("catch"), space_token(), cons]),
+			(None, None, None) => Some(format_elements![catch_token, space_token(), cons]),
 			(Some(l_paren), Some(r_paren), Some(error)) => Some(format_elements![
 				catch_token,
 				space_token(),
@@ -1,16 +1,118 @@
-use crate::{token, FormatElement, Formatter, ToFormatElement};
+use crate::formatter::verbatim_token;
+use crate::{FormatElement, Formatter, QuoteStyle, ToFormatElement};
 use rslint_parser::ast::String as JsString;
 
 impl ToFormatElement for JsString {
-	fn to_format_element(&self, _formatter: &Formatter) -> Option<FormatElement> {
-		let mut content = self.to_string();
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let content = self.to_string();
 
-		// uses single quotes
-		if content.starts_with('\'') {
-			content.replace_range(0..1, "\"");
-			content.replace_range(content.len() - 1..content.len(), "\"");
+		// line continuations may use `\r\n`: `"a\<CR><LF>b"`
+		Some(verbatim_token(&normalize_quotes(
+			&content,
+			formatter.options().quote_style,
+		)))
+	}
+}
+
+/// Prints the string literal `raw` with the `preferred` quotes, unless the string contains more
+/// of the preferred quotes than of the other quotes, in which case using the other quotes requires
+/// fewer escapes: `'a "b"'` stays as it is.
+///
+/// Quotes inside of the string are escaped if they match the enclosing quotes and are unescaped
+/// if they don't need to be escaped anymore.
+fn normalize_quotes(raw: &str, preferred: QuoteStyle) -> String {
+	let content = match raw
+		.strip_prefix(|c| c == '"' || c == '\'')
+		.and_then(|content| content.strip_suffix(|c| c == '"' || c == '\''))
+	{
+		Some(content) => content,
+		None => return raw.to_string(),
+	};
+
+	let mut preferred_count = 0;
+	let mut other_count = 0;
+	let mut chars = content.chars();
+
+	while let Some(char) = chars.next() {
+		if char == '\\' {
+			chars.next();
+		} else if char == preferred.as_char() {
+			preferred_count += 1;
+		} else if char == preferred.other().as_char() {
+			other_count += 1;
 		}
+	}
+
+	let quote = if preferred_count > other_count {
+		preferred.other()
+	} else {
+		preferred
+	}
+	.as_char();
+
+	let mut result = String::with_capacity(raw.len() + 2);
+	let mut chars = content.chars();
+	result.push(quote);
+
+	while let Some(char) = chars.next() {
+		match char {
+			'\\' => match chars.next() {
+				// the other quote doesn't need to be escaped
+				Some(escaped) if (escaped == '"' || escaped == '\'') && escaped != quote => {
+					result.push(escaped)
+				}
+				Some(escaped) => {
+					result.push('\\');
+					result.push(escaped);
+				}
+				None => result.push('\\'),
+			},
+			char if char == quote => {
+				result.push('\\');
+				result.push(char);
+			}
+			char => result.push(char),
+		}
+	}
+
+	result.push(quote);
+	result
+}
+
+#[cfg(test)]
+mod test {
+	use super::normalize_quotes;
+	use crate::QuoteStyle;
+
+	#[test]
+	fn uses_the_preferred_quotes() {
+		assert_eq!(normalize_quotes("'rome'", QuoteStyle::Double), "\"rome\"");
+		assert_eq!(normalize_quotes("\"rome\"", QuoteStyle::Single), "'rome'");
+		assert_eq!(normalize_quotes("'rome'", QuoteStyle::Single), "'rome'");
+	}
+
+	#[test]
+	fn uses_the_quotes_that_require_fewer_escapes() {
+		assert_eq!(
+			normalize_quotes("'say \"hi\"'", QuoteStyle::Double),
+			"'say \"hi\"'"
+		);
+		assert_eq!(normalize_quotes("\"it's\"", QuoteStyle::Single), "\"it's\"");
+	}
 
-		Some(token(content.as_str()))
+	#[test]
+	fn escapes_and_unescapes_quotes() {
+		assert_eq!(
+			normalize_quotes("'it\\'s \"a\" \"b\"'", QuoteStyle::Single),
+			"'it\\'s \"a\" \"b\"'"
+		);
+		assert_eq!(
+			normalize_quotes("\"it\\'s\"", QuoteStyle::Double),
+			"\"it's\""
+		);
+		assert_eq!(
+			normalize_quotes("'a \\\\'", QuoteStyle::Double),
+			"\"a \\\\\""
+		);
 	}
 }
@@ -0,0 +1,51 @@
+use crate::ts::typescript::{
+	find_token, format_decorators, format_modifiers, format_type_annotation,
+};
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{Expr, Name, TsConstructorParam, TsDecorator, TsType};
+use rslint_parser::{AstNode, SyntaxKind};
+
+impl ToFormatElement for TsConstructorParam {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// The parser doesn't wrap the name of a parameter property in a pattern:
+		// `public name?: Type = value`
+		let syntax = self.syntax();
+		let name = formatter.format_node(syntax.children().find_map(Name::cast)?)?;
+
+		let question_mark = match find_token(syntax, SyntaxKind::QUESTION) {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+
+		let type_annotation = format_type_annotation(
+			find_token(syntax, SyntaxKind::COLON),
+			syntax.children().find_map(TsType::cast),
+			formatter,
+		)?;
+
+		let initializer = match (
+			find_token(syntax, SyntaxKind::EQ),
+			syntax.children().find_map(Expr::cast),
+		) {
+			(None, None) => empty_element(),
+			(Some(eq_token), Some(value)) => format_elements![
+				space_token(),
+				formatter.format_token(&eq_token)?,
+				space_token(),
+				formatter.format_node(value)?
+			],
+			_ => return None,
+		};
+
+		Some(format_elements![
+			format_decorators(syntax.children().filter_map(TsDecorator::cast), formatter)?,
+			format_modifiers(syntax, formatter)?,
+			name,
+			question_mark,
+			type_annotation,
+			initializer
+		])
+	}
+}
@@ -0,0 +1,11 @@
+use crate::{format_elements, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::TsDecorator;
+
+impl ToFormatElement for TsDecorator {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.at_token()?)?,
+			formatter.format_node(self.expr()?)?
+		])
+	}
+}
@@ -0,0 +1,61 @@
+use crate::ts::typescript::format_modifiers;
+use crate::{
+	block_indent, concat_elements, format_elements, hard_line_break, join_elements, space_token,
+	token, FormatElement, Formatter, ToFormatElement, TrailingComma,
+};
+use rslint_parser::ast::{Name, TsEnum, TsEnumMember};
+use rslint_parser::{AstNode, SyntaxKind};
+
+impl ToFormatElement for TsEnum {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![format_modifiers(self.syntax(), formatter)?];
+
+		if let Some(const_token) = self.const_token() {
+			tokens.push(formatter.format_token(&const_token)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_token(&self.enum_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.syntax().children().find_map(Name::cast)?)?);
+		tokens.push(space_token());
+
+		// every member is on its own line and followed by a comma, unless trailing commas are disabled
+		let members = formatter
+			.format_separated(self.members())?
+			.collect::<Vec<_>>();
+		let has_members = !members.is_empty();
+		let mut members = join_elements(hard_line_break(), members);
+		if has_members && formatter.options().trailing_comma >= TrailingComma::Es5 {
+			members = format_elements![members, token(",")];
+		}
+
+		tokens.push(formatter.format_token(&self.l_curly_token()?)?);
+		tokens.push(block_indent(members));
+		tokens.push(formatter.format_token(&self.r_curly_token()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsEnumMember {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// the name is either an identifier or a string
+		let name = self
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|child| child.into_token())
+			.find(|child| matches!(child.kind(), SyntaxKind::IDENT | SyntaxKind::STRING))?;
+
+		let mut tokens = vec![formatter.format_token(&name)?];
+
+		if let Some(eq_token) = self.eq_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&eq_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(self.value()?)?);
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
@@ -0,0 +1,57 @@
+use crate::ts::typescript::find_ident;
+use crate::{format_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{TsAssertion, TsConstAssertion, TsNonNull};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for TsNonNull {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.target()?)?,
+			formatter.format_token(&self.excl_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsAssertion {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `<Type>expr`
+		if let Some(l_angle) = self.l_angle_token() {
+			return Some(format_elements![
+				formatter.format_token(&l_angle)?,
+				formatter.format_node(self.ty()?)?,
+				formatter.format_token(&self.r_angle_token()?)?,
+				formatter.format_node(self.expr()?)?
+			]);
+		}
+
+		Some(format_elements![
+			formatter.format_node(self.expr()?)?,
+			space_token(),
+			formatter.format_token(&find_ident(self.syntax(), "as")?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsConstAssertion {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `<const>expr`
+		if let Some(l_angle) = self.l_angle_token() {
+			return Some(format_elements![
+				formatter.format_token(&l_angle)?,
+				formatter.format_token(&self.const_token()?)?,
+				formatter.format_token(&self.r_angle_token()?)?,
+				formatter.format_node(self.expr()?)?
+			]);
+		}
+
+		Some(format_elements![
+			formatter.format_node(self.expr()?)?,
+			space_token(),
+			formatter.format_token(&find_ident(self.syntax(), "as")?)?,
+			space_token(),
+			formatter.format_token(&self.const_token()?)?
+		])
+	}
+}
@@ -0,0 +1,45 @@
+use crate::ts::typescript::format_modifiers;
+use crate::{
+	block_indent, concat_elements, format_elements, hard_line_break, join_elements, space_token,
+	token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{Name, TsInterfaceDecl};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for TsInterfaceDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![
+			format_modifiers(self.syntax(), formatter)?,
+			// the `interface` keyword is an identifier, the name is a node
+			formatter.format_token(&self.ident_token()?)?,
+			space_token(),
+			formatter.format_node(self.syntax().children().find_map(Name::cast)?)?,
+		];
+
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		if let Some(extends_token) = self.extends_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&extends_token)?);
+			tokens.push(space_token());
+			tokens.push(join_elements(
+				space_token(),
+				formatter.format_separated(self.extends())?,
+			));
+		}
+
+		let mut members = vec![];
+		for member in self.members() {
+			members.push(format_elements![formatter.format_node(member)?, token(";")]);
+		}
+
+		tokens.push(space_token());
+		tokens.push(formatter.format_token(&self.l_curly_token()?)?);
+		tokens.push(block_indent(join_elements(hard_line_break(), members)));
+		tokens.push(formatter.format_token(&self.r_curly_token()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
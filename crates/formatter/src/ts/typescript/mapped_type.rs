@@ -0,0 +1,87 @@
+use crate::ts::format_object_members;
+use crate::ts::typescript::find_token;
+use crate::{
+	concat_elements, format_elements, group_elements, if_group_breaks, space_token, token,
+	FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsMappedType, TsMappedTypeParam, TsMappedTypeReadonly};
+use rslint_parser::{AstNode, SyntaxKind};
+
+impl ToFormatElement for TsMappedType {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(readonly_modifier) = self.readonly_modifier() {
+			tokens.push(formatter.format_node(readonly_modifier)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_node(self.param()?)?);
+
+		// `+?` or `-?`
+		if let Some(plus_token) = self.plus_token() {
+			tokens.push(formatter.format_token(&plus_token)?);
+		} else if let Some(minus_token) = self.minus_token() {
+			tokens.push(formatter.format_token(&minus_token)?);
+		}
+
+		if let Some(question_mark) = self.question_mark_token() {
+			tokens.push(formatter.format_token(&question_mark)?);
+		}
+
+		tokens.push(formatter.format_token(&self.colon_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.ty()?)?);
+
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			format_object_members(
+				vec![concat_elements(tokens)],
+				if_group_breaks(token(";")),
+				formatter
+			),
+			formatter.format_token(&self.r_curly_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for TsMappedTypeReadonly {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(plus_token) = self.plus_token() {
+			tokens.push(formatter.format_token(&plus_token)?);
+		} else if let Some(minus_token) = self.minus_token() {
+			tokens.push(formatter.format_token(&minus_token)?);
+		}
+
+		tokens.push(formatter.format_token(&self.readonly_token()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsMappedTypeParam {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_token(&self.ident_token()?)?,
+			space_token(),
+			formatter.format_token(&find_token(self.syntax(), SyntaxKind::IN_KW)?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+		];
+
+		// key remapping: `[K in keyof T as Name<K>]`
+		if let Some(as_token) = self.as_token() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&as_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(self.alias()?)?);
+		}
+
+		tokens.push(formatter.format_token(&self.r_brack_token()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
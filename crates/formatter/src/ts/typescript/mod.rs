@@ -0,0 +1,108 @@
+//! Formatting of the TypeScript specific nodes and helpers to format the TypeScript syntax
+//! that may be part of JavaScript nodes, e.g. type annotations, modifiers or decorators.
+use crate::{
+	concat_elements, empty_element, format_elements, hard_line_break, space_token, FormatElement,
+	Formatter,
+};
+use rslint_parser::ast::{TsDecorator, TsType};
+use rslint_parser::{AstNode, NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken};
+
+mod constructor_param;
+mod decorator;
+mod enum_decl;
+mod expressions;
+mod interface_decl;
+mod mapped_type;
+mod module_items;
+mod namespace_decl;
+mod object_type;
+mod type_alias_decl;
+mod type_params;
+mod types;
+
+/// Returns the first token of `node` with the given kind, not considering the tokens of its
+/// descendants. Useful for tokens for which the generated AST has no accessor.
+pub(crate) fn find_token(node: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|child| child.into_token())
+		.find(|child| child.kind() == kind)
+}
+
+/// Returns the first identifier token of `node` with the given text, e.g. a contextual keyword like `as`
+pub(crate) fn find_ident(node: &SyntaxNode, text: &str) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|child| child.into_token())
+		.find(|child| child.kind() == SyntaxKind::IDENT && child.text() == text)
+}
+
+/// Formats the type annotation of a binding, a property or the return type of a function: `: Type`.
+///
+/// Returns an empty element if there's no annotation and [None] if the annotation is incomplete.
+pub(crate) fn format_type_annotation(
+	colon_token: Option<SyntaxToken>,
+	ty: Option<TsType>,
+	formatter: &Formatter,
+) -> Option<FormatElement> {
+	match (colon_token, ty) {
+		(None, None) => Some(empty_element()),
+		(Some(colon_token), Some(ty)) => Some(format_elements![
+			formatter.format_token(&colon_token)?,
+			space_token(),
+			formatter.format_node(ty)?
+		]),
+		_ => None,
+	}
+}
+
+/// Formats the modifiers of a declaration or a class member, e.g. `private static readonly`,
+/// in the order they appear in the source. Every modifier is followed by a space.
+pub(crate) fn format_modifiers(node: &SyntaxNode, formatter: &Formatter) -> Option<FormatElement> {
+	let mut elements = vec![];
+
+	for child in node.children_with_tokens() {
+		if let Some(child_token) = child.into_token() {
+			if matches!(
+				child_token.kind(),
+				SyntaxKind::DECLARE_KW
+					| SyntaxKind::ABSTRACT_KW
+					| SyntaxKind::PUBLIC_KW
+					| SyntaxKind::PROTECTED_KW
+					| SyntaxKind::PRIVATE_KW
+					| SyntaxKind::STATIC_KW
+					| SyntaxKind::READONLY_KW
+			) {
+				elements.push(formatter.format_token(&child_token)?);
+				elements.push(space_token());
+			}
+		}
+	}
+
+	Some(concat_elements(elements))
+}
+
+/// Formats the decorators of a declaration, a class member or a parameter. A decorator stays on the
+/// same line as the decorated code unless it has been on its own line in the source.
+pub(crate) fn format_decorators(
+	decorators: impl IntoIterator<Item = TsDecorator>,
+	formatter: &Formatter,
+) -> Option<FormatElement> {
+	let mut elements = vec![];
+
+	for decorator in decorators {
+		let has_line_break_after = match decorator.syntax().next_sibling_or_token() {
+			Some(NodeOrToken::Token(next)) => {
+				next.kind() == SyntaxKind::WHITESPACE && next.text().contains('\n')
+			}
+			_ => false,
+		};
+
+		elements.push(formatter.format_node(decorator)?);
+		elements.push(if has_line_break_after {
+			hard_line_break()
+		} else {
+			space_token()
+		});
+	}
+
+	Some(concat_elements(elements))
+}
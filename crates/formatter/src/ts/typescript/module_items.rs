@@ -0,0 +1,79 @@
+use crate::ts::format_semicolon;
+use crate::{
+	concat_elements, format_elements, space_token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	Name, TsExportAssignment, TsExternalModuleRef, TsImportEqualsDecl, TsModuleRef,
+	TsNamespaceExportDecl,
+};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for TsImportEqualsDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(export_token) = self.export_token() {
+			tokens.push(formatter.format_token(&export_token)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_token(&self.import_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.syntax().children().find_map(Name::cast)?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_token(&self.eq_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.module()?)?);
+		tokens.push(format_semicolon(formatter));
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsModuleRef {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			TsModuleRef::TsExternalModuleRef(module_ref) => module_ref.to_format_element(formatter),
+			TsModuleRef::TsEntityName(name) => name.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsExternalModuleRef {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.require_token()?)?,
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_token(&self.string_token()?)?,
+			formatter.format_token(&self.r_paren_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsExportAssignment {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.expr()?)?,
+			format_semicolon(formatter)
+		])
+	}
+}
+
+impl ToFormatElement for TsNamespaceExportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			formatter.format_token(&self.as_token()?)?,
+			space_token(),
+			formatter.format_token(&self.namespace_token()?)?,
+			space_token(),
+			formatter.format_node(self.syntax().children().find_map(Name::cast)?)?,
+			format_semicolon(formatter)
+		])
+	}
+}
@@ -0,0 +1,75 @@
+use crate::ts::statements::format_statements;
+use crate::ts::typescript::{find_ident, find_token, format_modifiers};
+use crate::{
+	block_indent, concat_elements, format_elements, space_token, token, FormatElement, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{Name, TsModuleBlock, TsModuleDecl, TsNamespaceBody, TsNamespaceDecl};
+use rslint_parser::{AstNode, SyntaxKind};
+
+impl ToFormatElement for TsNamespaceDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![format_modifiers(self.syntax(), formatter)?];
+
+		// The `namespace` keyword is an identifier. It's absent for the inner declarations
+		// of `namespace a.b.c {}` that start with the `.`
+		if let Some(namespace_token) = self.ident_token() {
+			tokens.push(formatter.format_token(&namespace_token)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(dot_token) = self.dot_token() {
+			tokens.push(formatter.format_token(&dot_token)?);
+		}
+
+		tokens.push(formatter.format_node(self.syntax().children().find_map(Name::cast)?)?);
+		tokens.push(format_namespace_body(self.body()?, formatter)?);
+
+		Some(concat_elements(tokens))
+	}
+}
+
+fn format_namespace_body(body: TsNamespaceBody, formatter: &Formatter) -> Option<FormatElement> {
+	match body {
+		TsNamespaceBody::TsModuleBlock(block) => Some(format_elements![
+			space_token(),
+			formatter.format_node(block)?
+		]),
+		TsNamespaceBody::TsNamespaceDecl(namespace) => formatter.format_node(namespace),
+	}
+}
+
+impl ToFormatElement for TsModuleDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![format_modifiers(self.syntax(), formatter)?];
+
+		// `module "name"` or `global`
+		match self.module_token() {
+			Some(module_token) => {
+				tokens.push(formatter.format_token(&module_token)?);
+				tokens.push(space_token());
+				tokens
+					.push(formatter.format_token(&find_token(self.syntax(), SyntaxKind::STRING)?)?);
+			}
+			None => tokens.push(formatter.format_token(&find_ident(self.syntax(), "global")?)?),
+		}
+
+		// `declare module "name";` has no body
+		match self.body() {
+			Some(body) => tokens.push(format_namespace_body(body, formatter)?),
+			None => tokens.push(token(";")),
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsModuleBlock {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			block_indent(format_statements(self.items(), formatter)),
+			formatter.format_token(&self.r_curly_token()?)?
+		])
+	}
+}
@@ -0,0 +1,164 @@
+use crate::ts::format_object_members;
+use crate::ts::typescript::{find_token, format_modifiers, format_type_annotation};
+use crate::{
+	concat_elements, empty_element, format_elements, group_elements, if_group_breaks, space_token,
+	token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	AstChildren, Expr, Literal, Name, TsCallSignatureDecl, TsConstructSignatureDecl,
+	TsIndexSignature, TsMethodSignature, TsObjectType, TsPropertySignature, TsTypeElement,
+};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode};
+
+impl ToFormatElement for TsObjectType {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let members = format_type_members(self.members(), formatter)?;
+
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			format_object_members(members, if_group_breaks(token(";")), formatter),
+			formatter.format_token(&self.r_curly_token()?)?
+		]))
+	}
+}
+
+/// Formats the members of an object type or an interface and separates them with a `;`,
+/// regardless of whether they've been separated by a `,`, a `;` or a line break in the source.
+pub(crate) fn format_type_members(
+	members: AstChildren<TsTypeElement>,
+	formatter: &Formatter,
+) -> Option<Vec<FormatElement>> {
+	let mut result = vec![];
+	let mut members = members.peekable();
+
+	while let Some(member) = members.next() {
+		let member = formatter.format_node(member)?;
+
+		if members.peek().is_some() {
+			result.push(format_elements![member, token(";")]);
+		} else {
+			result.push(member);
+		}
+	}
+
+	Some(result)
+}
+
+impl ToFormatElement for TsTypeElement {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			TsTypeElement::TsCallSignatureDecl(signature) => signature.to_format_element(formatter),
+			TsTypeElement::TsConstructSignatureDecl(signature) => {
+				signature.to_format_element(formatter)
+			}
+			TsTypeElement::TsPropertySignature(signature) => signature.to_format_element(formatter),
+			TsTypeElement::TsMethodSignature(signature) => signature.to_format_element(formatter),
+			TsTypeElement::TsIndexSignature(signature) => signature.to_format_element(formatter),
+		}
+	}
+}
+
+/// Formats the key of a property or method signature, which the parser doesn't wrap in a node
+/// if it is computed: `[Symbol.iterator]`
+fn format_signature_key(node: &SyntaxNode, formatter: &Formatter) -> Option<FormatElement> {
+	if let Some(l_brack) = find_token(node, SyntaxKind::L_BRACK) {
+		return Some(format_elements![
+			formatter.format_token(&l_brack)?,
+			formatter.format_node(node.children().find_map(Expr::cast)?)?,
+			formatter.format_token(&find_token(node, SyntaxKind::R_BRACK)?)?
+		]);
+	}
+
+	let key = node.children().next()?;
+
+	match Name::cast(key.clone()) {
+		Some(name) => formatter.format_node(name),
+		None => formatter.format_node(Literal::cast(key)?),
+	}
+}
+
+impl ToFormatElement for TsPropertySignature {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let question_mark = match self.question_mark_token() {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![
+			format_modifiers(self.syntax(), formatter)?,
+			format_signature_key(self.syntax(), formatter)?,
+			question_mark,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for TsMethodSignature {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![
+			format_modifiers(self.syntax(), formatter)?,
+			format_signature_key(self.syntax(), formatter)?,
+		];
+
+		if let Some(question_mark) = self.question_mark_token() {
+			tokens.push(formatter.format_token(&question_mark)?);
+		}
+
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		tokens.push(formatter.format_node(self.parameters()?)?);
+		tokens.push(format_type_annotation(
+			self.colon_token(),
+			self.return_type(),
+			formatter,
+		)?);
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsCallSignatureDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let type_params = match self.type_params() {
+			Some(type_params) => formatter.format_node(type_params)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![
+			type_params,
+			formatter.format_node(self.parameters()?)?,
+			format_type_annotation(self.colon_token(), self.return_type(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for TsConstructSignatureDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let type_params = match self.type_params() {
+			Some(type_params) => formatter.format_node(type_params)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			type_params,
+			formatter.format_node(self.parameters()?)?,
+			format_type_annotation(self.colon_token(), self.return_type(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for TsIndexSignature {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			format_modifiers(self.syntax(), formatter)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(self.pat()?)?,
+			formatter.format_token(&self.r_brack_token()?)?,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?
+		])
+	}
+}
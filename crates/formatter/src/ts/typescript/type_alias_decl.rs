@@ -0,0 +1,29 @@
+use crate::ts::format_semicolon;
+use crate::ts::typescript::format_modifiers;
+use crate::{concat_elements, space_token, FormatElement, Formatter, ToFormatElement};
+use rslint_parser::ast::{Name, TsTypeAliasDecl};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for TsTypeAliasDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![
+			format_modifiers(self.syntax(), formatter)?,
+			// the `type` keyword is an identifier, the name is a node
+			formatter.format_token(&self.ident_token()?)?,
+			space_token(),
+			formatter.format_node(self.syntax().children().find_map(Name::cast)?)?,
+		];
+
+		if let Some(type_params) = self.type_params() {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		tokens.push(space_token());
+		tokens.push(formatter.format_token(&self.eq_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.ty()?)?);
+		tokens.push(format_semicolon(formatter));
+
+		Some(concat_elements(tokens))
+	}
+}
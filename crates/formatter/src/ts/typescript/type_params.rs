@@ -0,0 +1,81 @@
+use crate::{
+	concat_elements, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	TsConstraint, TsDefault, TsExprWithTypeArgs, TsTypeArgs, TsTypeParam, TsTypeParams,
+};
+
+impl ToFormatElement for TsTypeParams {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let params = formatter.format_separated(self.params())?;
+
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_angle_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), params)),
+			formatter.format_token(&self.r_angle_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for TsTypeParam {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![formatter.format_token(&self.ident_token()?)?];
+
+		if let Some(constraint) = self.constraint() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(constraint)?);
+		}
+
+		if let Some(default) = self.default() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(default)?);
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsConstraint {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.extends_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsDefault {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeArgs {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let args = formatter.format_separated(self.args())?;
+
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_angle_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), args)),
+			formatter.format_token(&self.r_angle_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for TsExprWithTypeArgs {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![formatter.format_node(self.item()?)?];
+
+		if let Some(type_args) = self.type_params() {
+			tokens.push(formatter.format_node(type_args)?);
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
@@ -0,0 +1,470 @@
+use crate::ts::format_trailing_comma;
+use crate::ts::typescript::{find_ident, find_token};
+use crate::{
+	concat_elements, empty_element, format_elements, group_elements, indent, join_elements,
+	soft_indent, soft_line_break_or_space, space_token, token, FormatElement, Formatter,
+	ToFormatElement, TrailingComma,
+};
+use rslint_parser::ast::{
+	Name, TsArray, TsConditionalType, TsConstructorType, TsEntityName, TsExtends, TsFnType,
+	TsImport, TsIndexedArray, TsInfer, TsIntersection, TsLiteral, TsParen, TsPredicate,
+	TsQualifiedPath, TsTemplate, TsTemplateElement, TsThisOrName, TsTuple, TsTupleElement, TsType,
+	TsTypeName, TsTypeOperator, TsTypeParams, TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnion,
+};
+use rslint_parser::{AstNode, NodeOrToken, SyntaxKind, SyntaxNode};
+
+impl ToFormatElement for TsType {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			TsType::TsAny(_)
+			| TsType::TsUnknown(_)
+			| TsType::TsNumber(_)
+			| TsType::TsObject(_)
+			| TsType::TsBoolean(_)
+			| TsType::TsBigint(_)
+			| TsType::TsString(_)
+			| TsType::TsSymbol(_)
+			| TsType::TsVoid(_)
+			| TsType::TsUndefined(_)
+			| TsType::TsNull(_)
+			| TsType::TsNever(_)
+			| TsType::TsThis(_) => format_keyword_type(self.syntax(), formatter),
+			TsType::TsLiteral(literal) => literal.to_format_element(formatter),
+			TsType::TsPredicate(predicate) => predicate.to_format_element(formatter),
+			TsType::TsTuple(tuple) => tuple.to_format_element(formatter),
+			TsType::TsParen(paren) => paren.to_format_element(formatter),
+			TsType::TsTypeRef(type_ref) => type_ref.to_format_element(formatter),
+			TsType::TsTemplate(template) => template.to_format_element(formatter),
+			TsType::TsMappedType(mapped_type) => mapped_type.to_format_element(formatter),
+			TsType::TsImport(import) => import.to_format_element(formatter),
+			TsType::TsArray(array) => array.to_format_element(formatter),
+			TsType::TsIndexedArray(indexed_array) => indexed_array.to_format_element(formatter),
+			TsType::TsTypeOperator(type_operator) => type_operator.to_format_element(formatter),
+			TsType::TsTypeQuery(type_query) => type_query.to_format_element(formatter),
+			TsType::TsIntersection(intersection) => intersection.to_format_element(formatter),
+			TsType::TsUnion(union) => union.to_format_element(formatter),
+			TsType::TsFnType(fn_type) => fn_type.to_format_element(formatter),
+			TsType::TsConstructorType(constructor_type) => {
+				constructor_type.to_format_element(formatter)
+			}
+			TsType::TsConditionalType(conditional_type) => {
+				conditional_type.to_format_element(formatter)
+			}
+			TsType::TsObjectType(object_type) => object_type.to_format_element(formatter),
+			TsType::TsInfer(infer) => infer.to_format_element(formatter),
+		}
+	}
+}
+
+/// Formats a type that only consists of a keyword, e.g. `string` or `this`
+fn format_keyword_type(node: &SyntaxNode, formatter: &Formatter) -> Option<FormatElement> {
+	let keyword = node
+		.children_with_tokens()
+		.filter_map(|child| child.into_token())
+		.find(|child| !child.kind().is_trivia())?;
+
+	formatter.format_token(&keyword)
+}
+
+/// Formats the types of a union or an intersection, separated by the `|` or `&` tokens.
+/// A leading separator, e.g. `type A = | B | C`, is removed.
+fn format_type_list(
+	node: &SyntaxNode,
+	separator: SyntaxKind,
+	formatter: &Formatter,
+) -> Option<FormatElement> {
+	let mut first = None;
+	let mut rest = vec![];
+	let mut separator_token = None;
+
+	for child in node.children_with_tokens() {
+		match child {
+			NodeOrToken::Node(node) => {
+				if let Some(ty) = TsType::cast(node) {
+					let ty = formatter.format_node(ty)?;
+
+					if first.is_none() {
+						first = Some(ty);
+					} else {
+						rest.push(format_elements![
+							soft_line_break_or_space(),
+							formatter.format_token(&separator_token.take()?)?,
+							space_token(),
+							ty
+						]);
+					}
+				}
+			}
+			NodeOrToken::Token(child_token) => {
+				if child_token.kind() == separator && first.is_some() {
+					separator_token = Some(child_token);
+				}
+			}
+		}
+	}
+
+	Some(group_elements(format_elements![
+		first?,
+		indent(concat_elements(rest))
+	]))
+}
+
+impl ToFormatElement for TsLiteral {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// negative numbers, e.g. `-1`
+		let minus = match find_token(self.syntax(), SyntaxKind::MINUS) {
+			Some(minus) => formatter.format_token(&minus)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![minus, formatter.format_node(self.lit()?)?])
+	}
+}
+
+impl ToFormatElement for TsPredicate {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(asserts_token) = find_ident(self.syntax(), "asserts") {
+			tokens.push(formatter.format_token(&asserts_token)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_node(self.lhs()?)?);
+
+		// `asserts value` has no type
+		if let Some(is_token) = find_ident(self.syntax(), "is") {
+			// the name may be `this`, which is a type too
+			let ty = self.syntax().children().filter_map(TsType::cast).last()?;
+
+			tokens.push(space_token());
+			tokens.push(formatter.format_token(&is_token)?);
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(ty)?);
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsThisOrName {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			TsThisOrName::TsThis(this) => format_keyword_type(this.syntax(), formatter),
+			TsThisOrName::TsTypeName(name) => name.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsTuple {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let elements = formatter
+			.format_separated(self.syntax().children().filter_map(TsTupleElement::cast))?;
+
+		Some(group_elements(format_elements![
+			formatter.format_token(&self.l_brack_token()?)?,
+			soft_indent(format_elements![
+				join_elements(soft_line_break_or_space(), elements),
+				format_trailing_comma(TrailingComma::Es5, formatter)
+			]),
+			formatter.format_token(&self.r_brack_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for TsTupleElement {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(dotdotdot_token) = self.dotdotdot_token() {
+			tokens.push(formatter.format_token(&dotdotdot_token)?);
+		}
+
+		let question_mark = match self.question_mark_token() {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+
+		// The parser doesn't wrap the label of a named element in a pattern: `name?: Type`
+		match self.syntax().children().find_map(Name::cast) {
+			Some(name) => {
+				tokens.push(formatter.format_node(name)?);
+				tokens.push(question_mark);
+				tokens.push(formatter.format_token(&self.colon_token()?)?);
+				tokens.push(space_token());
+				tokens.push(formatter.format_node(self.ty()?)?);
+			}
+			None => {
+				tokens.push(formatter.format_node(self.ty()?)?);
+				tokens.push(question_mark);
+			}
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsParen {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.r_paren_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeRef {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let type_args = match self.type_args() {
+			Some(type_args) => formatter.format_node(type_args)?,
+			None => empty_element(),
+		};
+
+		Some(format_elements![
+			formatter.format_node(self.name()?)?,
+			type_args
+		])
+	}
+}
+
+impl ToFormatElement for TsEntityName {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			TsEntityName::TsTypeName(name) => name.to_format_element(formatter),
+			TsEntityName::TsQualifiedPath(path) => path.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsTypeName {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		formatter.format_token(&self.ident_token()?)
+	}
+}
+
+impl ToFormatElement for TsQualifiedPath {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `lhs` and `rhs` both return the first name of `a.b`
+		let mut names = self.syntax().children().filter_map(TsEntityName::cast);
+		let lhs = names.next()?;
+		let rhs = names.next()?;
+
+		Some(format_elements![
+			formatter.format_node(lhs)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_node(rhs)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTemplate {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut elements = vec![];
+
+		for child in self.syntax().children_with_tokens() {
+			match child {
+				NodeOrToken::Token(child_token) => match child_token.kind() {
+					SyntaxKind::BACKTICK => elements.push(formatter.format_token(&child_token)?),
+					// The chunks are printed verbatim because any change to them changes the type
+					SyntaxKind::TEMPLATE_CHUNK => elements.push(token(child_token.text())),
+					_ => {}
+				},
+				NodeOrToken::Node(node) => {
+					if let Some(element) = TsTemplateElement::cast(node) {
+						elements.push(formatter.format_node(element)?);
+					}
+				}
+			}
+		}
+
+		Some(concat_elements(elements))
+	}
+}
+
+impl ToFormatElement for TsTemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&find_token(self.syntax(), SyntaxKind::DOLLARCURLY)?)?,
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.r_curly_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsImport {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![
+			formatter.format_token(&self.import_token()?)?,
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_token(&self.arg()?)?,
+			formatter.format_token(&self.r_paren_token()?)?,
+		];
+
+		if let Some(dot_token) = self.dot_token() {
+			tokens.push(formatter.format_token(&dot_token)?);
+			tokens.push(formatter.format_node(self.qualifier()?)?);
+		}
+
+		if let Some(type_args) = self.type_args() {
+			tokens.push(formatter.format_node(type_args)?);
+		}
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsTypeQuery {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.typeof_token()?)?,
+			space_token(),
+			formatter.format_node(self.expr()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeQueryExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		match self {
+			TsTypeQueryExpr::TsEntityName(name) => name.to_format_element(formatter),
+			TsTypeQueryExpr::TsImport(import) => import.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsArray {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_token(&self.r_brack_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsIndexedArray {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut types = self.syntax().children().filter_map(TsType::cast);
+		let object = types.next()?;
+		let index = types.next()?;
+
+		Some(format_elements![
+			formatter.format_node(object)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(index)?,
+			formatter.format_token(&self.r_brack_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeOperator {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `keyof`, `unique` or `readonly`
+		let operator = self
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|child| child.into_token())
+			.find(|child| !child.kind().is_trivia())?;
+
+		Some(format_elements![
+			formatter.format_token(&operator)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsUnion {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		format_type_list(self.syntax(), SyntaxKind::PIPE, formatter)
+	}
+}
+
+impl ToFormatElement for TsIntersection {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		format_type_list(self.syntax(), SyntaxKind::AMP, formatter)
+	}
+}
+
+impl ToFormatElement for TsFnType {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![];
+
+		if let Some(type_params) = self.syntax().children().find_map(TsTypeParams::cast) {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		tokens.push(formatter.format_node(self.params()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_token(&self.fat_arrow_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.return_type()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsConstructorType {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		let mut tokens = vec![formatter.format_token(&self.new_token()?)?, space_token()];
+
+		if let Some(type_params) = self.syntax().children().find_map(TsTypeParams::cast) {
+			tokens.push(formatter.format_node(type_params)?);
+		}
+
+		tokens.push(formatter.format_node(self.params()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_token(&self.fat_arrow_token()?)?);
+		tokens.push(space_token());
+		tokens.push(formatter.format_node(self.return_type()?)?);
+
+		Some(concat_elements(tokens))
+	}
+}
+
+impl ToFormatElement for TsConditionalType {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		// `check extends ext ? true_type : false_type`, the extends type is part of the TsExtends node
+		let mut types = self.syntax().children().filter_map(TsType::cast);
+		let check_type = types.next()?;
+		let true_type = types.next()?;
+		let false_type = types.next()?;
+
+		Some(group_elements(format_elements![
+			formatter.format_node(check_type)?,
+			space_token(),
+			formatter.format_node(self.extends()?)?,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				formatter.format_token(&self.question_mark_token()?)?,
+				space_token(),
+				formatter.format_node(true_type)?,
+				soft_line_break_or_space(),
+				formatter.format_token(&self.colon_token()?)?,
+				space_token(),
+				formatter.format_node(false_type)?
+			])
+		]))
+	}
+}
+
+impl ToFormatElement for TsExtends {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.extends_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsInfer {
+	fn to_format_element(&self, formatter: &Formatter) -> Option<FormatElement> {
+		Some(format_elements![
+			formatter.format_token(&self.infer_token()?)?,
+			space_token(),
+			formatter.format_node(self.syntax().children().find_map(Name::cast)?)?
+		])
+	}
+}
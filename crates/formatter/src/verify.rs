@@ -0,0 +1,347 @@
+//! Verifies that formatting neither changes the meaning of the code nor results in code that
+//! changes when it's formatted again.
+//!
+//! The formatted code is re-parsed and compared with the source, ignoring the trivia. The formatter
+//! may add or remove some tokens without changing the meaning of the code, e.g. semicolons or
+//! trailing commas, or change the quotes of strings. These changes aren't reported as mismatches.
+//! Neither are changes to the whitespace of templates whose code is formatted by an embedded
+//! formatter, e.g. ``css`color: red;` ``.
+use crate::{format_text, parse, FormatOptions};
+use core::file_handlers::Language;
+use core::App;
+use rslint_errors::file::FileId;
+use rslint_errors::Diagnostic;
+use rslint_parser::ast::Template;
+use rslint_parser::{
+	AstNode, NodeOrToken, Parse, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken,
+	TextRange,
+};
+
+/// Verifies that `formatted`, the formatted `source` written in `language`, is equivalent to the
+/// source and that formatting it again with the same `options` and `app` doesn't change it.
+///
+/// Returns a [Diagnostic] in the file `file_id` for every failed check, or an empty list if the
+/// formatted code passes the verification.
+pub fn verify_format(
+	source: &str,
+	formatted: &str,
+	language: Language,
+	options: FormatOptions,
+	app: &App,
+	file_id: FileId,
+) -> Vec<Diagnostic> {
+	let (source_parse, formatted_parse) =
+		match (parse(source, language), parse(formatted, language)) {
+			(Some(source_parse), Some(formatted_parse)) => (source_parse, formatted_parse),
+			_ => return vec![],
+		};
+
+	verify_syntax(&source_parse, &formatted_parse, file_id)
+		.into_iter()
+		.chain(verify_equivalence(
+			&source_parse.syntax(),
+			&formatted_parse.syntax(),
+			app,
+			file_id,
+		))
+		.chain(verify_idempotency(
+			formatted, language, options, app, file_id,
+		))
+		.collect()
+}
+
+/// Verifies that the formatted code has no syntax errors, unless the source already has some.
+fn verify_syntax(
+	source: &Parse<SyntaxNode>,
+	formatted: &Parse<SyntaxNode>,
+	file_id: FileId,
+) -> Option<Diagnostic> {
+	if !source.errors().is_empty() || formatted.errors().is_empty() {
+		return None;
+	}
+
+	let diagnostic = formatted.errors().iter().fold(
+		Diagnostic::error(
+			file_id,
+			"FormatterError",
+			"the formatted code has syntax errors",
+		),
+		|diagnostic, error| diagnostic.footer_note(error.title.clone()),
+	);
+
+	Some(diagnostic)
+}
+
+/// Verifies that the tokens and the nodes of the `formatted` tree are the same as those of the
+/// `source` tree.
+fn verify_equivalence(
+	source: &SyntaxNode,
+	formatted: &SyntaxNode,
+	app: &App,
+	file_id: FileId,
+) -> Option<Diagnostic> {
+	if source.lexical_eq(formatted) {
+		return None;
+	}
+
+	let mut source_elements = significant_elements(source, app);
+	let mut formatted_elements = significant_elements(formatted, app);
+
+	loop {
+		match (source_elements.next(), formatted_elements.next()) {
+			(None, None) => return None,
+			(Some(source_element), Some(formatted_element))
+				if is_equivalent(&source_element, &formatted_element, app) => {}
+			(source_element, formatted_element) => {
+				let range = match &source_element {
+					Some(element) => element.text_range(),
+					None => TextRange::empty(source.text_range().end()),
+				};
+
+				return Some(
+					Diagnostic::error(
+						file_id,
+						"FormatterError",
+						"the formatted code isn't equivalent to the source",
+					)
+					.primary(
+						range,
+						format!("the source contains {} here", describe(&source_element)),
+					)
+					.footer_note(format!(
+						"the formatted code contains {} instead",
+						describe(&formatted_element)
+					)),
+				);
+			}
+		}
+	}
+}
+
+/// Verifies that formatting the `formatted` code again results in the same code.
+fn verify_idempotency(
+	formatted: &str,
+	language: Language,
+	options: FormatOptions,
+	app: &App,
+	file_id: FileId,
+) -> Option<Diagnostic> {
+	let reformatted = format_text(formatted, language, options, app).ok()?;
+
+	if reformatted.code() == formatted {
+		return None;
+	}
+
+	let (line, expected, actual) = formatted
+		.lines()
+		.chain(std::iter::repeat(""))
+		.zip(reformatted.code().lines().chain(std::iter::repeat("")))
+		.enumerate()
+		.find(|(_, (expected, actual))| expected != actual)
+		.map(|(index, (expected, actual))| (index + 1, expected, actual))
+		// only the line endings differ
+		.unwrap_or((1, "", ""));
+
+	Some(
+		Diagnostic::error(
+			file_id,
+			"FormatterError",
+			"formatting the formatted code changes it again",
+		)
+		.footer_note(format!(
+			"line {} of the formatted code `{}` is formatted as `{}`",
+			line, expected, actual
+		)),
+	)
+}
+
+/// Returns the nodes and tokens of `root` that must be present in the source as well as in the
+/// formatted code.
+fn significant_elements<'a>(
+	root: &SyntaxNode,
+	app: &'a App,
+) -> impl Iterator<Item = SyntaxElement> + 'a {
+	root.descendants_with_tokens()
+		.filter(move |element| match element {
+			NodeOrToken::Node(node) => node.kind() != SyntaxKind::EMPTY_STMT,
+			NodeOrToken::Token(token) => {
+				!token.kind().is_trivia()
+					&& !is_optional(token)
+					// the embedded formatter may add or remove whitespace between the expressions
+					&& !(is_embedded_chunk(token, app) && token.text().trim().is_empty())
+			}
+		})
+}
+
+/// Returns `true` if `token` is a chunk of a template whose code is formatted by an embedded
+/// formatter, which may change its whitespace.
+fn is_embedded_chunk(token: &SyntaxToken, app: &App) -> bool {
+	token.kind() == SyntaxKind::TEMPLATE_CHUNK
+		&& token
+			.parent()
+			.and_then(Template::cast)
+			.and_then(|template| template.tag())
+			.and_then(|tag| app.get_embedded_formatter(tag.syntax().text().to_string().trim()))
+			.is_some()
+}
+
+/// Returns `true` for the tokens that the formatter may add or remove: semicolons, except the
+/// ones of a `for` statement, trailing commas and the commas separating the members of a type.
+fn is_optional(token: &SyntaxToken) -> bool {
+	let parent = token.parent().map(|parent| parent.kind());
+
+	match token.kind() {
+		SyntaxKind::SEMICOLON => parent != Some(SyntaxKind::FOR_STMT),
+		// `{ a: string, b: number }` is formatted as `{ a: string; b: number }`
+		SyntaxKind::COMMA if is_type_member(parent) => true,
+		SyntaxKind::COMMA => {
+			let next = std::iter::successors(token.next_token(), |token| token.next_token())
+				.find(|token| !token.kind().is_trivia());
+
+			matches!(
+				next.map(|token| token.kind()),
+				Some(SyntaxKind::R_BRACK | SyntaxKind::R_CURLY | SyntaxKind::R_PAREN)
+			)
+		}
+		_ => false,
+	}
+}
+
+fn is_type_member(kind: Option<SyntaxKind>) -> bool {
+	matches!(
+		kind,
+		Some(
+			SyntaxKind::TS_PROPERTY_SIGNATURE
+				| SyntaxKind::TS_METHOD_SIGNATURE
+				| SyntaxKind::TS_INDEX_SIGNATURE
+				| SyntaxKind::TS_CALL_SIGNATURE_DECL
+				| SyntaxKind::TS_CONSTRUCT_SIGNATURE_DECL
+		)
+	)
+}
+
+fn is_equivalent(source: &SyntaxElement, formatted: &SyntaxElement, app: &App) -> bool {
+	match (source, formatted) {
+		(NodeOrToken::Node(source), NodeOrToken::Node(formatted)) => {
+			source.kind() == formatted.kind()
+		}
+		(NodeOrToken::Token(source), NodeOrToken::Token(formatted))
+			if is_embedded_chunk(source, app) =>
+		{
+			let without_whitespace =
+				|token: &SyntaxToken| token.text().split_whitespace().collect::<String>();
+			is_embedded_chunk(formatted, app)
+				&& without_whitespace(source) == without_whitespace(formatted)
+		}
+		(NodeOrToken::Token(source), NodeOrToken::Token(formatted)) => {
+			source.kind() == formatted.kind()
+				&& normalized_text(source) == normalized_text(formatted)
+		}
+		_ => false,
+	}
+}
+
+/// Returns the text of `token` with `\n` line endings and, for strings, without the quotes.
+fn normalized_text(token: &SyntaxToken) -> String {
+	let text = token.text().replace("\r\n", "\n").replace('\r', "\n");
+
+	if token.kind() == SyntaxKind::STRING {
+		string_value(&text)
+	} else {
+		text
+	}
+}
+
+/// Returns the content of the string literal `text` without the enclosing quotes and with
+/// unescaped quotes, so that `'it\'s'` and `"it's"` have the same value.
+fn string_value(text: &str) -> String {
+	let content = match text.get(1..text.len().saturating_sub(1)) {
+		Some(content) => content,
+		None => return text.to_string(),
+	};
+
+	let mut result = String::with_capacity(content.len());
+	let mut chars = content.chars();
+
+	while let Some(char) = chars.next() {
+		match char {
+			'\\' => match chars.next() {
+				Some(quote @ ('"' | '\'')) => result.push(quote),
+				Some(escaped) => {
+					result.push('\\');
+					result.push(escaped);
+				}
+				None => result.push('\\'),
+			},
+			char => result.push(char),
+		}
+	}
+
+	result
+}
+
+fn describe(element: &Option<SyntaxElement>) -> String {
+	match element {
+		Some(NodeOrToken::Node(node)) => format!("a `{:?}` node", node.kind()),
+		Some(NodeOrToken::Token(token)) => format!("`{}`", token.text()),
+		None => "the end of the file".to_string(),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::verify_format;
+	use crate::{format_text, FormatOptions};
+	use core::file_handlers::Language;
+	use core::App;
+
+	fn verify(source: &str, formatted: &str) -> Vec<String> {
+		let app = App::new();
+		verify_format(
+			source,
+			formatted,
+			Language::Js,
+			FormatOptions::default(),
+			&app,
+			0,
+		)
+		.into_iter()
+		.map(|diagnostic| diagnostic.title)
+		.collect()
+	}
+
+	#[test]
+	fn formatted_code_passes_the_verification() {
+		let source = "let a = 'b'\nfor (;;) { call(a,b,) }\n";
+		let formatted =
+			format_text(source, Language::Js, FormatOptions::default(), &App::new()).unwrap();
+
+		assert!(verify(source, formatted.code()).is_empty());
+	}
+
+	#[test]
+	fn reports_changed_tokens() {
+		assert_eq!(
+			verify("let a = 1;\n", "let b = 1;\n"),
+			vec!["the formatted code isn't equivalent to the source"]
+		);
+		assert_eq!(
+			verify("a;\nb;\n", "a, b;\n"),
+			vec!["the formatted code isn't equivalent to the source"]
+		);
+	}
+
+	#[test]
+	fn reports_syntax_errors() {
+		assert!(verify("let a = 1;\n", "let a = ;\n")
+			.contains(&"the formatted code has syntax errors".to_string()));
+	}
+
+	#[test]
+	fn reports_code_that_changes_when_formatted_again() {
+		assert_eq!(
+			verify("let a=1;\n", "let   a = 1;\n"),
+			vec!["formatting the formatted code changes it again"]
+		);
+	}
+}
@@ -1,5 +1,9 @@
 use core::create_app;
-use rome_formatter::{format_file, FormatOptions};
+use path::RomePath;
+use rome_formatter::{format_file, verify_format, FormatOptions};
+use rslint_errors::file::SimpleFile;
+use rslint_errors::termcolor::Buffer;
+use rslint_errors::Emitter;
 use std::fs;
 use std::path::Path;
 
@@ -14,7 +18,15 @@ use std::path::Path;
 ///
 /// * `json/null` -> input: `tests/specs/json/null.json`, expected output: `tests/specs/json/null.expected.json`
 /// * `null` -> input: `tests/specs/null.json`, expected output: `tests/specs/null.expected.json`
+///
+/// The formatted output is also verified with [verify_format]: it must be equivalent to the input
+/// and formatting it again must not change it.
 pub fn run(spec_input_file: &str, expected_file: &str) {
+	run_with_options(spec_input_file, expected_file, FormatOptions::default())
+}
+
+/// Same as [run] but formats the input with the given `options` instead of the default options.
+pub fn run_with_options(spec_input_file: &str, expected_file: &str, options: FormatOptions) {
 	let app = create_app();
 	let file_path = &spec_input_file;
 	let spec_input_file = Path::new(spec_input_file);
@@ -32,8 +44,33 @@ pub fn run(spec_input_file: &str, expected_file: &str) {
 		expected_file.display(),
 	);
 
-	let result = format_file(file_path, FormatOptions::default(), &app);
+	let result = format_file(file_path, options.clone(), &app).unwrap();
 	let expected_output = fs::read_to_string(expected_file).unwrap();
 
 	assert_eq!(&expected_output, result.code());
+
+	let input = fs::read_to_string(spec_input_file).unwrap();
+	let language = RomePath::new(file_path)
+		.deduce_handler(&app)
+		.get_handler()
+		.unwrap()
+		.language();
+
+	let diagnostics = verify_format(&input, result.code(), language, options, &app, 0);
+
+	if !diagnostics.is_empty() {
+		let file = SimpleFile::new(file_path.to_string(), input);
+		let mut emitter = Emitter::new(&file);
+		let mut buffer = Buffer::no_color();
+
+		for diagnostic in &diagnostics {
+			emitter.emit_with_writer(diagnostic, &mut buffer).unwrap();
+		}
+
+		panic!(
+			"The formatted output of '{}' failed the verification:\n{}",
+			spec_input_file.display(),
+			String::from_utf8_lossy(buffer.as_slice())
+		);
+	}
 }
@@ -6,8 +6,104 @@ mod formatter {
 		tests_macros::gen_tests! {"tests/specs/json/*.json", spec_test::run}
 	}
 
+	mod jsonc {
+		use crate::spec_test;
+		tests_macros::gen_tests! {"tests/specs/jsonc/*.jsonc", spec_test::run}
+	}
+
+	mod json5 {
+		use crate::spec_test;
+		tests_macros::gen_tests! {"tests/specs/json5/*.json5", spec_test::run}
+	}
+
 	mod js {
 		use crate::spec_test;
 		tests_macros::gen_tests! {"tests/specs/js/**/**.js", spec_test::run}
 	}
+
+	mod ts {
+		use crate::spec_test;
+		tests_macros::gen_tests! {"tests/specs/ts/**/**.ts", spec_test::run}
+	}
+
+	/// Specs that are formatted with a non-default option. The directory name is the option
+	/// and its value.
+	mod options {
+		use crate::spec_test;
+		use rome_formatter::{
+			FormatOptions, LineEndingStyle, QuoteStyle, Semicolons, TrailingComma,
+		};
+
+		fn run_quote_style_single(input: &str, expected: &str) {
+			let options = FormatOptions {
+				quote_style: QuoteStyle::Single,
+				..FormatOptions::default()
+			};
+			spec_test::run_with_options(input, expected, options)
+		}
+
+		fn run_semicolons_as_needed(input: &str, expected: &str) {
+			let options = FormatOptions {
+				semicolons: Semicolons::AsNeeded,
+				..FormatOptions::default()
+			};
+			spec_test::run_with_options(input, expected, options)
+		}
+
+		fn run_trailing_comma_none(input: &str, expected: &str) {
+			let options = FormatOptions {
+				trailing_comma: TrailingComma::None,
+				..FormatOptions::default()
+			};
+			spec_test::run_with_options(input, expected, options)
+		}
+
+		fn run_trailing_comma_all(input: &str, expected: &str) {
+			let options = FormatOptions {
+				trailing_comma: TrailingComma::All,
+				..FormatOptions::default()
+			};
+			spec_test::run_with_options(input, expected, options)
+		}
+
+		fn run_bracket_spacing(input: &str, expected: &str) {
+			let options = FormatOptions {
+				bracket_spacing: true,
+				..FormatOptions::default()
+			};
+			spec_test::run_with_options(input, expected, options)
+		}
+
+		fn run_line_ending_crlf(input: &str, expected: &str) {
+			let options = FormatOptions {
+				line_ending: LineEndingStyle::Crlf,
+				..FormatOptions::default()
+			};
+			spec_test::run_with_options(input, expected, options)
+		}
+
+		mod quote_style_single {
+			tests_macros::gen_tests! {"tests/specs/options/quote_style_single/*", super::run_quote_style_single}
+		}
+
+		mod semicolons_as_needed {
+			tests_macros::gen_tests! {"tests/specs/options/semicolons_as_needed/*", super::run_semicolons_as_needed}
+		}
+
+		mod trailing_comma_none {
+			tests_macros::gen_tests! {"tests/specs/options/trailing_comma_none/*", super::run_trailing_comma_none}
+		}
+
+		mod trailing_comma_all {
+			tests_macros::gen_tests! {"tests/specs/options/trailing_comma_all/*", super::run_trailing_comma_all}
+		}
+
+		mod bracket_spacing {
+			tests_macros::gen_tests! {"tests/specs/options/bracket_spacing/*", super::run_bracket_spacing}
+		}
+
+		mod line_ending_crlf {
+			tests_macros::gen_tests! {"tests/specs/options/line_ending_crlf/*", super::run_line_ending_crlf}
+		}
+	}
 }
@@ -0,0 +1,9 @@
+class A {
+	#private = 1;
+	static   #counter;
+	static   get   getter() { return this.value }
+	static set setter(value) {}
+	static async *  method() {}
+	[computed]() {}
+	"string"() {}
+}
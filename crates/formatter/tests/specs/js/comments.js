@@ -0,0 +1,39 @@
+#!/usr/bin/env node
+// Leading comment of the first statement
+let a = 10; // trailing comment
+
+/**
+     * A JSDoc comment
+     * that is re-indented
+     */
+function add(/* first */ a, b /* second */) {
+	// comment before the return statement
+	return;
+	// last comment in the block
+}
+
+call(a /* after a */, /* before b */ b);
+let array = [ 1, /* two */ 2, 3 ];
+let list = [
+	1, // after the comma
+	2
+];
+let object = { a: 1, /* b */ b: 2 };
+
+function empty() {
+	// nothing to see here
+}
+
+foo(a, b) // end of the call
+const arr = [ // first
+  1, 2, 3 // three
+];
+call(/* empty */);
+let x = { /* dangling */ };
+if (a) {
+  // nothing
+} else {}
+const none = [
+  // only
+];
+// the end
@@ -0,0 +1,11 @@
+let a = ;
+let b = 1;
+if (a) {
+	call(1,,
+	2;
+	)
+	let c = [1, 2];
+}
+foo(1);
+)
+let d = 4;
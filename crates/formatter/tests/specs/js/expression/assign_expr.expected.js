@@ -0,0 +1,6 @@
+a = b;
+a += b;
+a.b = c;
+[a, b] = [b, a];
+({a, b} = c);
+a ||= b;
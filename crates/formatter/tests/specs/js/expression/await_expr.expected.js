@@ -0,0 +1,4 @@
+async function f() {
+	await a;
+	let b = await c();
+}
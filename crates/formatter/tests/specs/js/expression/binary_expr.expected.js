@@ -0,0 +1,8 @@
+a + b;
+a - b * c;
+a === b;
+a instanceof B;
+"a" in b;
+a && b || c;
+a ?? b;
+a ** b;
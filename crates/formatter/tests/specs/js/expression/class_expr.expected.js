@@ -0,0 +1,4 @@
+let A = class {};
+let B = class Named extends A {
+	m() {}
+};
@@ -0,0 +1,2 @@
+let A = class {}
+let B = class   Named   extends   A { m() {} }
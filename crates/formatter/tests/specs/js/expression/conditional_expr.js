@@ -0,0 +1,2 @@
+a?b:c
+let d =   a   ?   b   :   c  ?  d  :  e
@@ -0,0 +1,28 @@
+const Button = css`
+	color: ${(props) => props.color};
+	${mixin}
+	&:hover {
+		background: url(a.png)
+	}
+`;
+const query = gql`
+	query User($id: ID!) {
+		user(id: $id) {
+			id,
+			name
+			...UserFields
+		}
+	}
+`;
+function render(items) {
+	return html`
+		<ul class="list">
+			${items.map((item) => html`
+				<li>${item}</li>
+			`)}
+			<li>last</li></ul>
+	`;
+}
+const unknown = sql`SELECT   *   FROM users`;
+const invalid = css`a { color: red;`;
+const empty = css``;
@@ -0,0 +1,4 @@
+let a = function (  ) {}
+let b = function   named( c ,d ) { return c }
+let e = async   function() {}
+let f = function  *  gen() {}
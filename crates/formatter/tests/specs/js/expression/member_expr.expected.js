@@ -0,0 +1,13 @@
+a.b;
+a.b.c;
+a[b];
+a["key"][0];
+a?.b;
+a?.[b];
+a?.(b);
+class A extends B {
+	m() {
+		super.m();
+		super["n"];
+	}
+}
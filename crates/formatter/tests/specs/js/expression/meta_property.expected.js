@@ -0,0 +1,4 @@
+function F() {
+	new.target;
+}
+import.meta;
@@ -0,0 +1,2 @@
+function F() { new  .  target }
+import  .  meta
@@ -0,0 +1,3 @@
+new Foo
+new   Foo(  a,b  )
+new a.b.C()
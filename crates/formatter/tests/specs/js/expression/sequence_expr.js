@@ -0,0 +1,2 @@
+a  ,  b  ,  c
+for (i = 0, j = 10;i<j;i++,j--) {}
@@ -0,0 +1,6 @@
+let a = `plain`
+let b = `before ${  value  } after ${`nested ${ deep }`}`
+tag`tagged ${   x }`
+let c = `multi
+  line   ${ y }
+`
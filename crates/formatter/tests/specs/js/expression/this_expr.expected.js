@@ -0,0 +1,2 @@
+this;
+let self = this;
@@ -0,0 +1,14 @@
+!a;
+- a;
++  a;
+- -a;
++ +a;
+- --a;
+~ a;
+typeof   a;
+void  0;
+delete a.b;
+a ++;
+b  --;
+++ c;
+-- d;
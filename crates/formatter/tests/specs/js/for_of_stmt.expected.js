@@ -0,0 +1,7 @@
+for (const a of b) {}
+for (let [a, b] of c) {
+	a;
+}
+async function f() {
+	for await (const a of b) {}
+}
@@ -0,0 +1,5 @@
+for (const a of b) {}
+for (let [a, b] of   c) { a }
+async function f() {
+	for await (const a of b) {}
+}
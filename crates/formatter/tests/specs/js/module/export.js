@@ -0,0 +1,7 @@
+export const a = 1
+export function b() {}
+export class C {}
+export {a, b as d}
+export { e } from "e"
+export * from "all"
+export default   a + b
@@ -0,0 +1,6 @@
+import   "side-effect"
+import a from 'a'
+import * as  ns from "ns"
+import {b,c as d} from "bc"
+import e, { f } from "ef"
+import g, * as h from "gh"
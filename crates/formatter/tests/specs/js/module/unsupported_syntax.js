@@ -0,0 +1,2 @@
+export  *  as  ns  from   "ns"
+let   a =   1
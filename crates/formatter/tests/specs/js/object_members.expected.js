@@ -0,0 +1,14 @@
+let a = {
+	[key]: 1,
+	"string": 2,
+	3: 4,
+	...spread,
+	shorthand,
+	method(a) {},
+	async method() {},
+	*generator() {},
+	get getter() {
+		return 1;
+	},
+	set setter(v) {},
+};
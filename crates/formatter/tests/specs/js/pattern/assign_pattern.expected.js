@@ -0,0 +1,2 @@
+function f(a = 1, [b = 2], {c = 3}) {}
+let [d = 4] = e;
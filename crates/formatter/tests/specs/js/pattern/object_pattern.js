@@ -0,0 +1,4 @@
+let {a,b} = c
+let {  a: b, c: { d }  } = e
+let { a = 1, b: c = 2 } = d
+function f({ a, b }) {}
@@ -0,0 +1,3 @@
+let [a, ...   rest] = b
+let { c, ...others } = d
+function f(a, ...   args) {}
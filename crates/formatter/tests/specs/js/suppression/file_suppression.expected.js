@@ -0,0 +1,3 @@
+// rome-ignore-file format: generated code
+const  a  =  1;
+call( a,b )
@@ -0,0 +1,18 @@
+const a = 1;
+// rome-ignore format: keep the matrix aligned
+const matrix = [
+  1, 0, 0,
+  0, 1, 0,
+  0, 0, 1
+];
+function f() {
+	/* rome-ignore format */
+	call( a,b );
+	call(a, b);
+}
+const object = {
+	// rome-ignore format
+	table: [1,   2,
+	        3,   4],
+	other: [1, 2],
+};
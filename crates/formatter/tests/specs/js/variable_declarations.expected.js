@@ -0,0 +1,3 @@
+let a = 1, b = 2, c;
+var d, e;
+const {f} = g, [h] = i;
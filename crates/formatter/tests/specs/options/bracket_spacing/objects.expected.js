@@ -0,0 +1,9 @@
+let a = { b: 1, c: 2 };
+let { d, e } = a;
+let empty = {};
+import { f, g } from "module";
+export { f, g };
+let long = {
+	first: "a very long string that does not fit",
+	second: "another very long string",
+};
@@ -0,0 +1,2 @@
+type A = {a: string; b: number};
+type B = {[K in keyof A]: A[K]};
@@ -0,0 +1,8 @@
+function f() {
+	return `multi
+line`;
+}
+/**
+ * comment
+ */
+let a = [1, 2];
@@ -0,0 +1,8 @@
+let a = 'rome';
+let b = 'rome';
+let c = "it's";
+let d = 'say "hi"';
+let e = 'it\'s "quoted"';
+let f = '"double"';
+import x from 'module';
+let g = {'key': 'value'};
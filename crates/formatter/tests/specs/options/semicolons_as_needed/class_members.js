@@ -0,0 +1,13 @@
+class A {
+	a = 1;
+	b;
+	[computed] = 2;
+	c = 3;
+	*generator() {}
+	#d = 4;
+	[e] = 5;
+	f() {}
+	in = 6;
+	g = 7
+	h() {}
+}
@@ -0,0 +1,26 @@
+let a = 1
+const b = 2
+a = b
+;(function () {})()
+;[1, 2].forEach(call)
+;`template`.length
+;+a
+;-b
+;/regex/.test(a)
+for (let i = 0; i < 10; i++) {}
+for (;;) {
+	break
+}
+do {
+	continue
+} while (a)
+function f() {
+	return a
+}
+function g() {
+	throw new Error()
+}
+debugger
+import x from "x"
+export {x}
+export default a
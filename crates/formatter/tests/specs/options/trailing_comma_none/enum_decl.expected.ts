@@ -0,0 +1,4 @@
+enum Direction {
+	Up,
+	Down
+}
@@ -0,0 +1 @@
+enum Direction { Up, Down }
@@ -0,0 +1,40 @@
+let array = [
+	"a very long string that does not fit",
+	"another very long string",
+	"and one more"
+];
+let object = {
+	first: "a very long string that does not fit",
+	second: "another very long string"
+};
+let {
+	firstProperty,
+	secondProperty,
+	thirdProperty,
+	fourthProperty,
+	fifthProperty,
+	sixth
+} = object;
+import {
+	firstImport,
+	secondImport,
+	thirdImport,
+	fourthImport,
+	fifthImport,
+	sixth
+} from "module";
+function f(
+	firstParameter,
+	secondParameter,
+	thirdParameter,
+	fourthParameter,
+	fifth
+) {}
+call(
+	firstArgument,
+	secondArgument,
+	thirdArgument,
+	fourthArgument,
+	fifthArgument,
+	sixth
+);
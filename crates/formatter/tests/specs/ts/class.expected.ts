@@ -0,0 +1,18 @@
+abstract class A<T> extends B<T> implements C, D<T> {
+	private readonly x?: number = 1;
+	protected static y: string;
+	public z!: boolean;
+	#w: number = 2;
+	[key: string]: any;
+	constructor(private a: number, protected b?: string, c = 2) {
+		super();
+	}
+	protected abstract m<U>(a: U): void;
+	public static get g(): number {
+		return 1;
+	}
+	private set s(value: number) {}
+	optional?(): void;
+	async method(): Promise<void> {}
+}
+declare class E {}
@@ -0,0 +1,9 @@
+@Component({selector: "app"})
+class A {
+	@Input() name: string;
+	@Output()
+	change = new EventEmitter();
+	constructor(@Inject(TOKEN) private readonly token: string) {}
+	@HostListener("click") onClick() {}
+}
+@dec export class B {}
@@ -0,0 +1,4 @@
+enum A { B, C = 2, "D" = 3 }
+const enum E { F = 1 << 0, G = 1 << 1, }
+declare enum H {}
+export enum I { J }
@@ -0,0 +1,17 @@
+const a = b!;
+const c = d as string;
+const e = <number>f;
+const g = [1, 2] as const;
+const h = obj!.prop!.value;
+let i!: number;
+declare const j: string;
+declare function k(a: string): void;
+function l(this: Window, ...rest: number[]): asserts rest is number[] {}
+function m(a: unknown): a is string {
+	return true;
+}
+function overload(a: string): void;
+function overload(a: number): void;
+function overload(a: any) {}
+const {n, o}: {n: string; o: number} = p;
+const [q, r]: [string, number] = s;
@@ -0,0 +1,12 @@
+function identity<T>(value: T): T {
+	return value;
+}
+const a = identity<string>("a");
+const b = new Map<string, number>();
+class Box<T extends object = {}> {
+	constructor(public value: T) {}
+}
+const arrow = <T>(value: T): T => value;
+function f<A, B extends keyof A>(a: A, b: B): A[B] {
+	return a[b];
+}
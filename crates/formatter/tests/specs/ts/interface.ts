@@ -0,0 +1,10 @@
+interface   A { a: string, b?: number; readonly c: boolean
+  [key: string]: any
+  method<T>(a: T): void
+  (call: number): string
+  new (x: number): A
+  get(): void;
+  ["computed"]: number
+}
+export interface B<T extends object = {}> extends A, C<T> {}
+declare interface D {  }
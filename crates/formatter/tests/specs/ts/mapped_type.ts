@@ -0,0 +1,4 @@
+type A<T> = { [K in keyof T]: T[K] }
+type B<T> = { readonly [K in keyof T]?: T[K] }
+type C<T> = { -readonly [K in keyof T]-?: T[K] }
+type D<T> = { +readonly [K in keyof T]+?: T[K] };
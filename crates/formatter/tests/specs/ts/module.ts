@@ -0,0 +1,12 @@
+import type { A } from "a";
+import type B from "b";
+import C = require("c");
+import D = E.F;
+export import G = H;
+export type { I };
+export type { J } from "j";
+export = K;
+export as namespace L;
+export default interface M {}
+export type N = string;
+export declare const o: number;
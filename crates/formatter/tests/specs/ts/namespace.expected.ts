@@ -0,0 +1,16 @@
+namespace A {
+	export const a = 1;
+}
+namespace B.C.D {
+	const b = 2;
+}
+declare namespace E {}
+declare module "module" {
+	export function f(): void;
+}
+declare module "short";
+declare global {
+	interface Window {
+		a: string;
+	}
+}
@@ -0,0 +1,19 @@
+type A = string
+type B<T> = T | null | undefined;
+type C = { a: string; b: number }
+type D = [string, number?, ...boolean[]]
+type E = (a: string, b?: number) => void
+type F = new () => A
+type G = keyof typeof obj
+type H = A extends string ? "yes" : "no"
+type I<T> = T extends Array<infer U> ? U : never
+type J = A["b"]
+type K = `prefix-${string}`
+type L = import("./module").Type
+type M = A & B & { c: number }
+type N = -1 | "a" | true
+type O = (string | number)[]
+type P = [first: string, second?: number]
+type Q = unique symbol
+type R = ns.Inner.Type<string>
+type VeryLongUnionType = "first-option" | "second-option" | "third-option" | "fourth-option"
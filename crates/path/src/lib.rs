@@ -2,13 +2,61 @@
 //! It is a small wrapper around [path::PathBuf] but it is also able to
 //! give additional information around the the file that holds:
 //! - the [FileHandlers] for the specific file
-//! - shortcuts to open/write to the file
-use core::{file_handlers::ExtensionHandler, App};
-use std::{fs::File, io::Write, ops::Deref, path::PathBuf};
+//! - shortcuts to open/write to the file, through the [FileSystem] of the [App]
+use core::{
+	file_handlers::ExtensionHandler,
+	file_system::{FileSystem, OsFileSystem},
+	App,
+};
+use rslint_errors::{file::FileId, Diagnostic};
+use std::{
+	fmt::{Display, Formatter},
+	io::{self, Read},
+	ops::Deref,
+	path::PathBuf,
+};
+
+pub mod traversal;
+
+/// The reasons why a [RomePath] can't be read or written
+#[derive(Debug)]
+pub enum FileError {
+	/// The file can't be opened or read
+	Read(io::Error),
+	/// The file can't be written
+	Write(io::Error),
+}
+
+impl FileError {
+	/// Returns a diagnostic for the error in the file `file_id`
+	pub fn to_diagnostic(&self, file_id: FileId) -> Diagnostic {
+		Diagnostic::error(file_id, "IoError", self.to_string())
+	}
+}
+
+impl Display for FileError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			FileError::Read(error) => write!(f, "the file can't be read: {}", error),
+			FileError::Write(error) => write!(f, "the file can't be written: {}", error),
+		}
+	}
+}
+
+impl std::error::Error for FileError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			FileError::Read(error) | FileError::Write(error) => Some(error),
+		}
+	}
+}
 
 pub struct RomePath<'handler> {
 	file: PathBuf,
 	handler: Option<&'handler dyn ExtensionHandler>,
+	/// The file system of the app passed to [RomePath::deduce_handler], the file system of the
+	/// OS until then
+	file_system: &'handler dyn FileSystem,
 }
 
 impl<'handler> Deref for RomePath<'handler> {
@@ -20,16 +68,18 @@ impl<'handler> Deref for RomePath<'handler> {
 }
 
 impl<'handler> RomePath<'handler> {
-	pub fn new(path_to_file: &str) -> Self {
+	pub fn new<P: Into<PathBuf>>(path_to_file: P) -> Self {
 		Self {
-			file: PathBuf::from(path_to_file),
+			file: path_to_file.into(),
 			handler: None,
+			file_system: &OsFileSystem {},
 		}
 	}
 
 	/// Deduce the file handler based on the extension of the file.
 	///
-	/// Any error will default to the base file handler for now.
+	/// A file whose extension is unknown or isn't valid UTF-8 gets the handler of unknown files.
+	/// From then on, the file is read and written with the file system of the `app`.
 	///
 	///
 	/// ```rust
@@ -53,10 +103,12 @@ impl<'handler> RomePath<'handler> {
 	/// )
 	/// ```
 	pub fn deduce_handler(mut self, app: &'handler App) -> Self {
-		if self.extension().is_none() {
-			return self;
-		}
-		let extension = self.extension().unwrap().to_str().unwrap();
+		self.file_system = app.file_system();
+
+		let extension = match self.extension() {
+			Some(extension) => extension.to_str().unwrap_or_default(),
+			None => return self,
+		};
 
 		if let Some(handler) = app.get_handler(extension) {
 			self.handler = Some(handler);
@@ -68,17 +120,24 @@ impl<'handler> RomePath<'handler> {
 		self
 	}
 
-	// TODO: handle error with diagnostic?
-	/// Opens a file and returns a [File] in write mode
-	pub fn open(&self) -> File {
-		File::open(&self.file).expect("cannot open the file to format")
+	/// Opens the file in read mode
+	pub fn open(&self) -> Result<Box<dyn Read>, FileError> {
+		self.file_system.open(&self.file).map_err(FileError::Read)
 	}
 
-	/// Accepts a file opened in read mode and saves into it
-	pub fn save(&mut self, content: &str) -> Result<(), std::io::Error> {
-		let mut file_to_write = File::create(&self.file).unwrap();
-		// TODO: handle error with diagnostic
-		file_to_write.write_all(content.as_bytes())
+	/// Reads the content of the file, which must be valid UTF-8
+	pub fn read_to_string(&self) -> Result<String, FileError> {
+		self.file_system
+			.read_to_string(&self.file)
+			.map_err(FileError::Read)
+	}
+
+	/// Replaces the content of the file with `content`. The file system of the OS never leaves
+	/// the file half written and keeps its permissions.
+	pub fn save(&mut self, content: &str) -> Result<(), FileError> {
+		self.file_system
+			.save(&self.file, content.as_bytes())
+			.map_err(FileError::Write)
 	}
 
 	/// Returns the current handler associated to the file.
@@ -92,10 +151,11 @@ impl<'handler> RomePath<'handler> {
 
 #[cfg(test)]
 mod test {
-	use crate::RomePath;
+	use crate::{FileError, RomePath};
 	use core::{
 		create_app,
-		file_handlers::{javascript::JsFileHandler, ExtensionHandler},
+		file_handlers::{javascript::JsFileHandler, ExtensionHandler, Language},
+		file_system::MemoryFileSystem,
 	};
 
 	#[test]
@@ -113,4 +173,43 @@ mod test {
 			expected.capabilities().lint
 		)
 	}
+
+	#[test]
+	fn reads_and_saves_files_with_the_file_system_of_the_app() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("src/a.js", "let a  = 1");
+		let mut app = create_app();
+		app.set_file_system(Box::new(file_system.clone()));
+
+		let mut file = RomePath::new("src/a.js").deduce_handler(&app);
+		assert_eq!(file.read_to_string().unwrap(), "let a  = 1");
+
+		file.save("let a = 1;\n").unwrap();
+		assert_eq!(file_system.get("src/a.js").unwrap(), b"let a = 1;\n");
+	}
+
+	#[test]
+	fn returns_errors_instead_of_panicking() {
+		let mut file = RomePath::new("does/not/exist.js");
+
+		assert!(matches!(file.open(), Err(FileError::Read(_))));
+		assert!(matches!(file.read_to_string(), Err(FileError::Read(_))));
+		assert!(matches!(file.save(""), Err(FileError::Write(_))));
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn deduces_the_handler_of_non_utf8_paths() {
+		use std::ffi::OsStr;
+		use std::os::unix::ffi::OsStrExt;
+
+		let app = create_app();
+		let name = OsStr::from_bytes(b"fil\xe9.js");
+		let file = RomePath::new(name).deduce_handler(&app);
+		assert_eq!(file.get_handler().unwrap().language(), Language::Js);
+
+		let extension = OsStr::from_bytes(b"file.j\xe9");
+		let file = RomePath::new(extension).deduce_handler(&app);
+		assert_eq!(file.get_handler().unwrap().language(), Language::Unknown);
+	}
 }
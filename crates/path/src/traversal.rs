@@ -0,0 +1,570 @@
+//! Expands the inputs of a command, e.g. `rome format src lib/*.js`, to the files they refer to.
+//!
+//! An input can be a file, a directory whose files are collected recursively, or a glob pattern
+//! like `src/**/*.ts`. Directories and patterns skip the `node_modules` directories, the files
+//! listed in the `.gitignore`, `.ignore` and `.romeignore` files of the directories and their
+//! ancestors up to the root of the git repository, or of the project outside of repositories, the
+//! ignore files themselves, and the files excluded by the [TraversalFilter]. A file passed as
+//! input is always collected.
+//!
+//! The files and directories are read through a [FileSystem], usually the file system of the
+//! [App](core::App).
+use core::file_system::{FileKind, FileSystem};
+use globset::GlobBuilder;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+/// Characters that turn an input that isn't an existing path into a glob pattern
+const GLOB_CHARACTERS: [char; 4] = ['*', '?', '[', '{'];
+
+/// The file listing the files that Rome ignores, in addition to the files listed in `.gitignore`.
+/// It uses the syntax of `.gitignore` files.
+pub const IGNORE_FILE: &str = ".romeignore";
+
+/// Directories that are never traversed
+const IGNORED_DIRECTORIES: [&str; 2] = [".git", "node_modules"];
+
+/// The ignore files read by the traversal, which are never collected themselves. The patterns of
+/// the later files take precedence, as do the patterns of the files of nested directories.
+const IGNORE_FILES: [&str; 3] = [".gitignore", ".ignore", IGNORE_FILE];
+
+#[derive(Debug)]
+pub enum TraversalError {
+	/// The input is neither an existing path nor a glob pattern
+	NotFound(String),
+	/// The input, or an ignore or include pattern, is an invalid glob pattern
+	InvalidPattern { pattern: String, reason: String },
+	/// A directory couldn't be read while collecting its files
+	Io(std::io::Error),
+}
+
+impl Display for TraversalError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TraversalError::NotFound(input) => write!(f, "The file \"{}\" doesn't exist.", input),
+			TraversalError::InvalidPattern { pattern, reason } => {
+				write!(f, "The glob pattern \"{}\" is invalid: {}", pattern, reason)
+			}
+			TraversalError::Io(error) => write!(f, "{}", error),
+		}
+	}
+}
+
+impl std::error::Error for TraversalError {}
+
+impl From<ignore::Error> for TraversalError {
+	fn from(error: ignore::Error) -> Self {
+		let kind = error
+			.io_error()
+			.map(std::io::Error::kind)
+			.unwrap_or(ErrorKind::Other);
+		TraversalError::Io(std::io::Error::new(kind, error.to_string()))
+	}
+}
+
+/// Excludes files from the directories and glob patterns, in addition to the ignore files
+#[derive(Debug, Clone)]
+pub struct TraversalFilter {
+	/// The root of the project, the patterns only apply to the files inside of it
+	root: PathBuf,
+	/// The directory that relative paths are resolved against
+	current_dir: PathBuf,
+	ignore: Gitignore,
+	/// If it isn't empty, the files that don't match it are excluded
+	include: Gitignore,
+}
+
+impl Default for TraversalFilter {
+	fn default() -> Self {
+		let current_dir = std::env::current_dir().unwrap_or_default();
+		Self {
+			root: current_dir.clone(),
+			current_dir,
+			ignore: Gitignore::empty(),
+			include: Gitignore::empty(),
+		}
+	}
+}
+
+impl TraversalFilter {
+	/// Creates a filter that excludes the files matching the `ignore` patterns and, unless
+	/// `include` is empty, the files that don't match any of the `include` patterns.
+	///
+	/// The patterns use the syntax of `.gitignore` files and are relative to the `root` of the
+	/// project: `/dist` only matches the `dist` directory of the root, `*.min.js` matches in
+	/// every directory and `src/` matches the `src` directories, including their files.
+	pub fn new<S: AsRef<str>>(
+		root: &Path,
+		ignore: &[S],
+		include: &[S],
+	) -> Result<Self, TraversalError> {
+		let current_dir = std::env::current_dir().map_err(TraversalError::Io)?;
+		let root = current_dir.join(root);
+
+		Ok(Self {
+			ignore: build_patterns(&root, ignore)?,
+			include: build_patterns(&root, include)?,
+			root,
+			current_dir,
+		})
+	}
+
+	/// Returns `true` if the file or directory at `path` is excluded. The directories that
+	/// don't match the include patterns aren't excluded because files inside of them can match.
+	fn is_excluded(&self, path: &Path, is_dir: bool) -> bool {
+		let path = self.absolute(path);
+		if !path.starts_with(&self.root) {
+			return false;
+		}
+
+		self.ignore.matched(&path, is_dir).is_ignore()
+			|| (!is_dir
+				&& !self.include.is_empty()
+				&& !self
+					.include
+					.matched_path_or_any_parents(&path, false)
+					.is_ignore())
+	}
+
+	/// Returns `path` resolved against the current directory, without `.` components, because
+	/// the patterns don't match `/root/./a.js` with the root `/root`
+	fn absolute(&self, path: &Path) -> PathBuf {
+		self.current_dir.join(path).components().collect()
+	}
+}
+
+/// Returns an error if `pattern` isn't a valid ignore or include pattern
+pub fn check_pattern(pattern: &str) -> Result<(), TraversalError> {
+	build_patterns(Path::new(""), &[pattern]).map(|_| ())
+}
+
+fn build_patterns<S: AsRef<str>>(root: &Path, patterns: &[S]) -> Result<Gitignore, TraversalError> {
+	let mut builder = GitignoreBuilder::new(root);
+
+	for pattern in patterns {
+		let pattern = pattern.as_ref();
+		builder
+			.add_line(None, pattern)
+			.map_err(|error| TraversalError::InvalidPattern {
+				pattern: pattern.to_string(),
+				reason: error.to_string(),
+			})?;
+	}
+
+	builder.build().map_err(TraversalError::from)
+}
+
+fn is_glob(input: &str) -> bool {
+	input.contains(&GLOB_CHARACTERS[..])
+}
+
+/// Returns the files referred to by the `inputs`: the files themselves, the files inside of the
+/// directories, recursively, and the files matching the glob patterns. The files of directories
+/// and patterns are skipped if they're ignored or excluded by the `filter`.
+///
+/// The files of a directory or a pattern are sorted by their path. A file referred to by multiple
+/// inputs is only returned once.
+pub fn collect_files<S: AsRef<str>>(
+	inputs: &[S],
+	filter: &TraversalFilter,
+	file_system: &dyn FileSystem,
+) -> Result<Vec<PathBuf>, TraversalError> {
+	let mut files = vec![];
+
+	for input in inputs {
+		let input = input.as_ref();
+		let path = Path::new(input);
+
+		match file_system.kind(path) {
+			Some(FileKind::Directory) => files.extend(walk(path, filter, file_system)?),
+			Some(_) => files.push(path.to_path_buf()),
+			None if is_glob(input) => collect_matches(input, filter, file_system, &mut files)?,
+			None => return Err(TraversalError::NotFound(input.to_string())),
+		}
+	}
+
+	let mut seen = HashSet::new();
+	files.retain(|file| seen.insert(file.clone()));
+
+	Ok(files)
+}
+
+/// Returns the files inside of `directory`, recursively, that aren't ignored or excluded by the
+/// `filter`. The directory itself is traversed even if it's ignored.
+fn walk(
+	directory: &Path,
+	filter: &TraversalFilter,
+	file_system: &dyn FileSystem,
+) -> Result<Vec<PathBuf>, TraversalError> {
+	let absolute = filter.absolute(directory);
+	// the ignore files of the ancestors, from the farthest to the parent
+	let mut ignore_files: Vec<_> = ignore_file_ancestors(&absolute, filter, file_system)
+		.into_iter()
+		.map(|ancestor| read_ignore_files(ancestor, ancestor, file_system))
+		.collect();
+	ignore_files.reverse();
+
+	let mut files = vec![];
+	walk_directory(
+		directory,
+		filter,
+		file_system,
+		&mut ignore_files,
+		&mut files,
+	)?;
+	Ok(files)
+}
+
+/// Returns the ancestors of the absolute `directory` whose ignore files apply to it, from the
+/// parent to the root of the git repository of `directory` or, if it isn't inside of a repository,
+/// to the root of the project.
+fn ignore_file_ancestors<'a>(
+	directory: &'a Path,
+	filter: &TraversalFilter,
+	file_system: &dyn FileSystem,
+) -> Vec<&'a Path> {
+	let mut ancestors = vec![];
+
+	for ancestor in directory.ancestors() {
+		if ancestor != directory {
+			ancestors.push(ancestor);
+		}
+		// `.git` is a file in worktrees and submodules
+		if file_system.kind(&ancestor.join(".git")).is_some() {
+			return ancestors;
+		}
+	}
+
+	ancestors.retain(|ancestor| ancestor.starts_with(&filter.root));
+	ancestors
+}
+
+/// Appends the files of `directory` to `files` and traverses its subdirectories, in the order of
+/// their names. `ignore_files` are the ignore files of the ancestors of `directory`.
+fn walk_directory(
+	directory: &Path,
+	filter: &TraversalFilter,
+	file_system: &dyn FileSystem,
+	ignore_files: &mut Vec<Gitignore>,
+	files: &mut Vec<PathBuf>,
+) -> Result<(), TraversalError> {
+	let mut entries = file_system
+		.read_dir(directory)
+		.map_err(TraversalError::Io)?;
+	entries.sort_by(|(a, _), (b, _)| a.file_name().cmp(&b.file_name()));
+
+	ignore_files.push(read_ignore_files(
+		directory,
+		&filter.absolute(directory),
+		file_system,
+	));
+
+	for (path, kind) in entries {
+		let is_dir = match kind {
+			FileKind::File => false,
+			FileKind::Directory => true,
+			FileKind::Other => continue,
+		};
+		if is_excluded(&path, is_dir, filter, ignore_files) {
+			continue;
+		}
+
+		if is_dir {
+			walk_directory(&path, filter, file_system, ignore_files, files)?;
+		} else {
+			files.push(path);
+		}
+	}
+
+	ignore_files.pop();
+	Ok(())
+}
+
+/// Reads the patterns of the ignore files of `directory`, whose path resolved against the
+/// current directory is `root`.
+///
+/// An ignore file that can't be read, or an invalid line of an ignore file, doesn't prevent the
+/// other patterns from applying.
+fn read_ignore_files(directory: &Path, root: &Path, file_system: &dyn FileSystem) -> Gitignore {
+	let mut builder = GitignoreBuilder::new(root);
+
+	for name in IGNORE_FILES {
+		let path = directory.join(name);
+		if file_system.kind(&path) != Some(FileKind::File) {
+			continue;
+		}
+		if let Ok(content) = file_system.read_to_string(&path) {
+			for line in content.lines() {
+				let _ = builder.add_line(Some(path.clone()), line);
+			}
+		}
+	}
+
+	builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+fn is_excluded(
+	path: &Path,
+	is_dir: bool,
+	filter: &TraversalFilter,
+	ignore_files: &[Gitignore],
+) -> bool {
+	let name = path.file_name().and_then(OsStr::to_str);
+	let is_ignored = if is_dir {
+		matches!(name, Some(name) if IGNORED_DIRECTORIES.contains(&name))
+	} else {
+		matches!(name, Some(name) if IGNORE_FILES.contains(&name))
+	};
+	if is_ignored {
+		return true;
+	}
+
+	// the ignore file of the closest directory with a matching pattern decides
+	let absolute = filter.absolute(path);
+	let matched = ignore_files
+		.iter()
+		.rev()
+		.map(|ignore_file| ignore_file.matched(&absolute, is_dir))
+		.find(|matched| !matched.is_none());
+
+	matched.is_some_and(|matched| matched.is_ignore()) || filter.is_excluded(path, is_dir)
+}
+
+/// Appends the files matching the glob `pattern` to `files`.
+///
+/// The pattern is split into the directory to search, the components without glob characters,
+/// and the pattern matched against the paths relative to that directory.
+fn collect_matches(
+	pattern: &str,
+	filter: &TraversalFilter,
+	file_system: &dyn FileSystem,
+	files: &mut Vec<PathBuf>,
+) -> Result<(), TraversalError> {
+	let mut base = PathBuf::new();
+	let mut rest = vec![];
+
+	for component in Path::new(pattern).components() {
+		let text = component.as_os_str().to_string_lossy();
+		if rest.is_empty() && !is_glob(&text) {
+			base.push(component);
+		} else if component != Component::CurDir {
+			rest.push(text.into_owned());
+		}
+	}
+
+	let search_current_dir = base.as_os_str().is_empty();
+	if search_current_dir {
+		base.push(".");
+	}
+
+	// `*` doesn't match a `/`, so `*.js` doesn't match `a/b.js`
+	let matcher = GlobBuilder::new(&rest.join("/"))
+		.literal_separator(true)
+		.build()
+		.map_err(|error| TraversalError::InvalidPattern {
+			pattern: pattern.to_string(),
+			reason: error.kind().to_string(),
+		})?
+		.compile_matcher();
+
+	for path in walk(&base, filter, file_system)? {
+		let relative = path.strip_prefix(&base).unwrap_or(&path);
+
+		if matcher.is_match(relative) {
+			files.push(if search_current_dir {
+				relative.to_path_buf()
+			} else {
+				path
+			});
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::{collect_files, TraversalError, TraversalFilter};
+	use core::file_system::{MemoryFileSystem, OsFileSystem};
+	use std::fs;
+	use std::path::{Path, PathBuf};
+
+	/// Creates the `files` in a new temporary directory and returns the directory
+	fn create_files(name: &str, files: &[&str]) -> PathBuf {
+		let root = std::env::temp_dir().join(format!("rome_traversal_{}", name));
+		let _ = fs::remove_dir_all(&root);
+
+		for file in files {
+			let path = root.join(file);
+			fs::create_dir_all(path.parent().unwrap()).unwrap();
+			fs::write(path, "").unwrap();
+		}
+
+		root
+	}
+
+	fn relative(root: &Path, files: Vec<PathBuf>) -> Vec<String> {
+		files
+			.iter()
+			.map(|file| {
+				file.strip_prefix(root)
+					.unwrap()
+					.to_string_lossy()
+					.replace('\\', "/")
+			})
+			.collect()
+	}
+
+	#[test]
+	fn collects_the_files_of_directories_recursively() {
+		let root = create_files("directories", &["b.js", "a/c.ts", "a/b/d.json"]);
+
+		let files = collect_files(
+			&[root.to_str().unwrap()],
+			&TraversalFilter::default(),
+			&OsFileSystem {},
+		)
+		.unwrap();
+
+		assert_eq!(relative(&root, files), vec!["a/b/d.json", "a/c.ts", "b.js"]);
+	}
+
+	#[test]
+	fn collects_the_files_matching_a_pattern_once() {
+		let root = create_files("patterns", &["a.js", "b.ts", "src/c.js", "src/d/e.js"]);
+		let root_text = root.to_str().unwrap();
+
+		let files = collect_files(
+			&[
+				format!("{}/*.js", root_text),
+				format!("{}/src/**/*.js", root_text),
+				format!("{}/a.js", root_text),
+			],
+			&TraversalFilter::default(),
+			&OsFileSystem {},
+		)
+		.unwrap();
+
+		assert_eq!(
+			relative(&root, files),
+			vec!["a.js", "src/c.js", "src/d/e.js"]
+		);
+	}
+
+	#[test]
+	fn skips_ignored_files() {
+		let root = create_files(
+			"ignored",
+			&[
+				"a.js",
+				"a.min.js",
+				"dist/b.js",
+				"node_modules/c/index.js",
+				"src/d.js",
+				"src/generated/e.js",
+			],
+		);
+		fs::write(root.join(".gitignore"), "dist/\n").unwrap();
+		fs::write(root.join("src/.romeignore"), "generated\n").unwrap();
+		let root_text = root.to_str().unwrap();
+
+		let filter = TraversalFilter::default();
+		let files = collect_files(&[root_text], &filter, &OsFileSystem {}).unwrap();
+		// the ignore files themselves aren't collected
+		assert_eq!(relative(&root, files), vec!["a.js", "a.min.js", "src/d.js"]);
+
+		let pattern = format!("{}/**/*.js", root_text);
+		let filter = TraversalFilter::new(&root, &["*.min.js"], &["/a*", "src/"]).unwrap();
+		let files = collect_files(&[pattern.as_str()], &filter, &OsFileSystem {}).unwrap();
+		assert_eq!(relative(&root, files), vec!["a.js", "src/d.js"]);
+
+		// files passed as input are always collected
+		let input = format!("{}/dist/b.js", root_text);
+		let files = collect_files(&[input.as_str()], &filter, &OsFileSystem {}).unwrap();
+		assert_eq!(relative(&root, files), vec!["dist/b.js"]);
+	}
+
+	#[test]
+	fn collects_the_files_of_a_file_system_in_memory() {
+		let file_system = MemoryFileSystem::new();
+		for file in [
+			"a.js",
+			"b/c.js",
+			"b/d.js",
+			"node_modules/e.js",
+			"src/f.js",
+			"src/g.js",
+		] {
+			file_system.insert(file, "");
+		}
+		file_system.insert(".gitignore", "b/\nsrc/*.js\n");
+		file_system.insert("src/.romeignore", "!g.js\n");
+
+		let files = collect_files(&["."], &TraversalFilter::default(), &file_system).unwrap();
+		assert_eq!(
+			files,
+			vec![PathBuf::from("./a.js"), PathBuf::from("./src/g.js")]
+		);
+
+		let files = collect_files(&["b/*.js"], &TraversalFilter::default(), &file_system).unwrap();
+		assert_eq!(
+			files,
+			vec![PathBuf::from("b/c.js"), PathBuf::from("b/d.js")]
+		);
+	}
+
+	#[test]
+	fn reads_the_ignore_files_up_to_the_repository_root() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("/home/.gitignore", "*.js\n");
+		file_system.insert("/home/repo/.git/HEAD", "");
+		file_system.insert("/home/repo/.gitignore", "b.js\n");
+		file_system.insert("/home/repo/src/a.js", "");
+		file_system.insert("/home/repo/src/b.js", "");
+
+		let files = collect_files(
+			&["/home/repo/src"],
+			&TraversalFilter::default(),
+			&file_system,
+		)
+		.unwrap();
+		assert_eq!(files, vec![PathBuf::from("/home/repo/src/a.js")]);
+	}
+
+	#[test]
+	fn reads_the_ignore_files_up_to_the_project_root_outside_of_repositories() {
+		let file_system = MemoryFileSystem::new();
+		file_system.insert("/home/.gitignore", "*.js\n");
+		file_system.insert("/home/project/.gitignore", "b.js\n");
+		file_system.insert("/home/project/src/a.js", "");
+		file_system.insert("/home/project/src/b.js", "");
+
+		let filter = TraversalFilter::new::<&str>(Path::new("/home/project"), &[], &[]).unwrap();
+		let files = collect_files(&["/home/project/src"], &filter, &file_system).unwrap();
+		assert_eq!(files, vec![PathBuf::from("/home/project/src/a.js")]);
+	}
+
+	#[test]
+	fn returns_an_error_for_missing_files() {
+		assert!(matches!(
+			collect_files(
+				&["does/not/exist.js"],
+				&TraversalFilter::default(),
+				&OsFileSystem {}
+			),
+			Err(TraversalError::NotFound(input)) if input == "does/not/exist.js"
+		));
+	}
+
+	#[test]
+	fn returns_an_error_for_invalid_patterns() {
+		assert!(matches!(
+			TraversalFilter::new(Path::new("."), &["src/{a,b"], &[]),
+			Err(TraversalError::InvalidPattern { pattern, .. }) if pattern == "src/{a,b"
+		));
+	}
+}
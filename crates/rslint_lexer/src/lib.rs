@@ -347,10 +347,12 @@ impl<'src> Lexer<'src> {
 				);
 
 		for _ in 0..2 {
-			match self.next_bounded() {
-				None => return Some(diagnostic),
-				Some(b) if !(*b as u8).is_ascii_hexdigit() => return Some(diagnostic),
-				_ => {}
+			// Don't consume the char after the escape, it may be the closing quote
+			match self.bytes.get(self.cur + 1) {
+				Some(b) if b.is_ascii_hexdigit() => {
+					self.next();
+				}
+				_ => return Some(diagnostic),
 			}
 		}
 		None
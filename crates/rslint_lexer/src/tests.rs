@@ -279,6 +279,12 @@ fn string_hex_escape_invalid() {
 		r#"'abcd \xZ0 \xGH'"#,
 		ERROR_TOKEN:16
 	}
+
+	assert_lex! {
+		r#""\x";"#,
+		ERROR_TOKEN:4,
+		SEMICOLON:1
+	}
 }
 
 #[test]
@@ -292,7 +292,7 @@ impl UnaryExpr {
 			return None;
 		}
 
-		Some(self.op_token()?.text_range().start() > self.expr()?.syntax().text_range().end())
+		Some(self.op_token()?.text_range().end() <= self.expr()?.syntax().text_range().start())
 	}
 }
 
@@ -1583,6 +1583,8 @@ impl PrivateProp {
 	pub fn excl_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![!]) }
 	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
 	pub fn ty(&self) -> Option<TsType> { support::child(&self.syntax) }
+	pub fn eq_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [=]) }
+	pub fn value(&self) -> Option<Expr> { support::child(&self.syntax) }
 	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[doc = ""]
@@ -1718,6 +1720,81 @@ pub struct ExprPattern {
 impl ExprPattern {
 	pub fn expr(&self) -> Option<Expr> { support::child(&self.syntax) }
 }
+#[doc = " The root of a JSON file, containing a single value\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonRoot {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonRoot {
+	pub fn value(&self) -> Option<JsonValue> { support::child(&self.syntax) }
+}
+#[doc = " A JSON object: `{ \"key\": \"value\" }`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonObject {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonObject {
+	pub fn l_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['{']) }
+	pub fn members(&self) -> AstChildren<JsonMember> { support::children(&self.syntax) }
+	pub fn r_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['}']) }
+}
+#[doc = " A member of a JSON object: `\"key\": \"value\"`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonMember {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonMember {
+	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
+}
+#[doc = " A JSON array: `[1, 2]`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonArray {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonArray {
+	pub fn l_brack_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['[']) }
+	pub fn elements(&self) -> AstChildren<JsonValue> { support::children(&self.syntax) }
+	pub fn r_brack_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![']']) }
+}
+#[doc = " A JSON string: `\"value\"`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonString {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonString {
+}
+#[doc = " A JSON number: `-1.5e3`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonNumber {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonNumber {
+	pub fn minus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [-]) }
+}
+#[doc = " A JSON boolean: `true` or `false`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonBoolean {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonBoolean {
+	pub fn true_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![true]) }
+	pub fn false_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![false]) }
+}
+#[doc = " The JSON `null` value\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonNull {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonNull {
+	pub fn null_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![null]) }
+}
+#[doc = " An unquoted key of a JSON5 object: `key` in `{ key: \"value\" }`\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonIdentifier {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsonIdentifier {
+}
 #[doc = ""]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ObjectProp {
@@ -1870,6 +1947,7 @@ pub enum TsType {
 	TsArray(TsArray),
 	TsIndexedArray(TsIndexedArray),
 	TsTypeOperator(TsTypeOperator),
+	TsTypeQuery(TsTypeQuery),
 	TsIntersection(TsIntersection),
 	TsUnion(TsUnion),
 	TsFnType(TsFnType),
@@ -1899,6 +1977,22 @@ pub enum TsTypeElement {
 	TsMethodSignature(TsMethodSignature),
 	TsIndexSignature(TsIndexSignature),
 }
+#[doc = " A JSON value\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonValue {
+	JsonObject(JsonObject),
+	JsonArray(JsonArray),
+	JsonString(JsonString),
+	JsonNumber(JsonNumber),
+	JsonBoolean(JsonBoolean),
+	JsonNull(JsonNull),
+}
+#[doc = " The key of a JSON object member\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonKey {
+	JsonString(JsonString),
+	JsonIdentifier(JsonIdentifier),
+}
 impl AstNode for TsAny {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_ANY }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3670,6 +3764,105 @@ impl AstNode for ExprPattern {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for JsonRoot {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_ROOT }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonObject {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_OBJECT }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonMember {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_MEMBER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonArray {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_ARRAY }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonString {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_STRING }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonNumber {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_NUMBER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonBoolean {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_BOOLEAN }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonNull {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_NULL }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsonIdentifier {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JSON_IDENTIFIER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl From<LiteralProp> for ObjectProp {
 	fn from(node: LiteralProp) -> ObjectProp { ObjectProp::LiteralProp(node) }
 }
@@ -4348,6 +4541,9 @@ impl From<TsIndexedArray> for TsType {
 impl From<TsTypeOperator> for TsType {
 	fn from(node: TsTypeOperator) -> TsType { TsType::TsTypeOperator(node) }
 }
+impl From<TsTypeQuery> for TsType {
+	fn from(node: TsTypeQuery) -> TsType { TsType::TsTypeQuery(node) }
+}
 impl From<TsIntersection> for TsType {
 	fn from(node: TsIntersection) -> TsType { TsType::TsIntersection(node) }
 }
@@ -4386,7 +4582,7 @@ impl AstNode for TsType {
 				| TS_MAPPED_TYPE | TS_IMPORT
 				| TS_ARRAY | TS_INDEXED_ARRAY
 				| TS_TYPE_OPERATOR
-				| TS_INTERSECTION
+				| TS_TYPE_QUERY | TS_INTERSECTION
 				| TS_UNION | TS_FN_TYPE
 				| TS_CONSTRUCTOR_TYPE
 				| TS_CONDITIONAL_TYPE
@@ -4419,6 +4615,7 @@ impl AstNode for TsType {
 			TS_ARRAY => TsType::TsArray(TsArray { syntax }),
 			TS_INDEXED_ARRAY => TsType::TsIndexedArray(TsIndexedArray { syntax }),
 			TS_TYPE_OPERATOR => TsType::TsTypeOperator(TsTypeOperator { syntax }),
+			TS_TYPE_QUERY => TsType::TsTypeQuery(TsTypeQuery { syntax }),
 			TS_INTERSECTION => TsType::TsIntersection(TsIntersection { syntax }),
 			TS_UNION => TsType::TsUnion(TsUnion { syntax }),
 			TS_FN_TYPE => TsType::TsFnType(TsFnType { syntax }),
@@ -4456,6 +4653,7 @@ impl AstNode for TsType {
 			TsType::TsArray(it) => &it.syntax,
 			TsType::TsIndexedArray(it) => &it.syntax,
 			TsType::TsTypeOperator(it) => &it.syntax,
+			TsType::TsTypeQuery(it) => &it.syntax,
 			TsType::TsIntersection(it) => &it.syntax,
 			TsType::TsUnion(it) => &it.syntax,
 			TsType::TsFnType(it) => &it.syntax,
@@ -4567,6 +4765,77 @@ impl AstNode for TsTypeElement {
 		}
 	}
 }
+impl From<JsonObject> for JsonValue {
+	fn from(node: JsonObject) -> JsonValue { JsonValue::JsonObject(node) }
+}
+impl From<JsonArray> for JsonValue {
+	fn from(node: JsonArray) -> JsonValue { JsonValue::JsonArray(node) }
+}
+impl From<JsonString> for JsonValue {
+	fn from(node: JsonString) -> JsonValue { JsonValue::JsonString(node) }
+}
+impl From<JsonNumber> for JsonValue {
+	fn from(node: JsonNumber) -> JsonValue { JsonValue::JsonNumber(node) }
+}
+impl From<JsonBoolean> for JsonValue {
+	fn from(node: JsonBoolean) -> JsonValue { JsonValue::JsonBoolean(node) }
+}
+impl From<JsonNull> for JsonValue {
+	fn from(node: JsonNull) -> JsonValue { JsonValue::JsonNull(node) }
+}
+impl AstNode for JsonValue {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		matches!(
+			kind,
+			JSON_OBJECT | JSON_ARRAY | JSON_STRING | JSON_NUMBER | JSON_BOOLEAN | JSON_NULL
+		)
+	}
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			JSON_OBJECT => JsonValue::JsonObject(JsonObject { syntax }),
+			JSON_ARRAY => JsonValue::JsonArray(JsonArray { syntax }),
+			JSON_STRING => JsonValue::JsonString(JsonString { syntax }),
+			JSON_NUMBER => JsonValue::JsonNumber(JsonNumber { syntax }),
+			JSON_BOOLEAN => JsonValue::JsonBoolean(JsonBoolean { syntax }),
+			JSON_NULL => JsonValue::JsonNull(JsonNull { syntax }),
+			_ => return None,
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			JsonValue::JsonObject(it) => &it.syntax,
+			JsonValue::JsonArray(it) => &it.syntax,
+			JsonValue::JsonString(it) => &it.syntax,
+			JsonValue::JsonNumber(it) => &it.syntax,
+			JsonValue::JsonBoolean(it) => &it.syntax,
+			JsonValue::JsonNull(it) => &it.syntax,
+		}
+	}
+}
+impl From<JsonString> for JsonKey {
+	fn from(node: JsonString) -> JsonKey { JsonKey::JsonString(node) }
+}
+impl From<JsonIdentifier> for JsonKey {
+	fn from(node: JsonIdentifier) -> JsonKey { JsonKey::JsonIdentifier(node) }
+}
+impl AstNode for JsonKey {
+	fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, JSON_STRING | JSON_IDENTIFIER) }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			JSON_STRING => JsonKey::JsonString(JsonString { syntax }),
+			JSON_IDENTIFIER => JsonKey::JsonIdentifier(JsonIdentifier { syntax }),
+			_ => return None,
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			JsonKey::JsonString(it) => &it.syntax,
+			JsonKey::JsonIdentifier(it) => &it.syntax,
+		}
+	}
+}
 impl std::fmt::Display for ObjectProp {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -4647,6 +4916,16 @@ impl std::fmt::Display for TsTypeElement {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for JsonValue {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonKey {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsAny {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -5452,3 +5731,48 @@ impl std::fmt::Display for ExprPattern {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for JsonRoot {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonObject {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonMember {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonArray {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonString {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonNumber {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonBoolean {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonNull {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsonIdentifier {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
@@ -0,0 +1,112 @@
+//! Extensions to JSON AST elements
+
+use crate::{ast::*, SyntaxKind::*};
+
+impl JsonMember {
+	/// The string or, in JSON5, the identifier before the colon: `"key"` in `"key": "value"`
+	pub fn key(&self) -> Option<JsonKey> {
+		let colon = self.colon_token();
+
+		self.syntax()
+			.children()
+			.take_while(|child| match &colon {
+				Some(colon) => child.text_range().end() <= colon.text_range().start(),
+				None => true,
+			})
+			.find_map(JsonKey::cast)
+	}
+
+	/// The value after the colon: `"value"` in `"key": "value"`
+	pub fn value(&self) -> Option<JsonValue> {
+		let colon = self.colon_token()?;
+
+		self.syntax()
+			.children()
+			.filter(|child| child.text_range().start() >= colon.text_range().end())
+			.find_map(JsonValue::cast)
+	}
+}
+
+impl JsonString {
+	/// The string literal including its quotes
+	pub fn value_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), STRING)
+	}
+
+	/// The content of the string literal with its escape sequences decoded: `a"b` for `"a\"b"`.
+	/// Returns `None` if the literal has an invalid escape sequence.
+	pub fn value(&self) -> Option<std::string::String> {
+		let token = self.value_token()?;
+		let text = token.text();
+		let mut chars = text.get(1..text.len().checked_sub(1)?)?.chars();
+		let mut value = std::string::String::with_capacity(text.len());
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				value.push(c);
+				continue;
+			}
+
+			let escaped = match chars.next()? {
+				'b' => '\u{8}',
+				'f' => '\u{c}',
+				'n' => '\n',
+				'r' => '\r',
+				't' => '\t',
+				'u' => {
+					let digits = chars.as_str().get(..4)?;
+					if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+						return None;
+					}
+					chars.nth(3);
+					char::from_u32(u32::from_str_radix(digits, 16).ok()?)?
+				}
+				c @ ('"' | '\'' | '\\' | '/') => c,
+				_ => return None,
+			};
+			value.push(escaped);
+		}
+
+		Some(value)
+	}
+}
+
+impl JsonIdentifier {
+	/// The identifier or keyword used as key: `key` in `{ key: 1 }`
+	pub fn name_token(&self) -> Option<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|element| element.into_token())
+			.find(|token| !token.kind().is_trivia())
+	}
+}
+
+impl JsonNumber {
+	/// The number without its sign: `1.5` in `-1.5` or `0xFF` in JSON5
+	pub fn value_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), NUMBER)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn string_value(text: &str) -> Option<std::string::String> {
+		match parse_json(text, 0).tree().value() {
+			Some(ast::JsonValue::JsonString(string)) => string.value(),
+			_ => panic!("{} isn't a string", text),
+		}
+	}
+
+	#[test]
+	fn json_string_value_decodes_the_escapes() {
+		assert_eq!(
+			string_value(r#""src\\generated""#).unwrap(),
+			"src\\generated"
+		);
+		assert_eq!(string_value(r#""\u002a.js""#).unwrap(), "*.js");
+		assert_eq!(string_value(r#""\"\/\n\t""#).unwrap(), "\"/\n\t");
+		assert_eq!(string_value(r#""caf\u00e9""#).unwrap(), "café");
+	}
+}
@@ -78,7 +78,7 @@ impl AstNode for TsModuleRef {
 	}
 
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
-		if Self::can_cast(syntax.kind()) {
+		if !Self::can_cast(syntax.kind()) {
 			None
 		} else {
 			Some(match syntax.kind() {
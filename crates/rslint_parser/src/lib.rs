@@ -229,6 +229,13 @@ pub enum FileKind {
 	Script,
 	Module,
 	TypeScript,
+	/// Strict JSON, see [parse_json]
+	Json,
+	/// JSON with comments and trailing commas, as used by `tsconfig.json`, see [parse_jsonc]
+	Jsonc,
+	/// [JSON5](https://json5.org), which adds unquoted keys, single quoted strings and hex
+	/// numbers to JSONC, see [parse_json5]
+	Json5,
 }
 
 impl Default for FileKind {
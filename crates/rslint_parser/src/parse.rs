@@ -1,10 +1,10 @@
 //! Utilities for high level parsing of js code.
 
 use crate::{
-	ast::{Expr, Module, Script},
+	ast::{Expr, JsonRoot, Module, Script},
 	*,
 };
-use rslint_errors::Severity;
+use rslint_errors::{Diagnostic, Severity};
 use std::marker::PhantomData;
 
 /// A utility struct for managing the result of a parser job
@@ -246,6 +246,101 @@ pub fn parse_expr(text: &str, file_id: usize) -> Parse<Expr> {
 	Parse::new(green, parse_errors)
 }
 
+/// Losslessly parses JSON text into a [`Parse`](Parse) which can then be turned into an untyped root [`SyntaxNode`](SyntaxNode).
+/// Or turned into a typed [`JsonRoot`](JsonRoot) with [`tree`](Parse::tree).
+///
+/// The text is tokenized with the JavaScript lexer. Comments and tokens that aren't valid JSON, like
+/// single quoted strings or trailing commas, are part of the tree but result in errors.
+///
+/// ```
+/// use rslint_parser::{ast::JsonValue, parse_json};
+///
+/// let parse = parse_json("[1,]", 0);
+///
+/// assert!(matches!(parse.tree().value(), Some(JsonValue::JsonArray(_))));
+/// assert_eq!(parse.errors()[0].title, "trailing commas aren't allowed in JSON");
+/// ```
+pub fn parse_json(text: &str, file_id: usize) -> Parse<JsonRoot> {
+	parse_json_common(text, file_id, FileKind::Json)
+}
+
+/// Same as [`parse_json`] but allows comments and trailing commas, as used by `tsconfig.json` or
+/// the settings of VS Code.
+///
+/// ```
+/// use rslint_parser::parse_jsonc;
+///
+/// let parse = parse_jsonc("{\n\t// the target\n\t\"target\": \"es5\",\n}", 0);
+///
+/// assert!(parse.errors().is_empty());
+/// ```
+pub fn parse_jsonc(text: &str, file_id: usize) -> Parse<JsonRoot> {
+	parse_json_common(text, file_id, FileKind::Jsonc)
+}
+
+/// Same as [`parse_jsonc`] but parses [JSON5](https://json5.org), which also allows unquoted keys,
+/// single quoted strings and hexadecimal numbers.
+///
+/// ```
+/// use rslint_parser::parse_json5;
+///
+/// let parse = parse_json5("{ key: 'value', color: 0xFF0000 }", 0);
+///
+/// assert!(parse.errors().is_empty());
+/// ```
+pub fn parse_json5(text: &str, file_id: usize) -> Parse<JsonRoot> {
+	parse_json_common(text, file_id, FileKind::Json5)
+}
+
+fn parse_json_common(text: &str, file_id: usize, file_kind: FileKind) -> Parse<JsonRoot> {
+	let (mut tokens, mut errors) = tokenize(text, file_id);
+
+	if file_kind == FileKind::Json {
+		let mut offset = 0;
+
+		for token in &tokens {
+			if token.kind == SyntaxKind::COMMENT {
+				errors.push(
+					Diagnostic::error(file_id, "SyntaxError", "comments aren't allowed in JSON")
+						.primary(offset..offset + token.len, "remove this comment"),
+				);
+			}
+			offset += token.len;
+		}
+	}
+
+	if file_kind != FileKind::Json5 {
+		// The JSON parser validates the escape sequences of double quoted strings, a string with
+		// an escape sequence that is invalid in JavaScript is a string with an invalid escape
+		let mut offset = 0;
+
+		for token in &mut tokens {
+			let range = offset..offset + token.len;
+
+			if token.kind == SyntaxKind::ERROR_TOKEN
+				&& crate::syntax::json::is_terminated_string(&text[range.clone()])
+			{
+				token.kind = SyntaxKind::STRING;
+				errors.retain(|error| match &error.primary {
+					Some(primary) => !range.contains(&primary.span.range.start),
+					None => true,
+				});
+			}
+			offset += token.len;
+		}
+	}
+
+	let tok_source = TokenSource::new(text, &tokens);
+	let mut parser = crate::Parser::new(tok_source, file_id, Syntax::new(file_kind));
+	crate::syntax::json::root(&mut parser);
+	let (events, p_diags) = parser.finish();
+	errors.extend(p_diags);
+	let mut tree_sink = LosslessTreeSink::new(text, &tokens);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, parse_errors) = tree_sink.finish();
+	Parse::new(green, parse_errors)
+}
+
 pub fn parse_with_syntax(text: &str, file_id: usize, syntax: Syntax) -> Parse<()> {
 	let (events, errors, tokens) = parse_common(text, file_id, syntax);
 	let mut tree_sink = LosslessTreeSink::new(text, &tokens);
@@ -0,0 +1,428 @@
+//! JSON values, see [RFC 8259](https://tools.ietf.org/html/rfc8259).
+//!
+//! JSON is tokenized by the JavaScript lexer. Tokens that are valid JavaScript but aren't valid
+//! JSON, e.g. single quoted strings, hex numbers or identifiers as object keys, are still added
+//! to the tree but result in an error.
+//!
+//! The [FileKind] of the parser selects the dialect: JSONC allows trailing commas and JSON5 also
+//! allows unquoted keys, single quoted strings and hex numbers. Both allow comments, which are
+//! trivia and reported by [crate::parse_json] for strict JSON.
+
+use crate::{SyntaxKind::*, *};
+
+/// The tokens that end a value, the parser doesn't skip them when recovering from an error
+pub const VALUE_RECOVERY_SET: TokenSet = token_set![T![,], T![:], T!['}'], T![']']];
+
+/// A JSON file, consisting of a single value
+pub fn root(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	value(p);
+
+	if !p.at(EOF) {
+		let start = p.cur_tok().range.start;
+		let mut end = p.cur_tok().range.end;
+		let error_marker = p.start();
+
+		while !p.at(EOF) {
+			end = p.cur_tok().range.end;
+			p.bump_any();
+		}
+
+		error_marker.complete(p, ERROR);
+
+		let err = p
+			.err_builder("a JSON file must contain a single value")
+			.primary(start..end, "remove the content after the value");
+		p.error(err);
+	}
+
+	m.complete(p, JSON_ROOT)
+}
+
+/// A JSON value: an object, array, string, number, boolean or `null`
+pub fn value(p: &mut Parser) -> Option<CompletedMarker> {
+	match p.cur() {
+		T!['{'] => Some(object(p)),
+		T!['['] => Some(array(p)),
+		STRING => Some(string(p)),
+		NUMBER | T![-] => Some(number(p)),
+		T![true] | T![false] => {
+			let m = p.start();
+			p.bump_any();
+			Some(m.complete(p, JSON_BOOLEAN))
+		}
+		T![null] => {
+			let m = p.start();
+			p.bump_any();
+			Some(m.complete(p, JSON_NULL))
+		}
+		EOF => {
+			let err = p
+				.err_builder("expected a JSON value but instead the file ends")
+				.primary(p.cur_tok().range, "the file ends here");
+			p.error(err);
+			None
+		}
+		_ => {
+			let err = p
+				.err_builder(&format!(
+					"expected a JSON value but instead found `{}`",
+					p.cur_src()
+				))
+				.primary(p.cur_tok().range, "unexpected");
+			p.err_recover(err, VALUE_RECOVERY_SET, false);
+			None
+		}
+	}
+}
+
+/// An object such as `{ "a": 1, "b": 2 }`
+pub fn object(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	p.expect(T!['{']);
+	let mut first = true;
+
+	while !p.at(EOF) && !p.at(T!['}']) {
+		let start = p.token_pos();
+
+		if first {
+			first = false;
+		} else if p.at(T![,]) && p.nth_at(1, T!['}']) {
+			trailing_comma(p);
+			break;
+		} else {
+			p.expect(T![,]);
+		}
+
+		member(p);
+		skip_if_stuck(p, start);
+	}
+
+	p.expect(T!['}']);
+	m.complete(p, JSON_OBJECT)
+}
+
+/// A member of an object such as `"a": 1`
+pub fn member(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+
+	if p.at(STRING) {
+		string(p);
+	} else if is_json5(p) && (p.at(T![ident]) || p.cur().is_keyword()) {
+		// `{ key: 1 }`
+		let m = p.start();
+		p.bump_any();
+		m.complete(p, JSON_IDENTIFIER);
+	} else if p.at_ts(token_set![T![ident], NUMBER]) || p.cur().is_keyword() {
+		// `{ a: 1 }` or `{ 1: 1 }`
+		let err = p
+			.err_builder("the keys of JSON objects must be strings")
+			.primary(
+				p.cur_tok().range,
+				format!("wrap the key in double quotes: `\"{}\"`", p.cur_src()),
+			);
+		p.err_and_bump(err);
+	} else {
+		let err = p
+			.err_builder(&format!(
+				"expected a string as the key of an object member but instead found `{}`",
+				p.cur_src()
+			))
+			.primary(p.cur_tok().range, "unexpected");
+		p.error(err);
+
+		// `{ [a]: 1 }`, the rest of the member can't be parsed either. `{ : 1 }` only misses the key
+		if !p.at(T![:]) {
+			skip_member(p);
+			return m.complete(p, JSON_MEMBER);
+		}
+	}
+
+	p.expect(T![:]);
+	value(p);
+	m.complete(p, JSON_MEMBER)
+}
+
+/// An array such as `[1, 2]`
+pub fn array(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	p.expect(T!['[']);
+	let mut first = true;
+
+	while !p.at(EOF) && !p.at(T![']']) {
+		let start = p.token_pos();
+
+		if first {
+			first = false;
+		} else if p.at(T![,]) && p.nth_at(1, T![']']) {
+			trailing_comma(p);
+			break;
+		} else {
+			p.expect(T![,]);
+		}
+
+		value(p);
+		skip_if_stuck(p, start);
+	}
+
+	p.expect(T![']']);
+	m.complete(p, JSON_ARRAY)
+}
+
+/// A string enclosed in double quotes, or in JSON5 also in single quotes
+pub fn string(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	let range = p.cur_tok().range;
+
+	// JSON5 strings are JavaScript strings, which are validated by the lexer
+	if !is_json5(p) {
+		if let Some((message, error_range)) = invalid_string_error(p.cur_src()) {
+			let start = usize::from(range.start);
+			let err = p
+				.err_builder(message)
+				.primary(start + error_range.start..start + error_range.end, "");
+			p.error(err);
+		}
+	}
+
+	p.bump(STRING);
+	m.complete(p, JSON_STRING)
+}
+
+/// A decimal number with an optional minus sign: `-1.5e3`, or in JSON5 also a hex number: `0xFF`
+pub fn number(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+
+	if p.at(T![-]) {
+		let minus_end = p.cur_tok().range.end;
+		p.bump_any();
+
+		if p.at(NUMBER) && p.cur_tok().range.start != minus_end {
+			let err = p
+				.err_builder("the minus sign of a JSON number must directly precede the number")
+				.primary(minus_end..p.cur_tok().range.start, "remove the whitespace");
+			p.error(err);
+		}
+	}
+
+	if p.at(NUMBER) {
+		if is_json5(p) {
+			if !is_json5_number(p.cur_src()) {
+				let err = p
+					.err_builder(&format!("`{}` isn't a valid JSON5 number", p.cur_src()))
+					.primary(
+						p.cur_tok().range,
+						"JSON5 numbers are decimal numbers without leading zeros or hex numbers",
+					);
+				p.error(err);
+			}
+		} else if !is_json_number(p.cur_src()) {
+			let err = p
+				.err_builder(&format!("`{}` isn't a valid JSON number", p.cur_src()))
+				.primary(
+					p.cur_tok().range,
+					"JSON numbers are decimal numbers without leading zeros",
+				);
+			p.error(err);
+		}
+
+		p.bump_any();
+	} else {
+		let err = p
+			.err_builder("expected a number after the minus sign")
+			.primary(p.cur_tok().range, "");
+		p.error(err);
+	}
+
+	m.complete(p, JSON_NUMBER)
+}
+
+fn trailing_comma(p: &mut Parser) {
+	if p.syntax.file_kind != FileKind::Json {
+		p.bump_any();
+		return;
+	}
+
+	let err = p
+		.err_builder("trailing commas aren't allowed in JSON")
+		.primary(p.cur_tok().range, "remove this comma");
+	p.err_and_bump(err);
+}
+
+fn is_json5(p: &Parser) -> bool {
+	p.syntax.file_kind == FileKind::Json5
+}
+
+/// Adds the tokens up to the `,` or `}` that ends the current object member to an error node
+fn skip_member(p: &mut Parser) {
+	if p.at_ts(token_set![T![,], T!['}'], EOF]) {
+		return;
+	}
+
+	let m = p.start();
+	let mut depth = 0usize;
+
+	while !p.at(EOF) {
+		match p.cur() {
+			T!['{'] | T!['['] => depth += 1,
+			T![,] | T!['}'] if depth == 0 => break,
+			T!['}'] | T![']'] => depth = depth.saturating_sub(1),
+			_ => {}
+		}
+		p.bump_any();
+	}
+
+	m.complete(p, ERROR);
+}
+
+/// Adds the current token to an error node if parsing a member or an element starting at
+/// `start` didn't consume any token, e.g. because of an unexpected `:`. This ensures that the
+/// parser always makes progress.
+fn skip_if_stuck(p: &mut Parser, start: usize) {
+	if p.token_pos() == start && !p.at(EOF) {
+		let m = p.start();
+		p.bump_any();
+		m.complete(p, ERROR);
+	}
+}
+
+/// Returns the error message for the string literal `text` if it isn't a valid JSON string,
+/// together with the range of the invalid part of `text`
+fn invalid_string_error(text: &str) -> Option<(&'static str, std::ops::Range<usize>)> {
+	if !text.starts_with('"') {
+		return Some((
+			"JSON strings must be enclosed in double quotes",
+			0..text.len(),
+		));
+	}
+
+	let mut chars = text.char_indices().peekable();
+
+	while let Some((index, char)) = chars.next() {
+		match char {
+			'\\' => match chars.next() {
+				Some((_, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't')) => {}
+				Some((_, 'u')) => {
+					let mut digits = 0;
+					while digits < 4 && chars.peek().is_some_and(|(_, c)| c.is_ascii_hexdigit()) {
+						chars.next();
+						digits += 1;
+					}
+
+					if digits < 4 {
+						let end = chars.peek().map_or(text.len(), |(end, _)| *end);
+						return Some(("`\\u` must be followed by four hex digits", index..end));
+					}
+				}
+				escape => {
+					let end = escape.map_or(text.len(), |(start, char)| start + char.len_utf8());
+					return Some(("invalid escape sequence in a JSON string", index..end));
+				}
+			},
+			char if char < ' ' => {
+				return Some((
+					"control characters in JSON strings must be escaped",
+					index..index + char.len_utf8(),
+				));
+			}
+			_ => {}
+		}
+	}
+
+	None
+}
+
+/// Returns `true` if `text` is a double quoted string literal that ends with its closing quote
+pub(crate) fn is_terminated_string(text: &str) -> bool {
+	if !text.starts_with('"') {
+		return false;
+	}
+
+	let mut chars = text.char_indices().skip(1);
+
+	while let Some((index, char)) = chars.next() {
+		match char {
+			'\\' => {
+				chars.next();
+			}
+			'"' => return index == text.len() - 1,
+			_ => {}
+		}
+	}
+
+	false
+}
+
+/// Returns `true` if `text` is a decimal number without leading zeros, with an optional fraction
+/// and exponent: `0`, `10.5`, `1e-3`
+fn is_json_number(text: &str) -> bool {
+	let bytes = text.as_bytes();
+	let mut index = 0;
+
+	let digits = |index: &mut usize| {
+		let start = *index;
+		while bytes.get(*index).is_some_and(u8::is_ascii_digit) {
+			*index += 1;
+		}
+		*index - start
+	};
+
+	match bytes.first() {
+		Some(b'0') => index += 1,
+		Some(b'1'..=b'9') => {
+			digits(&mut index);
+		}
+		_ => return false,
+	}
+
+	if bytes.get(index) == Some(&b'.') {
+		index += 1;
+		if digits(&mut index) == 0 {
+			return false;
+		}
+	}
+
+	if matches!(bytes.get(index), Some(b'e' | b'E')) {
+		index += 1;
+		if matches!(bytes.get(index), Some(b'+' | b'-')) {
+			index += 1;
+		}
+		if digits(&mut index) == 0 {
+			return false;
+		}
+	}
+
+	index == bytes.len()
+}
+
+/// Returns `true` if `text` is a hex number or a decimal number without leading zeros whose
+/// integer or fraction part may be empty: `0xFF`, `.5`, `5.`
+fn is_json5_number(text: &str) -> bool {
+	if let Some(digits) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+		return !digits.is_empty() && digits.chars().all(|char| char.is_ascii_hexdigit());
+	}
+
+	let (mantissa, exponent) = match text.find(['e', 'E']) {
+		Some(index) => (&text[..index], Some(&text[index + 1..])),
+		None => (text, None),
+	};
+	let (integer, fraction) = match mantissa.find('.') {
+		Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+		None => (mantissa, ""),
+	};
+	let is_digits = |text: &str| text.chars().all(|char| char.is_ascii_digit());
+
+	let valid_exponent = match exponent {
+		Some(exponent) => {
+			let digits = exponent.trim_start_matches(['+', '-']);
+			!digits.is_empty() && exponent.len() - digits.len() <= 1 && is_digits(digits)
+		}
+		None => true,
+	};
+
+	!(integer.is_empty() && fraction.is_empty())
+		&& is_digits(integer)
+		&& is_digits(fraction)
+		&& (integer.len() <= 1 || !integer.starts_with('0'))
+		&& valid_exponent
+}
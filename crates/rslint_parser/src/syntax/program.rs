@@ -406,7 +406,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 				..p.state.clone()
 			});
 			let inner = guard.start();
-			guard.bump_any();
+			guard.bump_remap(T![async]);
 			function_decl(&mut *guard, inner, false);
 			return m.complete(&mut *guard, EXPORT_DEFAULT_DECL);
 		}
@@ -434,7 +434,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			..p.state.clone()
 		});
 		let inner = guard.start();
-		guard.bump_any();
+		guard.bump_remap(T![async]);
 		function_decl(&mut *guard, inner, false);
 	} else if !only_ty && p.at(T![function]) {
 		p.state.decorators_were_valid = true;
@@ -120,7 +120,7 @@ pub fn stmt(
 		{
 			p.state.decorators_were_valid = true;
 			let m = decorator.map(|x| x.precede(p)).unwrap_or_else(|| p.start());
-			p.bump_any();
+			p.bump_remap(T![async]);
 			function_decl(
 				&mut *p.with_state(ParserState {
 					in_async: true,
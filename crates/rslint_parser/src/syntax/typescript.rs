@@ -1203,7 +1203,7 @@ fn is_mapped_type_start(p: &Parser) -> bool {
 		return true;
 	}
 	let mut cur = 1;
-	if p.cur_src() == "readonly" {
+	if p.nth_src(1) == "readonly" {
 		cur += 1;
 	}
 	if !p.nth_at(cur, T!['[']) {
@@ -1,4 +1,7 @@
-use crate::{ast::Module, parse_module, Parse, ParserError};
+use crate::{
+	ast::{JsonRoot, Module},
+	parse_json, parse_json5, parse_jsonc, parse_module, Parse, ParserError, SyntaxNode,
+};
 use expect_test::expect_file;
 use rslint_errors::{file::SimpleFiles, Emitter};
 use std::fs;
@@ -30,45 +33,114 @@ fn try_parse(path: &str, text: &str) -> Parse<Module> {
 
 #[test]
 fn parser_tests() {
-	dir_tests(&test_data_dir(), &["inline/ok"], "rast", |text, path| {
-		let parse = try_parse(path.to_str().unwrap(), text);
-		let errors = parse.errors();
-		assert_errors_are_absent(errors, path);
-		format!("{:#?}", parse.syntax())
-	});
-	dir_tests(&test_data_dir(), &["inline/err"], "rast", |text, path| {
-		let parse = try_parse(path.to_str().unwrap(), text);
-		let errors = parse.errors();
-		assert_errors_are_present(errors, path);
-		let mut files = SimpleFiles::new();
-		files.add(
-			path.file_name().unwrap().to_string_lossy().to_string(),
-			text.to_string(),
-		);
-		let mut ret = format!("{:#?}", parse.syntax());
-
-		for diag in parse.errors() {
-			let mut write = rslint_errors::termcolor::Buffer::no_color();
-			let mut emitter = Emitter::new(&files);
-			emitter
-				.emit_with_writer(diag, &mut write)
-				.expect("failed to emit diagnostic");
-
-			ret.push_str(&format!(
-				"--\n{}",
-				std::str::from_utf8(write.as_slice()).expect("non utf8 in error buffer")
-			));
-		}
-		ret.push_str(&format!("--\n{}", text));
-		ret
-	});
+	dir_tests(
+		&test_data_dir(),
+		&["inline/ok"],
+		"js",
+		"rast",
+		|text, path| {
+			let parse = try_parse(path.to_str().unwrap(), text);
+			let errors = parse.errors();
+			assert_errors_are_absent(errors, path);
+			format!("{:#?}", parse.syntax())
+		},
+	);
+	dir_tests(
+		&test_data_dir(),
+		&["inline/err"],
+		"js",
+		"rast",
+		|text, path| {
+			let parse = try_parse(path.to_str().unwrap(), text);
+			let errors = parse.errors();
+			assert_errors_are_present(errors, path);
+			format_with_errors(text, path, &parse.syntax(), errors)
+		},
+	);
+}
+
+#[test]
+fn json_parser_tests() {
+	json_dir_tests("json", parse_json);
+}
+
+#[test]
+fn jsonc_parser_tests() {
+	json_dir_tests("jsonc", parse_jsonc);
+}
+
+#[test]
+fn json5_parser_tests() {
+	json_dir_tests("json5", parse_json5);
+}
+
+/// Runs the tests in `test_data/<extension>`, whose files have the same `extension` as the name of
+/// the directory and are parsed with `parse`
+fn json_dir_tests(extension: &str, parse: fn(&str, usize) -> Parse<JsonRoot>) {
+	dir_tests(
+		&test_data_dir(),
+		&[&format!("{}/ok", extension)],
+		extension,
+		"rast",
+		|text, path| {
+			let parse = parse(text, 0);
+			assert_errors_are_absent(parse.errors(), path);
+			format!("{:#?}", parse.syntax())
+		},
+	);
+	dir_tests(
+		&test_data_dir(),
+		&[&format!("{}/err", extension)],
+		extension,
+		"rast",
+		|text, path| {
+			let parse = parse(text, 0);
+			assert_errors_are_present(parse.errors(), path);
+			format_with_errors(text, path, &parse.syntax(), parse.errors())
+		},
+	);
+}
+
+/// Prints the tree followed by the emitted `errors` and the source `text`
+fn format_with_errors(
+	text: &str,
+	path: &Path,
+	syntax: &SyntaxNode,
+	errors: &[ParserError],
+) -> String {
+	let mut files = SimpleFiles::new();
+	files.add(
+		path.file_name().unwrap().to_string_lossy().to_string(),
+		text.to_string(),
+	);
+	let mut ret = format!("{:#?}", syntax);
+
+	for diag in errors {
+		let mut write = rslint_errors::termcolor::Buffer::no_color();
+		let mut emitter = Emitter::new(&files);
+		emitter
+			.emit_with_writer(diag, &mut write)
+			.expect("failed to emit diagnostic");
+
+		ret.push_str(&format!(
+			"--\n{}",
+			std::str::from_utf8(write.as_slice()).expect("non utf8 in error buffer")
+		));
+	}
+	ret.push_str(&format!("--\n{}", text));
+	ret
 }
 
-fn dir_tests<F>(test_data_dir: &Path, paths: &[&str], outfile_extension: &str, f: F)
-where
+fn dir_tests<F>(
+	test_data_dir: &Path,
+	paths: &[&str],
+	extension: &str,
+	outfile_extension: &str,
+	f: F,
+) where
 	F: Fn(&str, &Path) -> String,
 {
-	for (path, input_code) in collect_js_files(test_data_dir, paths) {
+	for (path, input_code) in collect_files(test_data_dir, paths, extension) {
 		let actual = f(&input_code, &path);
 		let path = path.with_extension(outfile_extension);
 		expect_file![path].assert_eq(&actual)
@@ -80,27 +152,27 @@ pub fn project_dir() -> PathBuf {
 	PathBuf::from(dir).parent().unwrap().to_path_buf()
 }
 
-fn collect_js_files(root_dir: &Path, paths: &[&str]) -> Vec<(PathBuf, String)> {
+fn collect_files(root_dir: &Path, paths: &[&str], extension: &str) -> Vec<(PathBuf, String)> {
 	paths
 		.iter()
 		.flat_map(|path| {
 			let path = root_dir.to_owned().join(path);
-			js_files_in_dir(&path).into_iter()
+			files_in_dir(&path, extension).into_iter()
 		})
 		.map(|path| {
-			let text = fs::read_to_string(&path).expect("Could not read js file");
+			let text = fs::read_to_string(&path).expect("Could not read test file");
 			(path, text)
 		})
 		.collect()
 }
 
-fn js_files_in_dir(dir: &Path) -> Vec<PathBuf> {
+fn files_in_dir(dir: &Path, extension: &str) -> Vec<PathBuf> {
 	let mut acc = Vec::new();
 	println!("{:?}", dir);
 	for file in fs::read_dir(&dir).unwrap() {
 		let file = file.unwrap();
 		let path = file.path();
-		if path.extension().unwrap_or_default() == "js" {
+		if path.extension().unwrap_or_default() == extension {
 			acc.push(path);
 		}
 	}
@@ -323,10 +323,10 @@ pub trait SyntaxTokenExt {
 					len
 				};
 				(
-					CommentKind::JsDoc,
+					CommentKind::Multiline,
 					token
 						.text()
-						.get(3..end)
+						.get(2..end)
 						.map(|x| x.to_string())
 						.unwrap_or_default(),
 				)
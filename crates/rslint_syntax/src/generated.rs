@@ -306,6 +306,15 @@ pub enum SyntaxKind {
 	TS_NAMESPACE_EXPORT_DECL,
 	TS_DECORATOR,
 	TS_INFER,
+	JSON_ROOT,
+	JSON_OBJECT,
+	JSON_MEMBER,
+	JSON_ARRAY,
+	JSON_STRING,
+	JSON_NUMBER,
+	JSON_BOOLEAN,
+	JSON_NULL,
+	JSON_IDENTIFIER,
 	#[doc(hidden)]
 	__LAST,
 }
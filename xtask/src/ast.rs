@@ -318,6 +318,16 @@ pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
 		"TS_NAMESPACE_EXPORT_DECL",
 		"TS_DECORATOR",
 		"TS_INFER",
+		// JSON
+		"JSON_ROOT",
+		"JSON_OBJECT",
+		"JSON_MEMBER",
+		"JSON_ARRAY",
+		"JSON_STRING",
+		"JSON_NUMBER",
+		"JSON_BOOLEAN",
+		"JSON_NULL",
+		"JSON_IDENTIFIER",
 	],
 };
 
@@ -1421,6 +1431,8 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
 			T![!],
 			T![:],
 			ty: TsType,
+			T![=],
+			value: Expr,
 			T![;]
 		}
 
@@ -1504,6 +1516,59 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
 		struct ExprPattern {
 			expr: Expr
 		}
+
+		/// The root of a JSON file, containing a single value
+		struct JsonRoot {
+			value: JsonValue
+		}
+
+		/// A JSON object: `{ "key": "value" }`
+		struct JsonObject {
+			T!['{'],
+			members: [JsonMember],
+			T!['}'],
+		}
+
+		/// A member of a JSON object: `"key": "value"`
+		struct JsonMember {
+			/* key */
+			T![:]
+			/* value */
+		}
+
+		/// A JSON array: `[1, 2]`
+		struct JsonArray {
+			T!['['],
+			elements: [JsonValue],
+			T![']'],
+		}
+
+		/// A JSON string: `"value"`
+		struct JsonString {
+			/* value */
+		}
+
+		/// A JSON number: `-1.5e3`
+		struct JsonNumber {
+			T![-]
+			/* value */
+		}
+
+		/// A JSON boolean: `true` or `false`
+		struct JsonBoolean {
+			T![true],
+			T![false],
+		}
+
+		/// The JSON `null` value
+		struct JsonNull {
+			T![null]
+		}
+
+		/// An unquoted key of a JSON5 object: `key` in `{ key: "value" }`
+		struct JsonIdentifier {
+			/* name */
+		}
 	},
 	enums: &ast_enums! {
 		enum ObjectProp {
@@ -1675,6 +1740,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
 			TsArray,
 			TsIndexedArray,
 			TsTypeOperator,
+			TsTypeQuery,
 			TsIntersection,
 			TsUnion,
 			TsFnType,
@@ -1701,5 +1767,21 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
 			TsMethodSignature,
 			TsIndexSignature
 		}
+
+		/// A JSON value
+		enum JsonValue {
+			JsonObject,
+			JsonArray,
+			JsonString,
+			JsonNumber,
+			JsonBoolean,
+			JsonNull
+		}
+
+		/// The key of a JSON object member
+		enum JsonKey {
+			JsonString,
+			JsonIdentifier
+		}
 	},
 };